@@ -22,13 +22,13 @@ use std::os::raw::c_void;
 use std::ptr;
 
 
-/// Send static data to GPU with Vertex Buffer Object
-struct VertexBufferStatic {
+/// Send data to GPU with Vertex Buffer Object.
+struct VertexBuffer {
     id: GLuint,
     attribute_component_count: GLint,
 }
 
-impl VertexBufferStatic {
+impl VertexBuffer {
     /// Sends static data to GPU.
     ///
     /// # Arguments
@@ -37,18 +37,49 @@ impl VertexBufferStatic {
     ///
     /// # Safety
     /// This function does not check if data length and `attribute_component_count` match.
-    unsafe fn new(data: &[f32], attribute_component_count: GLint) -> VertexBufferStatic {
+    unsafe fn new_static(data: &[f32], attribute_component_count: GLint) -> VertexBuffer {
+        let buffer = VertexBuffer::new_empty(attribute_component_count);
+        buffer.buffer_data(data, gl_raw::STATIC_DRAW);
+        buffer
+    }
+
+    /// Creates new buffer without data. Data for buffers like this should
+    /// be updated with method `update_dynamic_data`.
+    fn new_dynamic(attribute_component_count: GLint) -> VertexBuffer {
+        VertexBuffer::new_empty(attribute_component_count)
+    }
+
+    /// Creates OpenGL's buffer object.
+    fn new_empty(attribute_component_count: GLint) -> VertexBuffer {
         let mut id: GLuint = 0;
 
-        gl_raw::GenBuffers(1, &mut id);
-        gl_raw::BindBuffer(gl_raw::ARRAY_BUFFER, id);
+        unsafe {
+            gl_raw::GenBuffers(1, &mut id);
+        }
+
+        VertexBuffer {id, attribute_component_count}
+    }
+
+    /// Replaces buffer's data with new data. Previous data will be
+    /// discarded, so OpenGL doesn't have to wait until previous
+    /// draw calls using the buffer are finished.
+    fn update_dynamic_data(&mut self, data: &[f32]) {
+        unsafe {
+            self.buffer_data(data, gl_raw::DYNAMIC_DRAW);
+        }
+    }
+
+    /// Binds buffer and sends data to GPU.
+    ///
+    /// # Safety
+    /// Argument `usage` must be valid buffer usage value.
+    unsafe fn buffer_data(&self, data: &[f32], usage: GLenum) {
+        gl_raw::BindBuffer(gl_raw::ARRAY_BUFFER, self.id);
 
         let size: GLsizeiptr = (size_of::<f32>() * data.len()) as GLsizeiptr;
         let data_ptr = data.as_ptr() as *const c_void;
 
-        gl_raw::BufferData(gl_raw::ARRAY_BUFFER, size, data_ptr, gl_raw::STATIC_DRAW);
-
-        VertexBufferStatic {id, attribute_component_count}
+        gl_raw::BufferData(gl_raw::ARRAY_BUFFER, size, data_ptr, usage);
     }
 
     /// Set vertex attribute to match buffer data.
@@ -66,7 +97,7 @@ impl VertexBufferStatic {
     }
 }
 
-impl Drop for VertexBufferStatic {
+impl Drop for VertexBuffer {
 
     /// Deletes OpenGL's buffer object.
     fn drop(&mut self) {
@@ -84,19 +115,27 @@ impl Drop for VertexBufferStatic {
 /// OpenGL ES 2.0 does not support Vertex Array Objects, so vertex
 /// attributes are set for every buffer
 /// when `draw` method is called if using OpenGL ES version of this struct.
+///
+/// Buffers can be static or dynamic. Data of dynamic buffers can be
+/// updated, which changes the vertex count of `VertexArray`, so static
+/// and dynamic buffers should not be added to the same `VertexArray`.
 #[cfg(not(feature = "gles"))]
 pub struct VertexArray {
     id: GLuint,
-    vertex_buffers: Vec<VertexBufferStatic>,
+    vertex_buffers: Vec<VertexBuffer>,
     vertex_count: GLsizei,
 }
 
 #[cfg(feature = "gles")]
 pub struct VertexArray {
-    vertex_buffers: Vec<(VertexBufferStatic, GLuint)>,
+    vertex_buffers: Vec<(VertexBuffer, GLuint)>,
     vertex_count: GLsizei,
 }
 
+/// Index of dynamic buffer in `VertexArray`.
+#[derive(Copy, Clone, Debug)]
+pub struct DynamicBufferIndex(usize);
+
 
 impl VertexArray {
 
@@ -137,29 +176,78 @@ impl VertexArray {
             panic!("buffer length doesn't match with attribute_component_count");
         }
 
-        #[cfg(not(feature = "gles"))]
-        {
-            let mut buffer;
-
-            unsafe {
-                buffer = VertexBufferStatic::new(data, attribute_component_count);
-            }
+        let buffer;
 
-            self.bind();
-            buffer.set_vertex_attributes(attribute_index);
-            self.vertex_buffers.push(buffer);
+        unsafe {
+            buffer = VertexBuffer::new_static(data, attribute_component_count);
         }
 
-        #[cfg(feature = "gles")]
+        self.push_buffer(buffer, attribute_index);
+    }
+
+    /// Adds new dynamic buffer to Vertex Array Object. Buffer will not
+    /// contain any data before calling method `update_dynamic_buffer`.
+    ///
+    /// # Arguments
+    /// * `attribute_component_count` - Number of floats in one attribute.
+    /// * `attribute_index` - Index of vertex attribute.
+    pub fn add_dynamic_buffer(&mut self, attribute_component_count: GLint, attribute_index: GLuint) -> DynamicBufferIndex {
+        let buffer = VertexBuffer::new_dynamic(attribute_component_count);
+        self.push_buffer(buffer, attribute_index);
+
+        DynamicBufferIndex(self.vertex_buffers.len() - 1)
+    }
+
+    /// Replace data of dynamic buffer. Vertex count of `VertexArray` will be
+    /// set to match the new data, so update all dynamic buffers with
+    /// same amount of vertices before drawing.
+    ///
+    /// # Panics
+    /// * If buffer length doesn't match with buffer's attribute_component_count.
+    pub fn update_dynamic_buffer(&mut self, index: DynamicBufferIndex, data: &[f32]) {
+        let vertex_count;
+
         {
-            let buffer;
+            let buffer = self.buffer_mut(index.0);
+            let attribute_component_count = buffer.attribute_component_count as usize;
 
-            unsafe {
-                buffer = VertexBufferStatic::new(data, attribute_component_count);
+            if data.len() % attribute_component_count != 0 {
+                panic!("buffer length doesn't match with attribute_component_count");
             }
 
-            self.vertex_buffers.push((buffer, attribute_index));
+            vertex_count = data.len() / attribute_component_count;
+
+            buffer.update_dynamic_data(data);
         }
+
+        self.vertex_count = vertex_count as GLsizei;
+    }
+
+    /// Add buffer to `vertex_buffers` and set vertex attributes.
+    #[cfg(not(feature = "gles"))]
+    fn push_buffer(&mut self, mut buffer: VertexBuffer, attribute_index: GLuint) {
+        self.bind();
+        buffer.set_vertex_attributes(attribute_index);
+        self.vertex_buffers.push(buffer);
+    }
+
+    /// Add buffer to `vertex_buffers`. Vertex attributes will be set
+    /// when drawing.
+    #[cfg(feature = "gles")]
+    fn push_buffer(&mut self, buffer: VertexBuffer, attribute_index: GLuint) {
+        self.vertex_buffers.push((buffer, attribute_index));
+    }
+
+    /// Get buffer from `vertex_buffers`.
+    #[cfg(not(feature = "gles"))]
+    fn buffer_mut(&mut self, index: usize) -> &mut VertexBuffer {
+        &mut self.vertex_buffers[index]
+    }
+
+    /// Get buffer from `vertex_buffers`.
+    #[cfg(feature = "gles")]
+    fn buffer_mut(&mut self, index: usize) -> &mut VertexBuffer {
+        &mut self.vertex_buffers[index].0
     }
 
     /// Bind OpenGL's Vertex Array Object. This method
@@ -174,6 +262,17 @@ impl VertexArray {
     /// Draw with buffers currently existing buffers in `VertexArray`. Remember to enable
     /// correct shader `Program` with it's `use_program` method before calling this method.
     pub fn draw(&mut self) {
+        self.draw_with_mode(gl_raw::TRIANGLES);
+    }
+
+    /// Draw lines with currently existing buffers in `VertexArray`. Every two
+    /// vertices make one line. See also method `draw`.
+    pub fn draw_lines(&mut self) {
+        self.draw_with_mode(gl_raw::LINES);
+    }
+
+    /// Draw primitives specified by argument `mode`.
+    fn draw_with_mode(&mut self, mode: GLenum) {
         #[cfg(not(feature = "gles"))]
         {
             self.bind();
@@ -186,8 +285,12 @@ impl VertexArray {
             }
         }
 
+        if self.vertex_count == 0 {
+            return;
+        }
+
         unsafe {
-            gl_raw::DrawArrays(gl_raw::TRIANGLES, 0, self.vertex_count);
+            gl_raw::DrawArrays(mode, 0, self.vertex_count);
         }
     }
 }
@@ -0,0 +1,115 @@
+/*
+gl/src/gl_wrapper/framebuffer.rs, 2017-09-22
+
+Copyright (c) 2017 Juuso Tuononen
+
+This file is licensed under
+
+Apache License, Version 2.0
+
+or
+
+MIT License
+*/
+
+//! Render to texture.
+
+use super::gl_raw;
+use self::gl_raw::types::*;
+
+use gl_wrapper::texture::Texture;
+
+/// Error information about framebuffer creation.
+#[derive(Debug)]
+pub enum FramebufferError {
+    /// Value from OpenGL's function `CheckFramebufferStatus`.
+    Incomplete(GLenum),
+}
+
+/// Framebuffer Object with RGBA color texture. Supports
+/// OpenGL 3.3 and OpenGL ES 2.0.
+pub struct Framebuffer {
+    id: GLuint,
+    texture: Texture,
+    width: u32,
+    height: u32,
+}
+
+impl Framebuffer {
+    /// Create new framebuffer and texture for it. Default framebuffer will
+    /// be bound after creating the framebuffer.
+    ///
+    /// Returns error if OpenGL implementation does not support
+    /// rendering to the texture.
+    pub fn new(width: u32, height: u32) -> Result<Framebuffer, FramebufferError> {
+        let texture = Texture::new_render_target(width, height);
+
+        let mut id: GLuint = 0;
+
+        unsafe {
+            gl_raw::GenFramebuffers(1, &mut id);
+        }
+
+        let framebuffer = Framebuffer { id, texture, width, height };
+
+        let status;
+
+        unsafe {
+            gl_raw::BindFramebuffer(gl_raw::FRAMEBUFFER, framebuffer.id);
+            gl_raw::FramebufferTexture2D(gl_raw::FRAMEBUFFER, gl_raw::COLOR_ATTACHMENT0, gl_raw::TEXTURE_2D, framebuffer.texture.id(), 0);
+
+            status = gl_raw::CheckFramebufferStatus(gl_raw::FRAMEBUFFER);
+
+            gl_raw::BindFramebuffer(gl_raw::FRAMEBUFFER, 0);
+        }
+
+        if status == gl_raw::FRAMEBUFFER_COMPLETE {
+            Ok(framebuffer)
+        } else {
+            Err(FramebufferError::Incomplete(status))
+        }
+    }
+
+    /// Render to this framebuffer. OpenGL viewport will
+    /// be set to match framebuffer's size.
+    pub fn bind(&mut self) {
+        unsafe {
+            gl_raw::BindFramebuffer(gl_raw::FRAMEBUFFER, self.id);
+            gl_raw::Viewport(0, 0, self.width as GLsizei, self.height as GLsizei);
+        }
+    }
+
+    /// Render to the default framebuffer. OpenGL viewport will be
+    /// set to argument `width` and `height`.
+    pub fn bind_default(width: u32, height: u32) {
+        unsafe {
+            gl_raw::BindFramebuffer(gl_raw::FRAMEBUFFER, 0);
+            gl_raw::Viewport(0, 0, width as GLsizei, height as GLsizei);
+        }
+    }
+
+    /// Get framebuffer's color texture.
+    pub fn texture_mut(&mut self) -> &mut Texture {
+        &mut self.texture
+    }
+
+    /// Framebuffer width in pixels.
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Framebuffer height in pixels.
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+}
+
+impl Drop for Framebuffer {
+    /// Deletes OpenGL framebuffer object. Framebuffer's
+    /// texture will be deleted when field `texture` is dropped.
+    fn drop(&mut self) {
+        unsafe {
+            gl_raw::DeleteFramebuffers(1, &self.id);
+        }
+    }
+}
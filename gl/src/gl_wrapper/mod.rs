@@ -27,6 +27,7 @@ pub mod shader;
 pub mod uniform;
 pub mod buffer;
 pub mod texture;
+pub mod framebuffer;
 
 
 use gl_raw::types::*;
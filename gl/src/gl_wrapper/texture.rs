@@ -18,6 +18,7 @@ use super::gl_raw;
 use self::gl_raw::types::*;
 
 use std::os::raw::c_void;
+use std::ptr;
 
 /// Texture with RGB or RGBA color
 pub struct Texture {
@@ -66,12 +67,56 @@ impl Texture {
         texture
     }
 
+    /// Create RGBA texture without data for rendering to it
+    /// with `Framebuffer`. This function will also
+    /// * Set Texture wrap mode to clamp to edge.
+    /// * Set Texture filtering to linear.
+    ///
+    /// Mipmaps will not be generated, so width and height
+    /// doesn't have to be power of two with OpenGL ES 2.0.
+    pub fn new_render_target(width: u32, height: u32) -> Texture {
+        let mut id: GLuint = 0;
+
+        unsafe {
+            gl_raw::GenTextures(1, &mut id);
+        }
+
+        let mut texture = Texture {id};
+        texture.bind();
+
+        unsafe {
+            gl_raw::TexParameteri(gl_raw::TEXTURE_2D, gl_raw::TEXTURE_WRAP_S, gl_raw::CLAMP_TO_EDGE as GLint);
+            gl_raw::TexParameteri(gl_raw::TEXTURE_2D, gl_raw::TEXTURE_WRAP_T, gl_raw::CLAMP_TO_EDGE as GLint);
+            gl_raw::TexParameteri(gl_raw::TEXTURE_2D, gl_raw::TEXTURE_MIN_FILTER, gl_raw::LINEAR as GLint);
+            gl_raw::TexParameteri(gl_raw::TEXTURE_2D, gl_raw::TEXTURE_MAG_FILTER, gl_raw::LINEAR as GLint);
+
+            gl_raw::TexImage2D(gl_raw::TEXTURE_2D, 0, gl_raw::RGBA as GLint, width as GLsizei, height as GLsizei, 0, gl_raw::RGBA, gl_raw::UNSIGNED_BYTE, ptr::null());
+        }
+
+        texture
+    }
+
     /// Binds texture for rendering.
     pub fn bind(&mut self) {
         unsafe {
             gl_raw::BindTexture(gl_raw::TEXTURE_2D, self.id);
         }
     }
+
+    /// Binds texture to texture unit `unit`. Texture unit zero
+    /// will be set as active texture unit after binding.
+    pub fn bind_to_unit(&mut self, unit: u32) {
+        unsafe {
+            gl_raw::ActiveTexture(gl_raw::TEXTURE0 + unit);
+            gl_raw::BindTexture(gl_raw::TEXTURE_2D, self.id);
+            gl_raw::ActiveTexture(gl_raw::TEXTURE0);
+        }
+    }
+
+    /// OpenGL texture object id.
+    pub fn id(&self) -> GLuint {
+        self.id
+    }
 }
 
 impl Drop for Texture {
@@ -17,7 +17,7 @@ MIT License
 use super::gl_raw;
 use self::gl_raw::types::*;
 
-use cgmath::{Vector3, Matrix4};
+use cgmath::{Vector2, Vector3, Matrix4};
 use cgmath::prelude::*;
 
 use std::ffi::CString;
@@ -106,4 +106,61 @@ impl Uniform for UniformMatrix4 {
             gl_raw::UniformMatrix4fv(self.location, 1, gl_raw::FALSE, data.as_ptr());
         }
     }
-}
\ No newline at end of file
+}
+
+/// Uniform for f32
+pub struct UniformFloat {
+    location: GLint,
+}
+
+impl Uniform for UniformFloat {
+    type Data = f32;
+
+    unsafe fn from_location(location: GLint) -> UniformFloat {
+        UniformFloat {location}
+    }
+
+    fn send(&mut self, data: &Self::Data) {
+        unsafe {
+            gl_raw::Uniform1f(self.location, *data);
+        }
+    }
+}
+
+/// Uniform for Vector2
+pub struct UniformVector2 {
+    location: GLint,
+}
+
+impl Uniform for UniformVector2 {
+    type Data = Vector2<f32>;
+
+    unsafe fn from_location(location: GLint) -> UniformVector2 {
+        UniformVector2 {location}
+    }
+
+    fn send(&mut self, data: &Self::Data) {
+        unsafe {
+            gl_raw::Uniform2fv(self.location, 1, data.as_ptr());
+        }
+    }
+}
+
+/// Uniform for texture sampler. Data is index of texture unit.
+pub struct UniformSampler {
+    location: GLint,
+}
+
+impl Uniform for UniformSampler {
+    type Data = u32;
+
+    unsafe fn from_location(location: GLint) -> UniformSampler {
+        UniformSampler {location}
+    }
+
+    fn send(&mut self, data: &Self::Data) {
+        unsafe {
+            gl_raw::Uniform1i(self.location, *data as GLint);
+        }
+    }
+}
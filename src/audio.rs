@@ -14,6 +14,8 @@ MIT License
 
 //! Play sound effects and music.
 
+use paths::GamePaths;
+
 /// Play sound effects.
 pub trait SoundEffectPlayer {
     /// Play laser sound at next update.
@@ -65,14 +67,14 @@ struct AllSoundEffects<A: Audio> {
 impl <A: Audio> AllSoundEffects<A> {
 
     /// Loads all sound effects that the game requires.
-    fn new(default_volume: A::Volume) -> Result<Self, String> {
+    fn new(default_volume: A::Volume, paths: &GamePaths) -> Result<Self, String> {
 
         let mut sounds = AllSoundEffects {
-            laser:                  A::load("game_files/audio/laser.wav")?,
-            explosion:              A::load("game_files/audio/explosion.wav")?,
-            laser_bomb_launch:      A::load("game_files/audio/laser_bomb_launch.wav")?,
-            laser_bomb_explosion:   A::load("game_files/audio/laser_bomb_explosion.wav")?,
-            player_laser_hits_laser_cannon:   A::load("game_files/audio/player_laser_hits_laser_cannon.wav")?,
+            laser:                  A::load(&paths.data_file("audio/laser.wav"))?,
+            explosion:              A::load(&paths.data_file("audio/explosion.wav"))?,
+            laser_bomb_launch:      A::load(&paths.data_file("audio/laser_bomb_launch.wav"))?,
+            laser_bomb_explosion:   A::load(&paths.data_file("audio/laser_bomb_explosion.wav"))?,
+            player_laser_hits_laser_cannon:   A::load(&paths.data_file("audio/player_laser_hits_laser_cannon.wav"))?,
         };
 
         sounds.change_volume(default_volume);
@@ -202,10 +204,8 @@ impl <P: AudioPlayer> AudioManager<P> {
     ///
     /// If argument player is `None`, sound effects and music will be disabled.
     ///
-    /// All errors will be printed to standard output.
-    pub fn new(music_file_path: &str, player: Option<P>) -> Self {
-        println!("");
-
+    /// All errors will be logged.
+    pub fn new(music_file_path: &str, paths: &GamePaths, player: Option<P>) -> Self {
         let music_volume = <P::Music as Audio>::Volume::from_percentage(<P::Music as Audio>::Volume::DEFAULT_VOLUME_PERCENTAGE);
         let effect_volume = <P::Effect as Audio>::Volume::from_percentage(<P::Effect as Audio>::Volume::DEFAULT_VOLUME_PERCENTAGE);
 
@@ -217,15 +217,15 @@ impl <P: AudioPlayer> AudioManager<P> {
                         Some(music)
                     }
                     Err(error) => {
-                        println!("music loading error: {}", error);
+                        error!("music loading error: {}", error);
                         None
                     }
                 };
 
-                let all_sound_effects = match AllSoundEffects::new(effect_volume) {
+                let all_sound_effects = match AllSoundEffects::new(effect_volume, paths) {
                     Ok(sound_effects) => Some(sound_effects),
                     Err(error) => {
-                        println!("error when loading sound effects: {}", error);
+                        error!("error when loading sound effects: {}", error);
                         None
                     },
                 };
@@ -239,7 +239,7 @@ impl <P: AudioPlayer> AudioManager<P> {
                 }
             }
             None => {
-                println!("Audio support disabled");
+                warn!("Audio support disabled");
 
                 Self {
                     sound_effects: SoundEffectManager::new(None),
@@ -0,0 +1,139 @@
+/*
+src/console.rs, 2017-09-30
+
+Copyright (c) 2017 Juuso Tuononen
+
+This file is licensed under
+
+Apache License, Version 2.0
+
+or
+
+MIT License
+*/
+
+//! Developer console commands.
+//!
+//! Console is opened and closed with the console key. Command
+//! name and arguments are separated with spaces:
+//!
+//! ```text
+//! set_health enemy 10
+//! ```
+//!
+//! See `CONSOLE_COMMANDS` for supported commands.
+
+use logic::{Difficulty, LAST_LEVEL_INDEX, PLAYER_MAX_HEALTH, ENEMY_MAX_HEALTH};
+use settings::{SettingId, SettingValue, parse_setting};
+
+/// Supported commands and their arguments.
+pub const CONSOLE_COMMANDS: &[(&str, &str)] = &[
+    ("help", ""),
+    ("clear", ""),
+    ("level", "LEVEL"),
+    ("difficulty", "easy|normal|hard"),
+    ("god", ""),
+    ("set_health", "player|enemy HEALTH"),
+    ("spawn", "bomb"),
+    ("timescale", "SCALE"),
+    ("set", "SETTING_KEY VALUE"),
+];
+
+/// Value range of command `timescale`.
+pub const MIN_TIME_SCALE: f32 = 0.1;
+pub const MAX_TIME_SCALE: f32 = 10.0;
+
+/// Character which health is changed with command `set_health`.
+#[derive(Copy, Clone)]
+pub enum Character {
+    Player,
+    Enemy,
+}
+
+/// Game objects which can be created with command `spawn`.
+#[derive(Copy, Clone)]
+pub enum SpawnObject {
+    LaserBomb,
+}
+
+/// Parsed console command.
+pub enum ConsoleCommand {
+    /// List supported commands.
+    Help,
+    /// Clear console output.
+    Clear,
+    /// Start level with current difficulty. Level is index starting from zero.
+    Level(u32),
+    /// Restart current level with difficulty.
+    Difficulty(Difficulty),
+    /// Toggle god mode.
+    God,
+    SetHealth(Character, u32),
+    Spawn(SpawnObject),
+    /// Change speed of game time.
+    TimeScale(f32),
+    /// Change setting value like setting it from the settings menu.
+    Set(SettingId, SettingValue),
+}
+
+impl ConsoleCommand {
+    /// Parse command line.
+    ///
+    /// Returns `Err(error_message)` if command is unknown or arguments are invalid.
+    pub fn parse(line: &str) -> Result<ConsoleCommand, String> {
+        let mut words = line.split_whitespace();
+
+        let name = words.next().unwrap_or("");
+
+        let usage = match CONSOLE_COMMANDS.iter().find(|&&(command, _)| command == name) {
+            Some(&(_, arguments)) => format!("usage: {} {}", name, arguments),
+            None => return Err(format!("unknown command \"{}\", type \"help\" for command list", name)),
+        };
+
+        let command = match (name, words.next(), words.next(), words.next()) {
+            ("help", None, None, None) => ConsoleCommand::Help,
+            ("clear", None, None, None) => ConsoleCommand::Clear,
+            ("level", Some(level), None, None) => {
+                match level.parse::<u32>() {
+                    Ok(level) if 1 <= level && level <= LAST_LEVEL_INDEX + 1 => ConsoleCommand::Level(level - 1),
+                    _ => return Err(format!("level must be a number between 1 and {}", LAST_LEVEL_INDEX + 1)),
+                }
+            },
+            ("difficulty", Some(difficulty), None, None) => {
+                match difficulty {
+                    "easy" => ConsoleCommand::Difficulty(Difficulty::Easy),
+                    "normal" => ConsoleCommand::Difficulty(Difficulty::Normal),
+                    "hard" => ConsoleCommand::Difficulty(Difficulty::Hard),
+                    _ => return Err(format!("unknown difficulty \"{}\"", difficulty)),
+                }
+            },
+            ("god", None, None, None) => ConsoleCommand::God,
+            ("set_health", Some(character), Some(health), None) => {
+                let (character, max_health) = match character {
+                    "player" => (Character::Player, PLAYER_MAX_HEALTH as u32),
+                    "enemy" => (Character::Enemy, ENEMY_MAX_HEALTH as u32),
+                    _ => return Err(usage),
+                };
+
+                match health.parse::<u32>() {
+                    Ok(health) if health <= max_health => ConsoleCommand::SetHealth(character, health),
+                    _ => return Err(format!("health must be a number between 0 and {}", max_health)),
+                }
+            },
+            ("spawn", Some("bomb"), None, None) => ConsoleCommand::Spawn(SpawnObject::LaserBomb),
+            ("timescale", Some(scale), None, None) => {
+                match scale.parse::<f32>() {
+                    Ok(scale) if MIN_TIME_SCALE <= scale && scale <= MAX_TIME_SCALE => ConsoleCommand::TimeScale(scale),
+                    _ => return Err(format!("time scale must be a number between {} and {}", MIN_TIME_SCALE, MAX_TIME_SCALE)),
+                }
+            },
+            ("set", Some(key), Some(value), None) => {
+                let (id, value) = parse_setting(key, value)?;
+                ConsoleCommand::Set(id, value)
+            },
+            _ => return Err(usage),
+        };
+
+        Ok(command)
+    }
+}
@@ -14,10 +14,12 @@ MIT License
 
 //! GUI toolkit components.
 
-use cgmath::{Matrix4, Point2, Vector3};
+use std::ops::Range;
+
+use cgmath::{Matrix4, Point2, Vector3, Vector4};
 use cgmath::prelude::*;
 
-use renderer::{ModelMatrix, Color, TileLocationInfo};
+use renderer::{ModelMatrix, Color, Fonts};
 
 use super::GUIEvent;
 
@@ -31,9 +33,41 @@ const GUI_HEALTH_BAR_COLOR: Vector3<f32> = Vector3 { x: 0.0, y: 0.0, z: 1.0 };
 
 const GUI_BUTTON_COLOR:  Vector3<f32> = Vector3 { x: 0.0, y: 0.0, z: 0.4 };
 const GUI_BUTTON_SELECTED_COLOR:  Vector3<f32> = Vector3 { x: 0.0, y: 0.0, z: 1.0 };
+/// Duration of button's color change when selection changes.
+const GUI_BUTTON_HIGHLIGHT_MILLISECONDS: f32 = 120.0;
+
+
+const GUI_WIDGET_BACKGROUND_COLOR: Vector3<f32> = Vector3 { x: 0.0, y: 0.0, z: 0.4 };
+const GUI_WIDGET_BORDER_COLOR: Vector3<f32> = Vector3 { x: 0.3, y: 0.3, z: 0.8 };
+const GUI_WIDGET_SELECTED_COLOR: Vector3<f32> = Vector3 { x: 0.0, y: 0.0, z: 1.0 };
+
+const GUI_SLIDER_HEIGHT: f32 = 0.2;
+const GUI_SLIDER_HANDLE_WIDTH: f32 = 0.2;
+const GUI_SLIDER_HANDLE_HEIGHT: f32 = 0.6;
+const GUI_SLIDER_TRACK_COLOR: Vector3<f32> = Vector3 { x: 0.0, y: 0.0, z: 0.4 };
+const GUI_SLIDER_FILL_COLOR: Vector3<f32> = Vector3 { x: 0.3, y: 0.3, z: 0.8 };
+const GUI_SLIDER_HANDLE_COLOR: Vector3<f32> = Vector3 { x: 0.6, y: 0.6, z: 0.9 };
+
+const GUI_CHECKBOX_SIZE: f32 = 0.6;
+const GUI_CHECKBOX_BORDER_WIDTH: f32 = 0.05;
+const GUI_CHECKBOX_MARK_SIZE: f32 = 0.3;
+const GUI_CHECKBOX_MARK_COLOR: Vector3<f32> = Vector3 { x: 1.0, y: 1.0, z: 1.0 };
+
+const GUI_DROPDOWN_HEIGHT: f32 = 0.8;
+/// Distance of arrow text centers from dropdown's left and right side.
+const GUI_DROPDOWN_ARROW_MARGIN: f32 = 0.3;
 
+const GUI_SCROLL_BAR_WIDTH: f32 = 0.2;
+const GUI_SCROLL_BAR_MIN_THUMB_HEIGHT: f32 = 0.3;
+const GUI_SCROLL_BAR_TRACK_COLOR: Vector3<f32> = Vector3 { x: 0.0, y: 0.0, z: 0.4 };
+const GUI_SCROLL_BAR_THUMB_COLOR: Vector3<f32> = Vector3 { x: 0.3, y: 0.3, z: 0.8 };
 
 const GUI_TEXT_MARGIN_LEFT_RIGHT: f32 = 0.1;
+/// Add little offset in y direction to make text look centered
+/// in y direction, because in the current font, the letters are not in center.
+const GUI_TEXT_OFFSET_Y: f32 = 0.04;
+const GUI_TEXT_DEFAULT_FONT_SIZE: f32 = 0.57;
+const GUI_TEXT_DEFAULT_COLOR: Vector4<f32> = Vector4 { x: 1.0, y: 1.0, z: 1.0, w: 1.0 };
 
 
 /// Macro for implementing `ModelMatrix` trait.
@@ -216,6 +250,10 @@ pub struct GUIButton {
     text: GUIText,
     color: Vector3<f32>,
     event_data: GUIEvent,
+    selected: bool,
+    /// Amount of selected color in button's color. Value is
+    /// in range [0.0, 1.0] and it moves towards selection state.
+    highlight: f32,
 }
 
 impl GUIButton {
@@ -226,8 +264,10 @@ impl GUIButton {
         let mut button = GUIButton {
             rectangle: GUIRectangle::new(Point2 {x, y}, width, height),
             text: GUIText::new(x, y, text),
-            color: Vector3::zero(),
+            color: GUI_BUTTON_COLOR,
             event_data,
+            selected: false,
+            highlight: 0.0,
         };
 
         button.set_state(GUIComponentState::Normal);
@@ -235,10 +275,43 @@ impl GUIButton {
         button
     }
 
+    /// Move button's color towards the color of
+    /// current selection state.
+    pub fn update_highlight(&mut self, milliseconds: u32) {
+        let change = milliseconds as f32 / GUI_BUTTON_HIGHLIGHT_MILLISECONDS;
+
+        self.highlight = if self.selected {
+            (self.highlight + change).min(1.0)
+        } else {
+            (self.highlight - change).max(0.0)
+        };
+
+        self.color = GUI_BUTTON_COLOR + (GUI_BUTTON_SELECTED_COLOR - GUI_BUTTON_COLOR) * self.highlight;
+    }
+
     /// Get button's `GUIText`.
     pub fn get_text(&self) -> &GUIText {
         &self.text
     }
+
+    /// Change button's text.
+    pub fn change_text(&mut self, text: &str) {
+        self.text.change_text(text);
+    }
+
+    /// Set x coordinate of button's center.
+    pub fn set_x(&mut self, x: f32) {
+        self.rectangle.position_mut().x = x;
+        self.rectangle.update_model_matrix();
+        self.text.set_x(x);
+    }
+
+    /// Set y coordinate of button's center.
+    pub fn set_y(&mut self, y: f32) {
+        self.rectangle.position_mut().y = y;
+        self.rectangle.update_model_matrix();
+        self.text.set_y(y);
+    }
 }
 
 impl_model_matrix!(GUIButton, rectangle);
@@ -250,12 +323,13 @@ impl GUIUserInteraction for GUIButton {
         self.rectangle.axis_aligned_rectangle_and_point_collision(point)
     }
 
-    /// Sets button color according to argument `state`.
+    /// Sets button's selection state. Button's color will change
+    /// when method `update_highlight` is called.
     fn set_state(&mut self, state: GUIComponentState) {
-        match state {
-            GUIComponentState::Normal => self.color = GUI_BUTTON_COLOR,
-            GUIComponentState::Selected => self.color = GUI_BUTTON_SELECTED_COLOR,
-        }
+        self.selected = match state {
+            GUIComponentState::Normal => false,
+            GUIComponentState::Selected => true,
+        };
     }
 
     fn event_data(&self) -> GUIEvent {
@@ -410,6 +484,23 @@ impl <T: GUIUserInteraction> GUIGroup<T> {
         }
     }
 
+    /// Index of currently selected component.
+    pub fn selected_index(&self) -> usize {
+        self.selected
+    }
+
+    /// Select component with index. Index is
+    /// ignored if it is out of bounds.
+    pub fn set_selected(&mut self, index: usize) {
+        if index >= self.components.len() {
+            return;
+        }
+
+        self.components[self.selected].set_state(GUIComponentState::Normal);
+        self.selected = index;
+        self.components[self.selected].set_state(GUIComponentState::Selected);
+    }
+
     /// Sets new event to currently selected component.
     pub fn set_event_of_currently_selected_component(&mut self, event: GUIEvent) {
         self.components[self.selected].set_event_data(event);
@@ -433,133 +524,19 @@ impl <T: GUIUserInteraction> GUIGroup<T> {
 }
 
 
-/// Tile from tile map.
-///
-/// For information about `tile_info` field, check renderer documentation.
-pub struct Tile {
-    rectangle: GUIRectangle<f32>,
-    tile_info: Vector3<f32>,
-}
-
-impl Tile {
-    /// Create new `Tile`.
-    ///
-    /// This will assume that tile size is 16x16 pixels and tile map size is 256x256 pixels.
-    ///
-    /// # Index argument
-    /// * Starts from top left corner with (0,0);
-    pub fn new((x, y): (u32, u32), gui_rectangle: GUIRectangle<f32>) -> Tile {
-        let tile_size = 1.0/16.0;
-        let x_movement = tile_size * x as f32;
-        let y_movement = 1.0 - tile_size * (y + 1) as f32;
-
-        Tile {
-            rectangle: gui_rectangle,
-            tile_info: Vector3::new(x_movement, y_movement, tile_size),
-        }
-    }
-
-    /// Sets new `GUIRectangle` for a `Tile`. This means setting the location
-    /// where tile will be rendered.
-    pub fn set_gui_rectangle(&mut self, gui_rectangle: GUIRectangle<f32>) {
-        self.rectangle = gui_rectangle;
-    }
-}
-
-impl_model_matrix!(Tile, rectangle);
-
-impl TileLocationInfo for Tile {
-    fn tile_info(&self) -> &Vector3<f32> {
-        &self.tile_info
-    }
-}
-
-/// Convert char to position index at game's font tile map.
-///
-/// First item in tuple will be x index, and second item will be y index.
-fn tile_map_index_from_char(c: char) -> (u32, u32) {
-    match c {
-        '0' => (0,0),
-        '1' => (1,0),
-        '2' => (2,0),
-        '3' => (3,0),
-        '4' => (4,0),
-        '5' => (5,0),
-        '6' => (6,0),
-        '7' => (7,0),
-        '8' => (8,0),
-        '9' => (9,0),
-        'A' => (10,0),
-        'B' => (11,0),
-        'C' => (12,0),
-        'D' => (13,0),
-        'E' => (14,0),
-        'F' => (15,0),
-
-        'G' => (0,1),
-        'H' => (1,1),
-        'I' => (2,1),
-        'J' => (3,1),
-        'K' => (4,1),
-        'L' => (5,1),
-        'M' => (6, 1),
-        'N' => (7, 1),
-        'O' => (8, 1),
-        'P' => (9, 1),
-        'Q' => (10,1),
-        'R' => (11,1),
-        'S' => (12,1),
-        'T' => (13,1),
-        'U' => (14,1),
-        'V' => (15,1),
-
-        'W' => (0,2),
-        'X' => (1,2),
-        'Y' => (2,2),
-        'Z' => (3,2),
-        ' ' => (4,2),
-        'a' => (5, 2),
-        'b' => (6, 2),
-        'c' => (7, 2),
-        'd' => (8, 2),
-        'e' => (9,2),
-        'f' => (10,2),
-        'g' => (11,2),
-        'h' => (12,2),
-        'i' => (13,2),
-        'j' => (14,2),
-        'k' => (15, 2),
-
-        'l' => (0, 3),
-        'm' => (1, 3),
-        'n' => (2, 3),
-        'o' => (3, 3),
-        'p' => (4, 3),
-        'q' => (5, 3),
-        'r' => (6, 3),
-        's' => (7, 3),
-        't' => (8, 3),
-        'u' => (9, 3),
-        'v' => (10,3),
-        'w' => (11,3),
-        'x' => (12,3),
-        'y' => (13,3),
-        'z' => (14,3),
-
-        _ => tile_map_index_from_char(' '),
-
-    }
-}
-
 /// Text for GUI.
 ///
-/// Text will be rendered as tiles from tile map font.
+/// Text will be rendered with a bitmap font. Glyph positions are
+/// calculated by the renderer, because the renderer has the font metrics.
 pub struct GUIText {
-    tiles: Vec<Tile>,
+    text: String,
+    /// Anchor point of the text. Alignment
+    /// sets which side of the text is at the anchor point.
     position: Point2<f32>,
+    /// Line height in world coordinates.
     font_size: f32,
-    tile_width: f32,
-    width: f32,
+    color: Vector4<f32>,
+    font: Fonts,
     alignment: GUIComponentAlignment,
 }
 
@@ -572,16 +549,15 @@ impl GUIText {
     /// Create new text.
     pub fn new_with_alignment(x: f32, y: f32, text: &str, alignment: GUIComponentAlignment) -> GUIText {
         let mut gui_text = GUIText {
-            tiles: Vec::new(),
-            // Add little offset in y direction to make text look centered
-            // in y direction, because in the current tile map font, the letters are not in center.
-            position: Point2 {x, y: y - 0.04},
-            font_size: 0.57,
-            tile_width: 0.0,
-            width: 0.0,
+            text: String::new(),
+            position: Point2 {x, y: y - GUI_TEXT_OFFSET_Y},
+            font_size: GUI_TEXT_DEFAULT_FONT_SIZE,
+            color: GUI_TEXT_DEFAULT_COLOR,
+            font: Fonts::Default,
             alignment,
         };
 
+        gui_text.set_x(x);
         gui_text.change_text(text);
 
         gui_text
@@ -589,64 +565,77 @@ impl GUIText {
 
     /// Update `GUIText` to have a new text.
     pub fn change_text(&mut self, text: &str) {
-        self.tiles.clear();
-
-        let text_len = text.len() as f32;
-
-        self.tile_width = self.font_size - 0.17;
-        self.width = text_len * self.tile_width;
-
-        let mut x = self.calculate_component_position(self.position.x);
-
-        for c in text.chars() {
-            let rectangle = GUIRectangle::new(Point2{ x, .. self.position }, self.font_size, self.font_size);
+        self.text.clear();
+        self.text.push_str(text);
+    }
 
-            self.tiles.push(Tile::new(tile_map_index_from_char(c), rectangle));
+    /// Get text.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
 
-            x += self.tile_width;
-        }
+    /// Anchor point of the text. Margin is already added to the x coordinate.
+    pub fn position(&self) -> &Point2<f32> {
+        &self.position
     }
 
-    /// Get tiles.
-    pub fn get_tiles(&self) -> &Vec<Tile> {
-        &self.tiles
+    /// Get font size.
+    pub fn font_size(&self) -> f32 {
+        self.font_size
     }
-}
 
-impl GUIPosition for GUIText {
-    /// Text width.
-    fn width(&self) -> f32 { self.width }
-    fn alignment(&self) -> GUIComponentAlignment {self.alignment}
+    /// Set font size. Font size is the line height in world coordinates.
+    pub fn set_font_size(&mut self, font_size: f32) {
+        self.font_size = font_size;
+    }
 
-    fn set_x(&mut self, x: f32) {
-        self.position.x = x;
+    /// Get text color.
+    pub fn color(&self) -> &Vector4<f32> {
+        &self.color
+    }
 
-        let mut x = x;
+    /// Set text color.
+    pub fn set_color(&mut self, color: Vector4<f32>) {
+        self.color = color;
+    }
 
-        for tile in &mut self.tiles {
-            let rectangle = GUIRectangle::new(Point2 {x, .. self.position}, self.font_size, self.font_size);
-            tile.set_gui_rectangle(rectangle);
+    /// Get font.
+    pub fn font(&self) -> Fonts {
+        self.font
+    }
 
-            x += self.tile_width;
-        }
+    /// Set font.
+    pub fn set_font(&mut self, font: Fonts) {
+        self.font = font;
     }
 
-    fn calculate_component_position(&self, new_x: f32) -> f32 {
-        let x;
+    /// Get current alignment setting.
+    pub fn alignment(&self) -> GUIComponentAlignment {
+        self.alignment
+    }
 
-        match self.alignment {
-            GUIComponentAlignment::Left   => x = new_x + self.tile_width/2.0 + GUI_TEXT_MARGIN_LEFT_RIGHT,
-            GUIComponentAlignment::Center => x = new_x - self.width/2.0 + self.tile_width/2.0,
-            GUIComponentAlignment::Right  => x = new_x - self.width + self.tile_width/2.0 - GUI_TEXT_MARGIN_LEFT_RIGHT,
+    /// Set anchor point's x coordinate. Texts with left or
+    /// right alignment will have a small margin to the anchor point.
+    pub fn set_x(&mut self, x: f32) {
+        self.position.x = match self.alignment {
+            GUIComponentAlignment::Left   => x + GUI_TEXT_MARGIN_LEFT_RIGHT,
+            GUIComponentAlignment::Center => x,
+            GUIComponentAlignment::Right  => x - GUI_TEXT_MARGIN_LEFT_RIGHT,
         };
+    }
 
-        x
+    /// Set y coordinate of the line's center.
+    pub fn set_y(&mut self, y: f32) {
+        self.position.y = y - GUI_TEXT_OFFSET_Y;
     }
 
-    fn update_position_from_half_screen_width(&mut self, width: f32) {
-        match self.alignment() {
-            GUIComponentAlignment::Left => self.update_component_position(-width),
-            GUIComponentAlignment::Right => self.update_component_position(width),
+    /// Move text to the screen edge if text has left or right alignment.
+    ///
+    /// Argument `width` is screen_width/2.0.
+    pub fn update_position_from_half_screen_width(&mut self, width: f32) {
+        match self.alignment {
+            GUIComponentAlignment::Left => self.set_x(-width),
+            GUIComponentAlignment::Right => self.set_x(width),
             _ => (),
         }
     }
@@ -656,32 +645,31 @@ impl GUIPosition for GUIText {
 /// FPS counter positioned to the left side of the screen.
 pub struct GUIFpsCounter {
     fps_text: GUIText,
-    fps_count_text: GUIText,
     show_fps: bool,
 }
 
 impl GUIFpsCounter {
     /// Create new `GUIFpsCounter`
     pub fn new(x: f32, y: f32) -> GUIFpsCounter {
-        let fps_text = GUIText::new_with_alignment(x, y, "FPS ", GUIComponentAlignment::Left);
-        let fps_count_text = GUIText::new_with_alignment(x + fps_text.width(), y, "0", GUIComponentAlignment::Left);
+        let mut fps_text = GUIText::new_with_alignment(x, y, "FPS 0", GUIComponentAlignment::Left);
+        // Monospace font keeps text from jumping when fps count changes.
+        fps_text.set_font(Fonts::Monospace);
 
         GUIFpsCounter {
             fps_text,
-            fps_count_text,
             show_fps: false,
         }
     }
 
     /// Set new fps count.
     pub fn update_fps_count(&mut self, fps_count: u32) {
-        let text = fps_count.to_string();
-        self.fps_count_text.change_text(&text);
+        let text = format!("FPS {}", fps_count);
+        self.fps_text.change_text(&text);
     }
 
-    /// Get texts of `GUIFpsCounter`.
-    pub fn texts(&self) -> [&GUIText; 2] {
-        [&self.fps_text, &self.fps_count_text]
+    /// Get text of `GUIFpsCounter`.
+    pub fn text(&self) -> &GUIText {
+        &self.fps_text
     }
 
     /// Get fps counter visibility.
@@ -699,12 +687,606 @@ impl GUIFpsCounter {
     /// Argument `width` is screen_width/2.0.
     pub fn update_position_from_half_screen_width(&mut self, width: f32) {
         self.fps_text.update_position_from_half_screen_width(width);
-        self.fps_count_text.update_position_from_half_screen_width(width - self.fps_text.width());
     }
 }
 
 
-// TODO: Rename GUIHealthBar to GUISlider?
+/// `GUIRectangle` with color.
+pub struct GUIColorRectangle {
+    rectangle: GUIRectangle<f32>,
+    color: Vector3<f32>,
+}
+
+impl GUIColorRectangle {
+    /// Create new `GUIColorRectangle`.
+    pub fn new(position: Point2<f32>, width: f32, height: f32, color: Vector3<f32>) -> GUIColorRectangle {
+        GUIColorRectangle {
+            rectangle: GUIRectangle::new(position, width, height),
+            color,
+        }
+    }
+
+    /// Set x coordinate of the center and width.
+    pub fn set_x_and_width(&mut self, x: f32, width: f32) {
+        self.rectangle.position_mut().x = x;
+        self.rectangle.set_width(width);
+        self.rectangle.update_model_matrix();
+    }
+
+    /// Set y coordinate of the center.
+    fn set_y(&mut self, y: f32) {
+        self.rectangle.position_mut().y = y;
+        self.rectangle.update_model_matrix();
+    }
+
+    /// Set y coordinate of the center and height.
+    fn set_y_and_height(&mut self, y: f32, height: f32) {
+        self.rectangle.position_mut().y = y;
+        self.rectangle.height = height;
+        self.rectangle.update_model_matrix();
+    }
+}
+
+impl_model_matrix!(GUIColorRectangle, rectangle);
+impl_color!(GUIColorRectangle);
+
+
+/// Slider for selecting a value from a range.
+///
+/// Value can be changed with steps or by setting the
+/// value from a point, which enables mouse dragging.
+pub struct GUISlider {
+    /// Track, filled part of the track and handle.
+    rectangles: [GUIColorRectangle; 3],
+    x: f32,
+    width: f32,
+    min: f32,
+    max: f32,
+    step: f32,
+    value: f32,
+    alignment: GUIComponentAlignment,
+    event_data: GUIEvent,
+}
+
+impl GUISlider {
+    /// Create new `GUISlider` with `GUIComponentAlignment::Center`.
+    ///
+    /// Argument `step` is the step size for method `step`. Values set from a point
+    /// are rounded to the nearest step.
+    pub fn new(x: f32, y: f32, width: f32, min: f32, max: f32, step: f32, value: f32, event_data: GUIEvent) -> GUISlider {
+        let position = Point2::new(x, y);
+
+        let mut slider = GUISlider {
+            rectangles: [
+                GUIColorRectangle::new(position, width, GUI_SLIDER_HEIGHT, GUI_SLIDER_TRACK_COLOR),
+                GUIColorRectangle::new(position, 0.0, GUI_SLIDER_HEIGHT, GUI_SLIDER_FILL_COLOR),
+                GUIColorRectangle::new(position, GUI_SLIDER_HANDLE_WIDTH, GUI_SLIDER_HANDLE_HEIGHT, GUI_SLIDER_HANDLE_COLOR),
+            ],
+            x,
+            width,
+            min,
+            max,
+            step,
+            value: min,
+            alignment: GUIComponentAlignment::Center,
+            event_data,
+        };
+
+        slider.set_value(value);
+
+        slider
+    }
+
+    /// Get current value.
+    pub fn value(&self) -> f32 {
+        self.value
+    }
+
+    /// Set value. Value will be clamped to slider's range.
+    pub fn set_value(&mut self, value: f32) {
+        self.value = value.max(self.min).min(self.max);
+        self.update_rectangles();
+    }
+
+    /// Change value by `steps` times the step size.
+    pub fn step(&mut self, steps: i32) {
+        let value = self.value + self.step * steps as f32;
+        self.set_value(value);
+    }
+
+    /// Set value from x coordinate of a point. Value will be
+    /// rounded to the nearest step.
+    pub fn set_value_from_point(&mut self, point: &Point2<f32>) {
+        let left = self.x - self.width/2.0;
+        let position = ((point.x - left) / self.width).max(0.0).min(1.0);
+        let mut value = self.min + position * (self.max - self.min);
+
+        if self.step > 0.0 {
+            value = self.min + ((value - self.min) / self.step).round() * self.step;
+        }
+
+        self.set_value(value);
+    }
+
+    /// Get rectangles of the slider.
+    pub fn rectangles(&self) -> &[GUIColorRectangle] {
+        &self.rectangles
+    }
+
+    /// Set y coordinate of slider's center.
+    pub fn set_y(&mut self, y: f32) {
+        for rectangle in &mut self.rectangles {
+            rectangle.set_y(y);
+        }
+    }
+
+    /// Update filled part of the track and handle position.
+    fn update_rectangles(&mut self) {
+        let range = self.max - self.min;
+        let position = if range > 0.0 { (self.value - self.min) / range } else { 0.0 };
+
+        let left = self.x - self.width/2.0;
+        let fill_width = self.width * position;
+
+        self.rectangles[0].set_x_and_width(self.x, self.width);
+        self.rectangles[1].set_x_and_width(left + fill_width/2.0, fill_width);
+        self.rectangles[2].set_x_and_width(left + fill_width, GUI_SLIDER_HANDLE_WIDTH);
+    }
+}
+
+impl GUIUserInteraction for GUISlider {
+    fn collision(&self, point: &Point2<f32>) -> bool {
+        // Handle is higher than the track, so use handle's height for collision.
+        let rectangle = GUIRectangle::new(Point2::new(self.x, self.rectangles[0].rectangle.position.y), self.width + GUI_SLIDER_HANDLE_WIDTH, GUI_SLIDER_HANDLE_HEIGHT);
+        rectangle.axis_aligned_rectangle_and_point_collision(point)
+    }
+
+    /// Sets handle color according to argument `state`.
+    fn set_state(&mut self, state: GUIComponentState) {
+        self.rectangles[2].color = match state {
+            GUIComponentState::Normal => GUI_SLIDER_HANDLE_COLOR,
+            GUIComponentState::Selected => GUI_WIDGET_SELECTED_COLOR,
+        };
+    }
+
+    fn event_data(&self) -> GUIEvent {
+        self.event_data
+    }
+
+    fn set_event_data(&mut self, data: GUIEvent) {
+        self.event_data = data;
+    }
+}
+
+impl GUIPosition for GUISlider {
+    fn width(&self) -> f32 { self.width }
+    fn alignment(&self) -> GUIComponentAlignment { self.alignment }
+    fn set_x(&mut self, x: f32) {
+        self.x = x;
+        self.update_rectangles();
+    }
+
+    fn update_position_from_half_screen_width(&mut self, width: f32) {
+        match self.alignment() {
+            GUIComponentAlignment::Left => self.update_component_position(-width),
+            GUIComponentAlignment::Right => self.update_component_position(width),
+            _ => (),
+        }
+    }
+}
+
+
+/// Checkbox for boolean values.
+pub struct GUICheckbox {
+    /// Border, background and check mark.
+    rectangles: [GUIColorRectangle; 3],
+    checked: bool,
+    alignment: GUIComponentAlignment,
+    event_data: GUIEvent,
+}
+
+impl GUICheckbox {
+    /// Create new `GUICheckbox` with `GUIComponentAlignment::Center`.
+    pub fn new(x: f32, y: f32, checked: bool, event_data: GUIEvent) -> GUICheckbox {
+        let position = Point2::new(x, y);
+        let background_size = GUI_CHECKBOX_SIZE - GUI_CHECKBOX_BORDER_WIDTH*2.0;
+
+        GUICheckbox {
+            rectangles: [
+                GUIColorRectangle::new(position, GUI_CHECKBOX_SIZE, GUI_CHECKBOX_SIZE, GUI_WIDGET_BORDER_COLOR),
+                GUIColorRectangle::new(position, background_size, background_size, GUI_WIDGET_BACKGROUND_COLOR),
+                GUIColorRectangle::new(position, GUI_CHECKBOX_MARK_SIZE, GUI_CHECKBOX_MARK_SIZE, GUI_CHECKBOX_MARK_COLOR),
+            ],
+            checked,
+            alignment: GUIComponentAlignment::Center,
+            event_data,
+        }
+    }
+
+    /// Is checkbox checked.
+    pub fn checked(&self) -> bool {
+        self.checked
+    }
+
+    /// Set checkbox checked or unchecked.
+    pub fn set_checked(&mut self, checked: bool) {
+        self.checked = checked;
+    }
+
+    /// Toggle checkbox.
+    pub fn toggle(&mut self) {
+        self.checked = !self.checked;
+    }
+
+    /// Set y coordinate of checkbox's center.
+    pub fn set_y(&mut self, y: f32) {
+        for rectangle in &mut self.rectangles {
+            rectangle.set_y(y);
+        }
+    }
+
+    /// Get rectangles which should be rendered. Check mark
+    /// is included only if checkbox is checked.
+    pub fn rectangles(&self) -> &[GUIColorRectangle] {
+        if self.checked {
+            &self.rectangles
+        } else {
+            &self.rectangles[..2]
+        }
+    }
+}
+
+impl GUIUserInteraction for GUICheckbox {
+    fn collision(&self, point: &Point2<f32>) -> bool {
+        self.rectangles[0].rectangle.axis_aligned_rectangle_and_point_collision(point)
+    }
+
+    /// Sets border color according to argument `state`.
+    fn set_state(&mut self, state: GUIComponentState) {
+        self.rectangles[0].color = match state {
+            GUIComponentState::Normal => GUI_WIDGET_BORDER_COLOR,
+            GUIComponentState::Selected => GUI_WIDGET_SELECTED_COLOR,
+        };
+    }
+
+    fn event_data(&self) -> GUIEvent {
+        self.event_data
+    }
+
+    fn set_event_data(&mut self, data: GUIEvent) {
+        self.event_data = data;
+    }
+}
+
+impl GUIPosition for GUICheckbox {
+    fn width(&self) -> f32 { GUI_CHECKBOX_SIZE }
+    fn alignment(&self) -> GUIComponentAlignment { self.alignment }
+    fn set_x(&mut self, x: f32) {
+        for rectangle in &mut self.rectangles {
+            let width = rectangle.rectangle.width();
+            rectangle.set_x_and_width(x, width);
+        }
+    }
+
+    fn update_position_from_half_screen_width(&mut self, width: f32) {
+        match self.alignment() {
+            GUIComponentAlignment::Left => self.update_component_position(-width),
+            GUIComponentAlignment::Right => self.update_component_position(width),
+            _ => (),
+        }
+    }
+}
+
+
+/// Dropdown which cycles through options. Current option
+/// is displayed between arrows.
+pub struct GUIDropdown {
+    background: GUIColorRectangle,
+    /// Current option, left arrow and right arrow.
+    texts: [GUIText; 3],
+    options: Vec<String>,
+    selected: usize,
+    x: f32,
+    width: f32,
+    alignment: GUIComponentAlignment,
+    event_data: GUIEvent,
+}
+
+impl GUIDropdown {
+    /// Create new `GUIDropdown` with `GUIComponentAlignment::Center`.
+    ///
+    /// # Panics
+    /// If `options` is empty.
+    pub fn new(x: f32, y: f32, width: f32, options: Vec<String>, selected: usize, event_data: GUIEvent) -> GUIDropdown {
+        if options.is_empty() {
+            panic!("GUIDropdown must have at least one option.");
+        }
+
+        let arrow_x = width/2.0 - GUI_DROPDOWN_ARROW_MARGIN;
+
+        let mut dropdown = GUIDropdown {
+            background: GUIColorRectangle::new(Point2::new(x, y), width, GUI_DROPDOWN_HEIGHT, GUI_WIDGET_BACKGROUND_COLOR),
+            texts: [
+                GUIText::new(x, y, ""),
+                GUIText::new(x - arrow_x, y, "<"),
+                GUIText::new(x + arrow_x, y, ">"),
+            ],
+            options,
+            selected: 0,
+            x,
+            width,
+            alignment: GUIComponentAlignment::Center,
+            event_data,
+        };
+
+        dropdown.set_selected(selected);
+
+        dropdown
+    }
+
+    /// Index of selected option.
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    /// Select option. Index will be clamped to the option list.
+    pub fn set_selected(&mut self, index: usize) {
+        self.selected = index.min(self.options.len() - 1);
+        self.texts[0].change_text(&self.options[self.selected]);
+    }
+
+    /// Move selection by `steps`. Selection wraps
+    /// around to the other end of the option list.
+    pub fn step(&mut self, steps: i32) {
+        let count = self.options.len() as i32;
+        let index = ((self.selected as i32 + steps) % count + count) % count;
+        self.set_selected(index as usize);
+    }
+
+    /// Replace option texts. Useful when language changes.
+    ///
+    /// # Panics
+    /// If `options` is empty.
+    pub fn set_options(&mut self, options: Vec<String>) {
+        if options.is_empty() {
+            panic!("GUIDropdown must have at least one option.");
+        }
+
+        self.options = options;
+        let selected = self.selected;
+        self.set_selected(selected);
+    }
+
+    /// Step direction for a point in the dropdown. Left half of the
+    /// dropdown selects the previous option and right half the next option.
+    pub fn step_from_point(&self, point: &Point2<f32>) -> i32 {
+        if point.x < self.x { -1 } else { 1 }
+    }
+
+    /// Get background rectangle.
+    pub fn background(&self) -> &GUIColorRectangle {
+        &self.background
+    }
+
+    /// Get texts.
+    pub fn texts(&self) -> &[GUIText] {
+        &self.texts
+    }
+
+    /// Set y coordinate of dropdown's center.
+    pub fn set_y(&mut self, y: f32) {
+        self.background.set_y(y);
+
+        for text in &mut self.texts {
+            text.set_y(y);
+        }
+    }
+}
+
+impl GUIUserInteraction for GUIDropdown {
+    fn collision(&self, point: &Point2<f32>) -> bool {
+        self.background.rectangle.axis_aligned_rectangle_and_point_collision(point)
+    }
+
+    /// Sets background color according to argument `state`.
+    fn set_state(&mut self, state: GUIComponentState) {
+        self.background.color = match state {
+            GUIComponentState::Normal => GUI_WIDGET_BACKGROUND_COLOR,
+            GUIComponentState::Selected => GUI_WIDGET_SELECTED_COLOR,
+        };
+    }
+
+    fn event_data(&self) -> GUIEvent {
+        self.event_data
+    }
+
+    fn set_event_data(&mut self, data: GUIEvent) {
+        self.event_data = data;
+    }
+}
+
+impl GUIPosition for GUIDropdown {
+    fn width(&self) -> f32 { self.width }
+    fn alignment(&self) -> GUIComponentAlignment { self.alignment }
+    fn set_x(&mut self, x: f32) {
+        self.x = x;
+        let width = self.width;
+        self.background.set_x_and_width(x, width);
+
+        let arrow_x = width/2.0 - GUI_DROPDOWN_ARROW_MARGIN;
+        self.texts[0].set_x(x);
+        self.texts[1].set_x(x - arrow_x);
+        self.texts[2].set_x(x + arrow_x);
+    }
+
+    fn update_position_from_half_screen_width(&mut self, width: f32) {
+        match self.alignment() {
+            GUIComponentAlignment::Left => self.update_component_position(-width),
+            GUIComponentAlignment::Right => self.update_component_position(width),
+            _ => (),
+        }
+    }
+}
+
+
+/// Viewport for a vertical list of rows.
+///
+/// List doesn't own the components of the rows. Method `row_y` gives
+/// positions for the rows. Only rows returned by `visible_rows` should be
+/// rendered and handle input. Scroll bar is displayed only if some rows
+/// don't fit into the viewport.
+pub struct GUIScrollList {
+    /// Track and thumb.
+    scroll_bar: [GUIColorRectangle; 2],
+    /// Y coordinate of the first row of the viewport.
+    top_y: f32,
+    row_height: f32,
+    row_count: usize,
+    visible_row_count: usize,
+    /// Index of the first row of the viewport.
+    first_visible_row: usize,
+}
+
+impl GUIScrollList {
+    /// Create new `GUIScrollList` scrolled to the first row.
+    ///
+    /// Argument `top_y` is the y coordinate of the first row of the viewport
+    /// and `scroll_bar_x` is the x coordinate of the scroll bar's center.
+    pub fn new(scroll_bar_x: f32, top_y: f32, row_height: f32, visible_row_count: usize, row_count: usize) -> GUIScrollList {
+        let visible_row_count = visible_row_count.max(1).min(row_count);
+        let track_height = row_height * visible_row_count as f32;
+        let track_y = top_y + row_height/2.0 - track_height/2.0;
+        let position = Point2::new(scroll_bar_x, track_y);
+
+        let mut scroll_list = GUIScrollList {
+            scroll_bar: [
+                GUIColorRectangle::new(position, GUI_SCROLL_BAR_WIDTH, track_height, GUI_SCROLL_BAR_TRACK_COLOR),
+                GUIColorRectangle::new(position, GUI_SCROLL_BAR_WIDTH, track_height, GUI_SCROLL_BAR_THUMB_COLOR),
+            ],
+            top_y,
+            row_height,
+            row_count,
+            visible_row_count,
+            first_visible_row: 0,
+        };
+
+        scroll_list.update_thumb();
+
+        scroll_list
+    }
+
+    /// Y coordinate of the row after the last row of the viewport.
+    pub fn bottom_y(&self) -> f32 {
+        self.top_y - self.row_height * self.visible_row_count as f32
+    }
+
+    /// Is row inside the viewport.
+    pub fn is_visible(&self, row: usize) -> bool {
+        row >= self.first_visible_row && row < self.first_visible_row + self.visible_row_count
+    }
+
+    /// Rows inside the viewport.
+    pub fn visible_rows(&self) -> Range<usize> {
+        self.first_visible_row..self.first_visible_row + self.visible_row_count
+    }
+
+    /// Y coordinate of the row's center at the current scroll position.
+    pub fn row_y(&self, row: usize) -> f32 {
+        self.top_y - self.row_height * (row as f32 - self.first_visible_row as f32)
+    }
+
+    /// Items of rows inside the viewport. Argument `item_rows` has the row
+    /// of every item in `items` in ascending order.
+    pub fn visible_items<'a, T>(&self, items: &'a [T], item_rows: &[usize]) -> &'a [T] {
+        let rows = self.visible_rows();
+        let start = item_rows.iter().take_while(|&&row| row < rows.start).count();
+        let end = item_rows.iter().take_while(|&&row| row < rows.end).count();
+
+        &items[start..end]
+    }
+
+    /// Index of the first visible row which collides with `point`. Argument `rows`
+    /// has one component for every row of the list.
+    pub fn collision<T: GUIUserInteraction>(&self, rows: &[T], point: &Point2<f32>) -> Option<usize> {
+        self.visible_rows().find(|&row| rows[row].collision(point))
+    }
+
+    /// Scroll list by `rows`. Negative values scroll up. Scrolling
+    /// stops at the ends of the list.
+    ///
+    /// Returns true if the viewport moved.
+    pub fn scroll(&mut self, rows: i32) -> bool {
+        let first_row = (self.first_visible_row as i32 + rows).max(0) as usize;
+        self.set_first_visible_row(first_row)
+    }
+
+    /// Scroll list the minimum amount which makes
+    /// the row visible. Rows outside the list are ignored.
+    ///
+    /// Returns true if the viewport moved.
+    pub fn scroll_to_row(&mut self, row: usize) -> bool {
+        if row >= self.row_count || self.is_visible(row) {
+            false
+        } else if row < self.first_visible_row {
+            self.set_first_visible_row(row)
+        } else {
+            self.set_first_visible_row(row + 1 - self.visible_row_count)
+        }
+    }
+
+    /// Get scroll bar rectangles. Slice is empty if all rows fit into the viewport.
+    pub fn rectangles(&self) -> &[GUIColorRectangle] {
+        if self.row_count > self.visible_row_count {
+            &self.scroll_bar
+        } else {
+            &[]
+        }
+    }
+
+    fn max_first_visible_row(&self) -> usize {
+        self.row_count - self.visible_row_count
+    }
+
+    /// Set first row of the viewport. Row will be clamped so
+    /// that the viewport stays inside the list.
+    fn set_first_visible_row(&mut self, row: usize) -> bool {
+        let row = row.min(self.max_first_visible_row());
+
+        if row == self.first_visible_row {
+            return false;
+        }
+
+        self.first_visible_row = row;
+        self.update_thumb();
+
+        true
+    }
+
+    /// Update thumb size and position to match the viewport.
+    fn update_thumb(&mut self) {
+        if self.row_count == 0 {
+            return;
+        }
+
+        let track_height = self.row_height * self.visible_row_count as f32;
+        let track_top = self.top_y + self.row_height/2.0;
+
+        let row_count = self.row_count as f32;
+        let thumb_height = (track_height * self.visible_row_count as f32 / row_count).max(GUI_SCROLL_BAR_MIN_THUMB_HEIGHT);
+        let movement = track_height - thumb_height;
+        let max_first_row = self.max_first_visible_row();
+
+        let position = if max_first_row > 0 {
+            self.first_visible_row as f32 / max_first_row as f32
+        } else {
+            0.0
+        };
+
+        let thumb_y = track_top - thumb_height/2.0 - movement * position;
+        self.scroll_bar[1].set_y_and_height(thumb_y, thumb_height);
+    }
+}
+
 
 /// Graphical value indicator.
 pub struct GUIHealthBar {
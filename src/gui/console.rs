@@ -0,0 +1,186 @@
+/*
+src/gui/console.rs, 2017-09-30
+
+Copyright (c) 2017 Juuso Tuononen
+
+This file is licensed under
+
+Apache License, Version 2.0
+
+or
+
+MIT License
+*/
+
+//! Developer console overlay.
+
+use cgmath::{Point2, Vector3};
+
+use gui::components::{GUIText, GUIColorRectangle, GUIComponentAlignment};
+use gui::{GUILayer, GUIComponentReferences};
+use input::Input;
+use renderer::{Fonts, SCREEN_TOP_Y_VALUE_IN_WORLD_COORDINATES};
+
+/// Number of output lines which are displayed.
+const CONSOLE_OUTPUT_LINE_COUNT: usize = 10;
+const CONSOLE_LINE_HEIGHT: f32 = 0.45;
+const CONSOLE_FONT_SIZE: f32 = 0.4;
+const CONSOLE_MARGIN: f32 = 0.2;
+const CONSOLE_MAX_INPUT_LENGTH: usize = 60;
+const CONSOLE_PROMPT: &str = "> ";
+const CONSOLE_BACKGROUND_COLOR: Vector3<f32> = Vector3 { x: 0.0, y: 0.0, z: 0.15 };
+
+/// Text input character from the console key. It is
+/// ignored, so closing the console doesn't add it to the command line.
+const CONSOLE_KEY_CHARACTER: char = '`';
+
+/// Console with output lines and a command line. Output lines
+/// are displayed above the command line.
+pub struct GUIConsole {
+    background: [GUIColorRectangle; 1],
+    /// Output line texts and the command line text.
+    texts: Vec<GUIText>,
+    /// Output lines, the newest line is the last line.
+    output: Vec<String>,
+    command_line: String,
+    open: bool,
+}
+
+impl GUIConsole {
+    /// Create new closed `GUIConsole`.
+    pub fn new() -> GUIConsole {
+        let line_count = CONSOLE_OUTPUT_LINE_COUNT + 1;
+        let height = line_count as f32 * CONSOLE_LINE_HEIGHT + CONSOLE_MARGIN * 2.0;
+        let background_y = SCREEN_TOP_Y_VALUE_IN_WORLD_COORDINATES - height/2.0;
+
+        let texts = (0..line_count).map(|i| {
+            let y = SCREEN_TOP_Y_VALUE_IN_WORLD_COORDINATES - CONSOLE_MARGIN - CONSOLE_LINE_HEIGHT * (i as f32 + 0.5);
+            let mut text = GUIText::new_with_alignment(0.0, y, "", GUIComponentAlignment::Left);
+            text.set_font(Fonts::Monospace);
+            text.set_font_size(CONSOLE_FONT_SIZE);
+            text
+        }).collect();
+
+        let mut console = GUIConsole {
+            background: [GUIColorRectangle::new(Point2::new(0.0, background_y), 0.0, height, CONSOLE_BACKGROUND_COLOR)],
+            texts,
+            output: Vec::new(),
+            command_line: String::new(),
+            open: false,
+        };
+
+        console.update_command_line_text();
+
+        console
+    }
+
+    /// Is console open.
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// Open or close the console.
+    pub fn toggle(&mut self) {
+        self.open = !self.open;
+    }
+
+    /// Add text to the output. Every line of
+    /// the text is added as an output line.
+    pub fn print(&mut self, text: &str) {
+        self.output.extend(text.lines().map(|line| line.to_string()));
+
+        if self.output.len() > CONSOLE_OUTPUT_LINE_COUNT {
+            let extra_lines = self.output.len() - CONSOLE_OUTPUT_LINE_COUNT;
+            self.output.drain(..extra_lines);
+        }
+
+        self.update_output_texts();
+    }
+
+    /// Remove all output lines.
+    pub fn clear(&mut self) {
+        self.output.clear();
+        self.update_output_texts();
+    }
+
+    /// Edit command line with text input and backspace key. Back key closes the console.
+    ///
+    /// Returns the command line when enter key is pressed and the command line is not empty.
+    pub fn handle_input<T: Input>(&mut self, input: &mut T) -> Option<String> {
+        // Pause key is a letter key in emscripten build, so it is typed
+        // to the command line instead of closing the console.
+        if input.key_hit_back() && !cfg!(target_os = "emscripten") {
+            self.open = false;
+            return None;
+        }
+
+        for character in input.text_input().chars() {
+            if character.is_control() || character == CONSOLE_KEY_CHARACTER {
+                continue;
+            }
+
+            if self.command_line.chars().count() < CONSOLE_MAX_INPUT_LENGTH {
+                self.command_line.push(character);
+            }
+        }
+
+        if input.key_hit_backspace() {
+            self.command_line.pop();
+        }
+
+        let mut command = None;
+
+        if input.key_hit_enter() {
+            let line = self.command_line.trim().to_string();
+            self.command_line.clear();
+
+            if !line.is_empty() {
+                self.print(&format!("{}{}", CONSOLE_PROMPT, line));
+                command = Some(line);
+            }
+        }
+
+        self.update_command_line_text();
+
+        command
+    }
+
+    /// Move texts to the left edge of the screen and
+    /// resize background to screen width.
+    ///
+    /// Argument `width` is screen_width/2.0.
+    pub fn update_position_from_half_screen_width(&mut self, width: f32) {
+        self.background[0].set_x_and_width(0.0, width * 2.0);
+
+        for text in &mut self.texts {
+            text.update_position_from_half_screen_width(width);
+        }
+    }
+
+    /// Update output line texts. The newest line is above the command line.
+    fn update_output_texts(&mut self) {
+        let empty_lines = CONSOLE_OUTPUT_LINE_COUNT - self.output.len();
+
+        for (i, text) in self.texts[..CONSOLE_OUTPUT_LINE_COUNT].iter_mut().enumerate() {
+            if i < empty_lines {
+                text.change_text("");
+            } else {
+                text.change_text(&self.output[i - empty_lines]);
+            }
+        }
+    }
+
+    /// Update command line text with prompt and cursor.
+    fn update_command_line_text(&mut self) {
+        let text = format!("{}{}_", CONSOLE_PROMPT, self.command_line);
+        self.texts[CONSOLE_OUTPUT_LINE_COUNT].change_text(&text);
+    }
+}
+
+impl GUILayer for GUIConsole {
+    fn components<'a>(&'a self) -> GUIComponentReferences<'a> {
+        GUIComponentReferences::new()
+            .set_rectangles(&self.background)
+            .set_texts(&self.texts)
+    }
+}
@@ -0,0 +1,502 @@
+/*
+src/gui/layout.rs, 2017-09-28
+
+Copyright (c) 2017 Juuso Tuononen
+
+This file is licensed under
+
+Apache License, Version 2.0
+
+or
+
+MIT License
+*/
+
+//! GUI layout files.
+//!
+//! Components of GUI layers are described in TOML files in the
+//! `gui` directory of the game data directory. Layout file can
+//! contain buttons, texts, bars and a setting list:
+//!
+//! ```toml
+//! [[button]]
+//! text = "main_menu.start_game"
+//! x = 0.0
+//! y = 1.0
+//! event = "change_state:difficulty_selection_menu"
+//!
+//! [[text]]
+//! text = "main_menu.title"
+//! x = 0.0
+//! y = 3.0
+//! ```
+//!
+//! Texts are keys to the locale files. See function `parse_event` for supported
+//! events. Optional values `anchor` and `alignment` can be `left`, `center` or `right`.
+//! Anchor sets the point of the screen where component's x coordinate starts.
+//!
+//! In debug builds, layout files are reloaded when they change.
+
+use std::fs::{self, File};
+use std::io::Read;
+use std::time::SystemTime;
+
+use toml::Value;
+use toml::value::Table;
+
+use paths::GamePaths;
+use logic::Difficulty;
+use utils::{Timer, TimeMilliseconds};
+
+use super::{GUIEvent, GUIState, ConfirmAction};
+use super::components::GUIComponentAlignment;
+
+/// Default size of buttons.
+pub const BUTTON_WIDTH: f32 = 5.0;
+pub const BUTTON_HEIGHT: f32 = 1.0;
+
+/// How often layout file changes are checked.
+const LAYOUT_CHECK_MILLISECONDS: u32 = 500;
+
+/// Available layouts.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Layouts {
+    MainMenu,
+    DifficultySelectionMenu,
+    PauseMenu,
+    SettingsMenu,
+    GameOverScreen,
+    PlayerWinsScreen,
+    NextLevelScreen,
+    GameStatus,
+    LayoutCount,
+}
+
+/// All layouts in the same order as in the `Layouts` enum.
+pub const ALL_LAYOUTS: &'static [Layouts] = &[
+    Layouts::MainMenu,
+    Layouts::DifficultySelectionMenu,
+    Layouts::PauseMenu,
+    Layouts::SettingsMenu,
+    Layouts::GameOverScreen,
+    Layouts::PlayerWinsScreen,
+    Layouts::NextLevelScreen,
+    Layouts::GameStatus,
+];
+
+impl Layouts {
+    /// Layout file of layout.
+    fn file(self) -> &'static str {
+        match self {
+            Layouts::MainMenu => "gui/main_menu.toml",
+            Layouts::DifficultySelectionMenu => "gui/difficulty_selection_menu.toml",
+            Layouts::PauseMenu => "gui/pause_menu.toml",
+            Layouts::SettingsMenu => "gui/settings_menu.toml",
+            Layouts::GameOverScreen => "gui/game_over_screen.toml",
+            Layouts::PlayerWinsScreen => "gui/player_wins_screen.toml",
+            Layouts::NextLevelScreen => "gui/next_level_screen.toml",
+            Layouts::GameStatus => "gui/game_status.toml",
+            Layouts::LayoutCount => panic!("LayoutCount is not a layout"),
+        }
+    }
+
+    /// Check that layout has the components that the layout's GUI layer requires.
+    fn validate(self, layout: &GUILayout) -> Result<(), String> {
+        match self {
+            Layouts::GameStatus => {
+                if layout.bars.len() != 2 {
+                    return Err("game status layout must have two bars".to_string());
+                }
+            },
+            Layouts::SettingsMenu => {
+                if layout.setting_list.is_none() {
+                    return Err("settings menu layout must have a setting list".to_string());
+                }
+            },
+            _ => {
+                if layout.buttons.is_empty() {
+                    return Err("layout must have at least one button".to_string());
+                }
+            },
+        }
+
+        Ok(())
+    }
+
+    /// Load all layouts.
+    ///
+    /// # Panics
+    /// * If loading of some layout fails.
+    pub fn load_all(paths: &GamePaths) -> Vec<GUILayout> {
+        ALL_LAYOUTS.iter().map(|&layout| {
+            match layout.load(paths) {
+                Ok(layout) => layout,
+                Err(error) => panic!("layout file {} loading failed: {}", paths.data_file(layout.file()), error),
+            }
+        }).collect()
+    }
+
+    /// Load and validate layout.
+    pub fn load(self, paths: &GamePaths) -> Result<GUILayout, String> {
+        self.load_file(&paths.data_file(self.file()))
+    }
+
+    /// Load and validate layout from file `file_path`.
+    fn load_file(self, file_path: &str) -> Result<GUILayout, String> {
+        let layout = GUILayout::load(file_path)?;
+        self.validate(&layout)?;
+        Ok(layout)
+    }
+}
+
+/// Point of the screen where component's x coordinate starts.
+#[derive(Copy, Clone, Debug)]
+pub enum Anchor {
+    Left,
+    Center,
+    Right,
+}
+
+impl Anchor {
+    /// Component's x coordinate from anchor and offset.
+    ///
+    /// Argument `width` is screen_width/2.0.
+    pub fn x(self, offset: f32, width: f32) -> f32 {
+        match self {
+            Anchor::Left => -width + offset,
+            Anchor::Center => offset,
+            Anchor::Right => width + offset,
+        }
+    }
+}
+
+/// Button of layout.
+pub struct ButtonLayout {
+    /// Key to the locale files.
+    pub text: String,
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+    pub anchor: Anchor,
+    pub event: GUIEvent,
+}
+
+/// Text of layout.
+pub struct TextLayout {
+    /// Key to the locale files.
+    pub text: String,
+    pub x: f32,
+    pub y: f32,
+    pub anchor: Anchor,
+    pub alignment: GUIComponentAlignment,
+    pub font_size: Option<f32>,
+}
+
+/// Bar of layout. Bar's position depends only on its alignment.
+pub struct BarLayout {
+    pub y: f32,
+    pub width: f32,
+    pub alignment: GUIComponentAlignment,
+    pub max_value: u32,
+    /// Bar color changes when value is less than or equal to this value.
+    pub low_value: u32,
+}
+
+/// Position of settings in the settings menu.
+pub struct SettingListLayout {
+    /// X coordinate of setting buttons.
+    pub x: f32,
+    /// X coordinate of setting value widgets.
+    pub widget_x: f32,
+    /// Y coordinate of the first setting.
+    pub y: f32,
+    /// Distance between settings.
+    pub spacing: f32,
+    /// Number of settings which fit on the screen. Other
+    /// settings are displayed by scrolling the list.
+    pub visible_rows: usize,
+    /// X coordinate of the scroll bar.
+    pub scroll_bar_x: f32,
+}
+
+/// Components of GUI layer.
+pub struct GUILayout {
+    pub buttons: Vec<ButtonLayout>,
+    pub texts: Vec<TextLayout>,
+    pub bars: Vec<BarLayout>,
+    /// Index of the button which is selected when layer is created.
+    pub selected: usize,
+    pub setting_list: Option<SettingListLayout>,
+}
+
+impl GUILayout {
+    /// Load layout from file.
+    ///
+    /// Returns `Err(error_message)` if file reading or parsing fails.
+    pub fn load(file_path: &str) -> Result<GUILayout, String> {
+        let mut text = String::new();
+
+        File::open(file_path)
+            .and_then(|mut file| file.read_to_string(&mut text))
+            .map_err(|error| error.to_string())?;
+
+        GUILayout::parse(&text)
+    }
+
+    /// Parse layout from TOML text.
+    ///
+    /// Returns `Err(error_message)` if some value is missing or invalid.
+    pub fn parse(text: &str) -> Result<GUILayout, String> {
+        let table = match text.parse::<Value>() {
+            Ok(Value::Table(table)) => table,
+            Ok(_) => return Err("layout is not a TOML table".to_string()),
+            Err(error) => return Err(error.to_string()),
+        };
+
+        let mut layout = GUILayout {
+            buttons: Vec::new(),
+            texts: Vec::new(),
+            bars: Vec::new(),
+            selected: 0,
+            setting_list: None,
+        };
+
+        for (key, value) in table {
+            match (key.as_str(), value) {
+                ("button", Value::Array(buttons)) => {
+                    for button in tables(buttons, "button")? {
+                        layout.buttons.push(ButtonLayout {
+                            text: string(&button, "text")?,
+                            x: number(&button, "x", None)?,
+                            y: number(&button, "y", None)?,
+                            width: number(&button, "width", Some(BUTTON_WIDTH))?,
+                            height: number(&button, "height", Some(BUTTON_HEIGHT))?,
+                            anchor: anchor(&button)?,
+                            event: parse_event(&string(&button, "event")?)?,
+                        });
+                    }
+                },
+                ("text", Value::Array(texts)) => {
+                    for text in tables(texts, "text")? {
+                        let font_size = match text.get("font_size") {
+                            Some(_) => Some(number(&text, "font_size", None)?),
+                            None => None,
+                        };
+
+                        layout.texts.push(TextLayout {
+                            text: string(&text, "text")?,
+                            x: number(&text, "x", None)?,
+                            y: number(&text, "y", None)?,
+                            anchor: anchor(&text)?,
+                            alignment: alignment(&text)?,
+                            font_size,
+                        });
+                    }
+                },
+                ("bar", Value::Array(bars)) => {
+                    for bar in tables(bars, "bar")? {
+                        layout.bars.push(BarLayout {
+                            y: number(&bar, "y", None)?,
+                            width: number(&bar, "width", None)?,
+                            alignment: alignment(&bar)?,
+                            max_value: number(&bar, "max_value", None)? as u32,
+                            low_value: number(&bar, "low_value", Some(0.0))? as u32,
+                        });
+                    }
+                },
+                ("selected", Value::Integer(index)) if index >= 0 => layout.selected = index as usize,
+                ("setting_list", Value::Table(list)) => {
+                    layout.setting_list = Some(SettingListLayout {
+                        x: number(&list, "x", None)?,
+                        widget_x: number(&list, "widget_x", None)?,
+                        y: number(&list, "y", None)?,
+                        spacing: number(&list, "spacing", None)?,
+                        visible_rows: number(&list, "visible_rows", None)?.max(1.0) as usize,
+                        scroll_bar_x: number(&list, "scroll_bar_x", None)?,
+                    });
+                },
+                (key, _) => return Err(format!("unknown or invalid value \"{}\"", key)),
+            }
+        }
+
+        Ok(layout)
+    }
+}
+
+/// Parse event text.
+///
+/// # Supported events
+/// * `next_level`
+/// * `exit`
+/// * `new_game:DIFFICULTY`, where difficulty is `easy`, `normal` or `hard`.
+/// * `back`, returns to the previous state.
+/// * `confirm:ACTION`, asks the user to confirm the action before it is done.
+///   Action is `abandon_game` or `exit`.
+/// * `change_state:STATE`, clears the state history. State is `main_menu`,
+///   `difficulty_selection_menu`, `pause_menu`, `game`, `player_wins_screen`,
+///   `next_level_screen`, `game_over_screen` or `settings_menu`.
+/// * `push_state:STATE`, opens the state on top of the current state.
+pub fn parse_event(text: &str) -> Result<GUIEvent, String> {
+    let mut parts = text.splitn(2, ':');
+
+    let event = match (parts.next(), parts.next()) {
+        (Some("next_level"), None) => GUIEvent::NextLevel,
+        (Some("exit"), None) => GUIEvent::Exit,
+        (Some("back"), None) => GUIEvent::Back,
+        (Some("new_game"), Some(difficulty)) => {
+            let difficulty = match difficulty {
+                "easy" => Difficulty::Easy,
+                "normal" => Difficulty::Normal,
+                "hard" => Difficulty::Hard,
+                _ => return Err(format!("unknown difficulty \"{}\"", difficulty)),
+            };
+
+            GUIEvent::NewGame(difficulty)
+        },
+        (Some("change_state"), Some(state)) => GUIEvent::ChangeState(parse_state(state)?),
+        (Some("push_state"), Some(state)) => GUIEvent::PushState(parse_state(state)?),
+        (Some("confirm"), Some(action)) => {
+            let action = match action {
+                "abandon_game" => ConfirmAction::AbandonGame,
+                "exit" => ConfirmAction::Exit,
+                _ => return Err(format!("unknown action \"{}\"", action)),
+            };
+
+            GUIEvent::Confirm(action)
+        },
+        _ => return Err(format!("unknown event \"{}\"", text)),
+    };
+
+    Ok(event)
+}
+
+/// Parse GUI state name of `change_state` and `push_state` events.
+fn parse_state(text: &str) -> Result<GUIState, String> {
+    let state = match text {
+        "main_menu" => GUIState::MainMenu,
+        "difficulty_selection_menu" => GUIState::DifficultySelectionMenu,
+        "pause_menu" => GUIState::PauseMenu,
+        "game" => GUIState::Game,
+        "player_wins_screen" => GUIState::PlayerWinsScreen,
+        "next_level_screen" => GUIState::NextLevelScreen,
+        "game_over_screen" => GUIState::GameOverScreen,
+        "settings_menu" => GUIState::SettingsMenu,
+        _ => return Err(format!("unknown GUI state \"{}\"", text)),
+    };
+
+    Ok(state)
+}
+
+/// Convert array values to tables.
+fn tables(values: Vec<Value>, name: &str) -> Result<Vec<Table>, String> {
+    values.into_iter().map(|value| {
+        match value {
+            Value::Table(table) => Ok(table),
+            _ => Err(format!("{} is not a table", name)),
+        }
+    }).collect()
+}
+
+/// Get string value from table.
+fn string(table: &Table, key: &str) -> Result<String, String> {
+    match table.get(key) {
+        Some(&Value::String(ref text)) => Ok(text.clone()),
+        Some(_) => Err(format!("value \"{}\" is not a string", key)),
+        None => Err(format!("value \"{}\" is missing", key)),
+    }
+}
+
+/// Get integer or float value from table as `f32`. If value is
+/// missing, argument `default` is returned if it is not `None`.
+fn number(table: &Table, key: &str, default: Option<f32>) -> Result<f32, String> {
+    match (table.get(key), default) {
+        (Some(&Value::Float(number)), _) => Ok(number as f32),
+        (Some(&Value::Integer(number)), _) => Ok(number as f32),
+        (Some(_), _) => Err(format!("value \"{}\" is not a number", key)),
+        (None, Some(default)) => Ok(default),
+        (None, None) => Err(format!("value \"{}\" is missing", key)),
+    }
+}
+
+/// Get optional value `anchor` from table. Default value is `Anchor::Center`.
+fn anchor(table: &Table) -> Result<Anchor, String> {
+    match table.get("anchor") {
+        None => Ok(Anchor::Center),
+        Some(&Value::String(ref text)) if text == "center" => Ok(Anchor::Center),
+        Some(&Value::String(ref text)) if text == "left" => Ok(Anchor::Left),
+        Some(&Value::String(ref text)) if text == "right" => Ok(Anchor::Right),
+        Some(_) => Err("value \"anchor\" must be \"left\", \"center\" or \"right\"".to_string()),
+    }
+}
+
+/// Get optional value `alignment` from table. Default value is `GUIComponentAlignment::Center`.
+fn alignment(table: &Table) -> Result<GUIComponentAlignment, String> {
+    match table.get("alignment") {
+        None => Ok(GUIComponentAlignment::Center),
+        Some(&Value::String(ref text)) if text == "center" => Ok(GUIComponentAlignment::Center),
+        Some(&Value::String(ref text)) if text == "left" => Ok(GUIComponentAlignment::Left),
+        Some(&Value::String(ref text)) if text == "right" => Ok(GUIComponentAlignment::Right),
+        Some(_) => Err("value \"alignment\" must be \"left\", \"center\" or \"right\"".to_string()),
+    }
+}
+
+/// Checks modification times of layout files.
+pub struct LayoutWatcher {
+    file_paths: Vec<String>,
+    modified: Vec<Option<SystemTime>>,
+    timer: Timer,
+}
+
+impl LayoutWatcher {
+    /// Create new `LayoutWatcher`. Current modification
+    /// times of layout files are stored.
+    pub fn new(paths: &GamePaths) -> LayoutWatcher {
+        let file_paths: Vec<String> = ALL_LAYOUTS.iter().map(|layout| paths.data_file(layout.file())).collect();
+        let modified = file_paths.iter().map(|path| modification_time(path)).collect();
+
+        LayoutWatcher {
+            file_paths,
+            modified,
+            timer: Timer::new(),
+        }
+    }
+
+    /// Load layouts which files have changed since the last check. File
+    /// modification times are checked only every `LAYOUT_CHECK_MILLISECONDS`.
+    ///
+    /// Loading errors are logged and layouts which failed
+    /// to load are not returned.
+    pub fn reload_changed_layouts(&mut self, current_time: &TimeMilliseconds) -> Vec<(Layouts, GUILayout)> {
+        let mut changed = Vec::new();
+
+        if !self.timer.check(current_time, LAYOUT_CHECK_MILLISECONDS) {
+            return changed;
+        }
+
+        for (i, path) in self.file_paths.iter().enumerate() {
+            let modified = modification_time(path);
+
+            if modified == self.modified[i] {
+                continue;
+            }
+
+            self.modified[i] = modified;
+
+            match ALL_LAYOUTS[i].load_file(path) {
+                Ok(layout) => {
+                    info!("layout file {} reloaded", path);
+                    changed.push((ALL_LAYOUTS[i], layout));
+                },
+                Err(error) => error!("layout file {} reloading failed: {}", path, error),
+            }
+        }
+
+        changed
+    }
+}
+
+/// File modification time. Returns `None` if it is not available.
+fn modification_time(file_path: &str) -> Option<SystemTime> {
+    fs::metadata(file_path).and_then(|metadata| metadata.modified()).ok()
+}
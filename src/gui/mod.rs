@@ -23,6 +23,14 @@ MIT License
 //! `GUI` stores info about currently active `GUILayer`.
 //! There can only be one active `GUILayer` at a time.
 //!
+//! Opened `GUIState`s are stored to a stack, so
+//! back button returns to the previous state. Modal dialogs
+//! are rendered on top of the layer below them, but only the
+//! dialog receives input.
+//!
+//! State changes are animated with fade and slide transitions. Transitions
+//! don't block input, new `GUILayer` receives input immediately.
+//!
 //! `GUI` will call `handle_input` method
 //! of currently active `GUILayer` when input should be updated.
 //! `GUILayer` will check the if there is adequate input for
@@ -32,32 +40,73 @@ MIT License
 //! `GUIEvent` type represents request of change to the current state of `GUI` or
 //! some other component of the game. That means you can request starting a new game or
 //! changing some setting also.
+//!
+//! Components of most `GUILayer`s are loaded from layout files. See
+//! module `layout` for the file format.
 
 pub mod components;
+pub mod layout;
+pub mod console;
 
-const BUTTON_WIDTH: f32 = 5.0;
-const BUTTON_HEIGHT: f32 = 1.0;
+use std::rc::Rc;
+use std::iter::Chain;
+use std::slice::Iter;
+
+use cgmath::{Point2, Vector3};
 
 const FPS_COUNTER_POSITION_Y: f32 = 3.2;
 
+const SLIDER_WIDTH: f32 = 3.0;
+const DROPDOWN_WIDTH: f32 = 4.0;
+
+const CONFIRM_DIALOG_WIDTH: f32 = 9.0;
+const CONFIRM_DIALOG_HEIGHT: f32 = 4.0;
+const CONFIRM_DIALOG_BUTTON_WIDTH: f32 = 3.0;
+/// Duration of transitions between menus.
+const MENU_TRANSITION_MILLISECONDS: u32 = 250;
+/// Duration of fade from the game to the screen which is displayed after the game.
+const END_SCREEN_TRANSITION_MILLISECONDS: u32 = 1000;
+/// How far layers slide during slide transitions.
+const TRANSITION_SLIDE_DISTANCE: f32 = 2.0;
+/// Maximum time step of animations, so animations don't
+/// skip to the end after the game has been frozen.
+const MAX_ANIMATION_STEP_MILLISECONDS: u32 = 100;
+
+const CONFIRM_DIALOG_BACKGROUND_COLOR: Vector3<f32> = Vector3 { x: 0.0, y: 0.0, z: 0.2 };
+
 use gui::components::*;
+use gui::layout::{GUILayout, Layouts, LayoutWatcher, Anchor, BUTTON_WIDTH, BUTTON_HEIGHT};
+use gui::console::GUIConsole;
 
 use input::Input;
 use logic::Difficulty;
-use settings::{ Settings, SettingType, BooleanSetting, IntegerSetting};
+use settings::{Settings, SettingId, SettingKind, SettingValue, ALL_SETTINGS, LANGUAGE_OPTIONS};
+use locale::Locale;
+use utils::{Timer, TimeMilliseconds};
 
 /// Event that will be sent from `GUILayer` to `GUI`.
 #[derive(Copy, Clone)]
 pub enum GUIEvent {
     NextLevel,
     NewGame(Difficulty),
+    /// Clear the state stack and change to the state.
     ChangeState(GUIState),
-    ChangeSetting(SettingType),
+    /// Open the state on top of the current state.
+    PushState(GUIState),
+    /// Return to the previous state.
+    Back,
+    /// Open `ConfirmDialog` for the action.
+    Confirm(ConfirmAction),
+    /// User accepted the action in `ConfirmDialog`.
+    ConfirmAccept(ConfirmAction),
+    /// User cancelled the action in `ConfirmDialog`.
+    ConfirmCancel(ConfirmAction),
+    ChangeSetting(SettingId, SettingValue),
     Exit,
 }
 
 /// Current state of the GUI.
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, PartialEq, Debug)]
 pub enum GUIState {
     MainMenu,
     DifficultySelectionMenu,
@@ -67,6 +116,84 @@ pub enum GUIState {
     NextLevelScreen,
     GameOverScreen,
     SettingsMenu,
+    ConfirmDialog,
+}
+
+impl GUIState {
+    /// Modal states are rendered on top of the previous state.
+    pub fn is_modal(self) -> bool {
+        match self {
+            GUIState::ConfirmDialog => true,
+            _ => false,
+        }
+    }
+}
+
+/// Action which is done only after the user accepts it in `ConfirmDialog`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum ConfirmAction {
+    /// Return from the game to the main menu.
+    AbandonGame,
+    Exit,
+}
+
+impl ConfirmAction {
+    /// Locale key of dialog's message.
+    fn message(self) -> &'static str {
+        match self {
+            ConfirmAction::AbandonGame => "confirm_dialog.abandon_game",
+            ConfirmAction::Exit => "confirm_dialog.exit",
+        }
+    }
+
+    /// Event which does the action.
+    pub fn event(self) -> GUIEvent {
+        match self {
+            ConfirmAction::AbandonGame => GUIEvent::ChangeState(GUIState::MainMenu),
+            ConfirmAction::Exit => GUIEvent::Exit,
+        }
+    }
+}
+
+/// Type of `Transition`.
+#[derive(Copy, Clone)]
+enum TransitionKind {
+    Fade,
+    /// New layer slides in from the right.
+    SlideForward,
+    /// New layer slides in from the left.
+    SlideBack,
+}
+
+/// Animated change between visible states.
+struct Transition {
+    /// Visible states before the change.
+    previous: Vec<GUIState>,
+    kind: TransitionKind,
+    duration: u32,
+    elapsed: u32,
+}
+
+impl Transition {
+    /// Transition progress in range [0.0, 1.0] with smooth start and end.
+    fn progress(&self) -> f32 {
+        let x = (self.elapsed as f32 / self.duration as f32).min(1.0);
+        x * x * (3.0 - 2.0 * x)
+    }
+
+    /// Returns true if transition has ended.
+    fn finished(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+}
+
+/// Components of a visible `GUILayer` and layer's transition effect.
+pub struct GUIRenderLayer<'a> {
+    pub components: GUIComponentReferences<'a>,
+    /// Opacity in range [0.0, 1.0].
+    pub alpha: f32,
+    /// Horizontal offset in world coordinates.
+    pub x_offset: f32,
 }
 
 /// Component information for rendering is only required for GUILayer.
@@ -124,17 +251,28 @@ pub trait GUILayerInputHandler : GUILayer {
 /// Currently used only for rendering the components.
 pub struct GUIComponentReferences<'a> {
     buttons: &'a [GUIButton],
+    /// Buttons of `GUIScrollList` rows inside the viewport.
+    list_buttons: &'a [GUIButton],
     texts: &'a [GUIText],
     health_bars: &'a [GUIHealthBar],
+    sliders: &'a [GUISlider],
+    checkboxes: &'a [GUICheckbox],
+    dropdowns: &'a [GUIDropdown],
+    rectangles: &'a [GUIColorRectangle],
 }
 
 impl <'a> GUIComponentReferences<'a> {
     /// Create new GUIComponentReferences with empty slices.
     fn new() -> GUIComponentReferences<'a> {
         GUIComponentReferences {
+            rectangles: &[],
             buttons: &[],
+            list_buttons: &[],
             texts: &[],
             health_bars: &[],
+            sliders: &[],
+            checkboxes: &[],
+            dropdowns: &[],
         }
     }
 
@@ -144,6 +282,12 @@ impl <'a> GUIComponentReferences<'a> {
         self
     }
 
+    /// Set `GUIButton` slice of `GUIScrollList` rows.
+    fn set_list_buttons(mut self, list_buttons: &'a [GUIButton]) -> GUIComponentReferences<'a> {
+        self.list_buttons = list_buttons;
+        self
+    }
+
     /// Set `GUIText` slice.
     fn set_texts(mut self, texts: &'a [GUIText]) -> GUIComponentReferences<'a> {
         self.texts = texts;
@@ -156,9 +300,33 @@ impl <'a> GUIComponentReferences<'a> {
         self
     }
 
-    /// Get `GUIButton` slice.
-    pub fn buttons(&self) -> &[GUIButton] {
-        self.buttons
+    /// Set `GUISlider` slice.
+    fn set_sliders(mut self, sliders: &'a [GUISlider]) -> GUIComponentReferences<'a> {
+        self.sliders = sliders;
+        self
+    }
+
+    /// Set `GUICheckbox` slice.
+    fn set_checkboxes(mut self, checkboxes: &'a [GUICheckbox]) -> GUIComponentReferences<'a> {
+        self.checkboxes = checkboxes;
+        self
+    }
+
+    /// Set `GUIDropdown` slice.
+    fn set_dropdowns(mut self, dropdowns: &'a [GUIDropdown]) -> GUIComponentReferences<'a> {
+        self.dropdowns = dropdowns;
+        self
+    }
+
+    /// Set `GUIColorRectangle` slice.
+    fn set_rectangles(mut self, rectangles: &'a [GUIColorRectangle]) -> GUIComponentReferences<'a> {
+        self.rectangles = rectangles;
+        self
+    }
+
+    /// Iterate buttons and then buttons of `GUIScrollList` rows.
+    pub fn buttons(&self) -> Chain<Iter<'a, GUIButton>, Iter<'a, GUIButton>> {
+        self.buttons.iter().chain(self.list_buttons.iter())
     }
 
     /// Get `GUIText` slice.
@@ -170,6 +338,27 @@ impl <'a> GUIComponentReferences<'a> {
     pub fn health_bars(&self) -> &[GUIHealthBar] {
         self.health_bars
     }
+
+    /// Get `GUISlider` slice.
+    pub fn sliders(&self) -> &[GUISlider] {
+        self.sliders
+    }
+
+    /// Get `GUICheckbox` slice.
+    pub fn checkboxes(&self) -> &[GUICheckbox] {
+        self.checkboxes
+    }
+
+    /// Get `GUIDropdown` slice.
+    pub fn dropdowns(&self) -> &[GUIDropdown] {
+        self.dropdowns
+    }
+
+    /// Get `GUIColorRectangle` slice. Rectangles are
+    /// backgrounds which are rendered first.
+    pub fn rectangles(&self) -> &[GUIColorRectangle] {
+        self.rectangles
+    }
 }
 
 
@@ -180,74 +369,306 @@ pub struct GUI {
     settings_menu: SettingsMenu,
     game_status: GameStatus,
     difficulty_selection_menu: BasicGUILayer,
-    state: GUIState,
+    /// Opened states. The last state is the current state
+    /// and the stack is never empty.
+    state_stack: Vec<GUIState>,
     fps_counter: GUIFpsCounter,
     game_over_screen: BasicGUILayer,
     player_wins_screen: BasicGUILayer,
     next_level_screen: BasicGUILayer,
+    confirm_dialog: ConfirmDialog,
+    /// Developer console which is rendered on top of other layers.
+    console: GUIConsole,
+    /// Locales in the same order as `LANGUAGE_OPTIONS`.
+    locales: Vec<Rc<Locale>>,
+    /// Index of current locale.
+    language: usize,
+    /// Layouts in the same order as `Layouts` enum.
+    layouts: Vec<GUILayout>,
+    /// Reloads changed layout files in debug builds.
+    layout_watcher: Option<LayoutWatcher>,
+    half_screen_width: f32,
+    transition: Option<Transition>,
+    /// Time of the previous animation update.
+    animation_timer: Timer,
+    /// Replace slide transitions with fades and disable button highlight animations.
+    reduced_motion: bool,
 }
 
 
 impl GUI {
     /// Create new `GUI`.
+    ///
+    /// Locales of all languages and layouts are loaded from the game data directory.
+    ///
+    /// # Panics
+    /// * If loading of some layout file fails.
     pub fn new(settings: &Settings) -> GUI {
+        let locales: Vec<Rc<Locale>> = LANGUAGE_OPTIONS.iter().map(|option| Rc::new(Locale::load(settings.paths(), option.key))).collect();
+        let language = settings.get(SettingId::Language).enum_index();
+        let locale = locales[language].clone();
+
+        let layouts = Layouts::load_all(settings.paths());
+        let layout = |layout: Layouts| &layouts[layout as usize];
+
+        let layout_watcher = if cfg!(debug_assertions) {
+            Some(LayoutWatcher::new(settings.paths()))
+        } else {
+            None
+        };
+
+        let setting_values: Vec<SettingValue> = ALL_SETTINGS.iter().map(|&id| settings.get(id)).collect();
+        let half_screen_width = 0.0;
+
         GUI {
-            main_menu: BasicGUILayer::main_menu(),
-            pause_menu: PauseMenu::new(),
-            settings_menu: SettingsMenu::new(settings),
-            game_status: GameStatus::new(),
-            difficulty_selection_menu: BasicGUILayer::difficulty_selection_menu(),
-            state: GUIState::MainMenu,
+            main_menu: BasicGUILayer::new(layout(Layouts::MainMenu), &locale, half_screen_width),
+            pause_menu: PauseMenu::new(layout(Layouts::PauseMenu), &locale, half_screen_width),
+            settings_menu: SettingsMenu::new(layout(Layouts::SettingsMenu), &setting_values, &locale, half_screen_width),
+            game_status: GameStatus::new(layout(Layouts::GameStatus)),
+            difficulty_selection_menu: BasicGUILayer::new(layout(Layouts::DifficultySelectionMenu), &locale, half_screen_width),
+            state_stack: vec![GUIState::MainMenu],
             fps_counter: GUIFpsCounter::new(0.0, FPS_COUNTER_POSITION_Y),
-            game_over_screen: BasicGUILayer::game_over_screen(),
-            player_wins_screen: BasicGUILayer::player_wins_screen(),
-            next_level_screen: BasicGUILayer::next_level_screen(),
+            game_over_screen: BasicGUILayer::new(layout(Layouts::GameOverScreen), &locale, half_screen_width),
+            player_wins_screen: BasicGUILayer::new(layout(Layouts::PlayerWinsScreen), &locale, half_screen_width),
+            next_level_screen: BasicGUILayer::new(layout(Layouts::NextLevelScreen), &locale, half_screen_width),
+            confirm_dialog: ConfirmDialog::new(ConfirmAction::Exit, &locale),
+            console: GUIConsole::new(),
+            locales,
+            language,
+            layouts,
+            layout_watcher,
+            half_screen_width,
+            transition: None,
+            animation_timer: Timer::new(),
+            reduced_motion: false,
+        }
+    }
+
+    /// Enable or disable reduced motion.
+    pub fn set_reduced_motion(&mut self, reduced_motion: bool) {
+        self.reduced_motion = reduced_motion;
+    }
+
+    /// Update transition and button highlight animations. Call this every frame.
+    pub fn update(&mut self, current_time: &TimeMilliseconds) {
+        let milliseconds = self.animation_timer.milliseconds(current_time).min(MAX_ANIMATION_STEP_MILLISECONDS);
+        self.animation_timer.reset(current_time);
+
+        let transition_finished = match self.transition {
+            Some(ref mut transition) => {
+                transition.elapsed += milliseconds;
+                transition.finished()
+            },
+            None => false,
+        };
+
+        if transition_finished {
+            self.transition = None;
+        }
+
+        let highlight_milliseconds = if self.reduced_motion { u32::max_value() } else { milliseconds };
+
+        let buttons = match self.state() {
+            GUIState::MainMenu => self.main_menu.get_buttons_mut(),
+            GUIState::PauseMenu => self.pause_menu.get_buttons_mut(),
+            GUIState::SettingsMenu => self.settings_menu.get_buttons_mut(),
+            GUIState::DifficultySelectionMenu => self.difficulty_selection_menu.get_buttons_mut(),
+            GUIState::GameOverScreen => self.game_over_screen.get_buttons_mut(),
+            GUIState::PlayerWinsScreen => self.player_wins_screen.get_buttons_mut(),
+            GUIState::NextLevelScreen => self.next_level_screen.get_buttons_mut(),
+            GUIState::ConfirmDialog => self.confirm_dialog.get_buttons_mut(),
+            GUIState::Game => return,
+        };
+
+        for button in buttons.get_components_mut() {
+            button.update_highlight(highlight_milliseconds);
+        }
+    }
+
+    /// Change language of GUI texts. Argument `language` is
+    /// index to `LANGUAGE_OPTIONS`.
+    pub fn set_language(&mut self, language: usize) {
+        if language == self.language || language >= self.locales.len() {
+            return;
+        }
+
+        self.language = language;
+        self.recreate_layers();
+    }
+
+    /// Reload layout files which have changed. Layout files
+    /// are only reloaded in debug builds.
+    pub fn reload_changed_layouts(&mut self, current_time: &TimeMilliseconds) {
+        let changed_layouts = match self.layout_watcher {
+            Some(ref mut watcher) => watcher.reload_changed_layouts(current_time),
+            None => return,
+        };
+
+        if changed_layouts.is_empty() {
+            return;
+        }
+
+        for (layout, gui_layout) in changed_layouts {
+            self.layouts[layout as usize] = gui_layout;
         }
+
+        self.recreate_layers();
+    }
+
+    /// Recreate `GUILayer`s from current layouts and locale.
+    ///
+    /// `SettingsMenu` keeps its setting values and selected setting,
+    /// and `GameStatus` keeps its health values.
+    fn recreate_layers(&mut self) {
+        let locale = self.locales[self.language].clone();
+        let width = self.half_screen_width;
+
+        self.main_menu = BasicGUILayer::new(&self.layouts[Layouts::MainMenu as usize], &locale, width);
+        self.pause_menu = PauseMenu::new(&self.layouts[Layouts::PauseMenu as usize], &locale, width);
+        self.difficulty_selection_menu = BasicGUILayer::new(&self.layouts[Layouts::DifficultySelectionMenu as usize], &locale, width);
+        self.game_over_screen = BasicGUILayer::new(&self.layouts[Layouts::GameOverScreen as usize], &locale, width);
+        self.player_wins_screen = BasicGUILayer::new(&self.layouts[Layouts::PlayerWinsScreen as usize], &locale, width);
+        self.next_level_screen = BasicGUILayer::new(&self.layouts[Layouts::NextLevelScreen as usize], &locale, width);
+        self.confirm_dialog = ConfirmDialog::new(self.confirm_dialog.action, &locale);
+
+        let selected = self.settings_menu.layer.buttons.selected_index();
+        let setting_values = self.settings_menu.values();
+        self.settings_menu = SettingsMenu::new(&self.layouts[Layouts::SettingsMenu as usize], &setting_values, &locale, width);
+        self.settings_menu.set_selected(selected);
+
+        let health = self.game_status.health;
+        self.game_status = GameStatus::new(&self.layouts[Layouts::GameStatus as usize]);
+        self.game_status.set_player_health(health[0]);
+        self.game_status.set_enemy_health(health[1]);
+        self.game_status.update_position_from_half_screen_width(width);
     }
 
     /// Call `handle_input` function of current `GUILayer`.
     ///
+    /// Back button returns to the previous state. If game is the
+    /// only state, back button opens the pause menu.
+    ///
     /// Updates `GUI`'s state according to `GUIEvent` returned by
-    /// the current `GUILayer`.
+    /// the current `GUILayer`. If user accepts the action of `ConfirmDialog`,
+    /// the action's event is returned instead of `GUIEvent::ConfirmAccept`.
     pub fn handle_input<T: Input>(&mut self, input: &mut T) -> Option<GUIEvent> {
-        let event = match self.state {
-            GUIState::MainMenu => self.main_menu.handle_input(input),
-            GUIState::PauseMenu => {
-                if input.key_hit_back() {
-                    Some(GUIEvent::ChangeState(GUIState::Game))
-                } else {
-                    self.pause_menu.handle_input(input)
-                }
-            },
-            GUIState::Game => {
-                if input.key_hit_back() {
-                    Some(GUIEvent::ChangeState(GUIState::PauseMenu))
-                } else {
-                    None
-                }
-            },
-            GUIState::SettingsMenu => self.settings_menu.handle_input(input),
-            GUIState::DifficultySelectionMenu => self.difficulty_selection_menu.handle_input(input),
-            GUIState::NextLevelScreen => self.next_level_screen.handle_input(input),
-            GUIState::GameOverScreen => self.game_over_screen.handle_input(input),
-            GUIState::PlayerWinsScreen => self.player_wins_screen.handle_input(input),
-
+        let event = if input.key_hit_back() {
+            match self.state() {
+                GUIState::Game if self.state_stack.len() == 1 => Some(GUIEvent::PushState(GUIState::PauseMenu)),
+                GUIState::ConfirmDialog => Some(GUIEvent::ConfirmCancel(self.confirm_dialog.action)),
+                _ if self.state_stack.len() > 1 => Some(GUIEvent::Back),
+                _ => None,
+            }
+        } else {
+            self.handle_layer_input(input)
         };
 
         if let Some(event) = event {
             self.handle_gui_event(event);
         }
 
-        event
+        match event {
+            Some(GUIEvent::ConfirmAccept(action)) => Some(action.event()),
+            event => event,
+        }
+    }
+
+    /// Call `handle_input` function of current `GUILayer`.
+    fn handle_layer_input<T: Input>(&mut self, input: &mut T) -> Option<GUIEvent> {
+        match self.state() {
+            GUIState::MainMenu => self.main_menu.handle_input(input),
+            GUIState::PauseMenu => self.pause_menu.handle_input(input),
+            GUIState::Game => None,
+            GUIState::SettingsMenu => self.settings_menu.handle_input_with_widgets(input),
+            GUIState::DifficultySelectionMenu => self.difficulty_selection_menu.handle_input(input),
+            GUIState::NextLevelScreen => self.next_level_screen.handle_input(input),
+            GUIState::GameOverScreen => self.game_over_screen.handle_input(input),
+            GUIState::PlayerWinsScreen => self.player_wins_screen.handle_input(input),
+            GUIState::ConfirmDialog => self.confirm_dialog.handle_input(input),
+        }
     }
 
     /// Update `GUI`'s state from `GUIEvent`.
+    ///
+    /// Starts a transition if visible states change.
     pub fn handle_gui_event(&mut self, event: GUIEvent ) {
+        let previous_state = self.state();
+        let previous_visible_states = self.visible_states();
+
         match event {
-            GUIEvent::NextLevel | GUIEvent::NewGame(_) => self.state = GUIState::Game,
-            GUIEvent::ChangeState(state) => self.state = state,
+            GUIEvent::NextLevel | GUIEvent::NewGame(_) => self.change_state(GUIState::Game),
+            GUIEvent::ChangeState(state) => self.change_state(state),
+            GUIEvent::PushState(state) => {
+                if state == GUIState::PauseMenu {
+                    self.pause_menu.reset_selection();
+                }
+
+                self.state_stack.push(state);
+            },
+            GUIEvent::Back => {
+                if self.state_stack.len() > 1 {
+                    self.state_stack.pop();
+                }
+            },
+            GUIEvent::Confirm(action) => {
+                self.confirm_dialog = ConfirmDialog::new(action, &self.locales[self.language]);
+                self.state_stack.push(GUIState::ConfirmDialog);
+            },
+            GUIEvent::ConfirmCancel(_) => self.close_confirm_dialog(),
+            GUIEvent::ConfirmAccept(action) => {
+                self.close_confirm_dialog();
+                self.handle_gui_event(action.event());
+            },
             _ => (),
         };
+
+        if previous_visible_states == self.visible_states() {
+            return;
+        }
+
+        let kind = match event {
+            GUIEvent::PushState(_) if !self.reduced_motion => TransitionKind::SlideForward,
+            GUIEvent::Back if !self.reduced_motion => TransitionKind::SlideBack,
+            _ => TransitionKind::Fade,
+        };
+
+        let duration = match (previous_state, self.state()) {
+            (GUIState::Game, GUIState::GameOverScreen) |
+            (GUIState::Game, GUIState::NextLevelScreen) |
+            (GUIState::Game, GUIState::PlayerWinsScreen) => END_SCREEN_TRANSITION_MILLISECONDS,
+            _ => MENU_TRANSITION_MILLISECONDS,
+        };
+
+        self.transition = Some(Transition {
+            previous: previous_visible_states,
+            kind,
+            duration,
+            elapsed: 0,
+        });
+    }
+
+    /// Remove `ConfirmDialog` from the top of the state stack.
+    fn close_confirm_dialog(&mut self) {
+        if self.state() == GUIState::ConfirmDialog && self.state_stack.len() > 1 {
+            self.state_stack.pop();
+        }
+    }
+
+    /// Clear the state stack and change to `state`.
+    fn change_state(&mut self, state: GUIState) {
+        self.state_stack.clear();
+        self.state_stack.push(state);
+    }
+
+    /// Current state.
+    pub fn state(&self) -> GUIState {
+        *self.state_stack.last().expect("GUI state stack is empty")
+    }
+
+    /// Returns true if `state` is the current state or
+    /// some state below the current state.
+    pub fn state_is_open(&self, state: GUIState) -> bool {
+        self.state_stack.contains(&state)
     }
 
     /// Update `GUIFpsCounter`.
@@ -275,9 +696,89 @@ impl GUI {
         &mut self.settings_menu
     }
 
+    /// Get `GUIConsole`.
+    pub fn get_console(&mut self) -> &mut GUIConsole {
+        &mut self.console
+    }
+
+    /// Is developer console open.
+    pub fn console_is_open(&self) -> bool {
+        self.console.is_open()
+    }
+
     /// Get current `GUILayer`'s components.
     pub fn components<'a>(&'a self) -> GUIComponentReferences<'a> {
-        match self.state {
+        self.layer_components(self.state())
+    }
+
+    /// Visible states in rendering order. If the current state is modal,
+    /// states below it are visible until the first non modal state.
+    fn visible_states(&self) -> Vec<GUIState> {
+        let first_visible = self.state_stack.iter().rposition(|state| !state.is_modal()).unwrap_or(0);
+
+        self.state_stack[first_visible..].to_vec()
+    }
+
+    /// Get visible `GUILayer`s in rendering order. Open
+    /// developer console is the last layer.
+    pub fn visible_layers<'a>(&'a self) -> Vec<GUIRenderLayer<'a>> {
+        let mut layers = self.visible_state_layers();
+
+        if self.console.is_open() {
+            layers.push(GUIRenderLayer {
+                components: self.console.components(),
+                alpha: 1.0,
+                x_offset: 0.0,
+            });
+        }
+
+        layers
+    }
+
+    /// Get `GUILayer`s of visible states in rendering order.
+    ///
+    /// During a transition, layers which are visible before and after the transition
+    /// are not animated. Other previous layers fade out and new layers fade in. Slide
+    /// transitions also move the layers horizontally.
+    fn visible_state_layers<'a>(&'a self) -> Vec<GUIRenderLayer<'a>> {
+        let current = self.visible_states();
+
+        let layer = |state: GUIState, alpha: f32, x_offset: f32| GUIRenderLayer {
+            components: self.layer_components(state),
+            alpha,
+            x_offset,
+        };
+
+        let transition = match self.transition {
+            Some(ref transition) => transition,
+            None => return current.into_iter().map(|state| layer(state, 1.0, 0.0)).collect(),
+        };
+
+        let progress = transition.progress();
+        let direction = match transition.kind {
+            TransitionKind::Fade => 0.0,
+            TransitionKind::SlideForward => 1.0,
+            TransitionKind::SlideBack => -1.0,
+        };
+
+        let unchanged = current.iter().zip(&transition.previous).take_while(|&(a, b)| a == b).count();
+
+        let mut layers: Vec<GUIRenderLayer<'a>> = current[..unchanged].iter().map(|&state| layer(state, 1.0, 0.0)).collect();
+
+        for &state in &transition.previous[unchanged..] {
+            layers.push(layer(state, 1.0 - progress, -direction * TRANSITION_SLIDE_DISTANCE * progress));
+        }
+
+        for &state in &current[unchanged..] {
+            layers.push(layer(state, progress, direction * TRANSITION_SLIDE_DISTANCE * (1.0 - progress)));
+        }
+
+        layers
+    }
+
+    /// Get components of state's `GUILayer`.
+    fn layer_components<'a>(&'a self, state: GUIState) -> GUIComponentReferences<'a> {
+        match state {
             GUIState::MainMenu => self.main_menu.components(),
             GUIState::PauseMenu => self.pause_menu.components(),
             GUIState::SettingsMenu => self.settings_menu.components(),
@@ -286,13 +787,26 @@ impl GUI {
             GUIState::GameOverScreen => self.game_over_screen.components(),
             GUIState::PlayerWinsScreen => self.player_wins_screen.components(),
             GUIState::NextLevelScreen => self.next_level_screen.components(),
+            GUIState::ConfirmDialog => self.confirm_dialog.components(),
         }
     }
 
-    /// Update positions of `GUIFpsCounter` and `GameStatus`.
+    /// Update positions of `GUIFpsCounter`, `GameStatus`, `GUIConsole` and
+    /// components which are anchored to screen edges.
     pub fn update_position_from_half_screen_width(&mut self, width: f32) {
+        self.half_screen_width = width;
+
         self.fps_counter.update_position_from_half_screen_width(width);
         self.game_status.update_position_from_half_screen_width(width);
+        self.console.update_position_from_half_screen_width(width);
+
+        self.main_menu.update_position_from_half_screen_width(width);
+        self.pause_menu.0.update_position_from_half_screen_width(width);
+        self.settings_menu.layer.update_position_from_half_screen_width(width);
+        self.difficulty_selection_menu.update_position_from_half_screen_width(width);
+        self.game_over_screen.update_position_from_half_screen_width(width);
+        self.player_wins_screen.update_position_from_half_screen_width(width);
+        self.next_level_screen.update_position_from_half_screen_width(width);
     }
 }
 
@@ -300,63 +814,79 @@ impl GUI {
 pub struct BasicGUILayer {
      buttons: GUIGroup<GUIButton>,
      texts: Vec<GUIText>,
+     /// X coordinate offsets and anchors of buttons.
+     button_anchors: Vec<(f32, Anchor)>,
+     /// X coordinate offsets and anchors of texts.
+     text_anchors: Vec<(f32, Anchor)>,
 }
 
 impl BasicGUILayer {
-    /// Create main menu.
-    fn main_menu() -> BasicGUILayer {
-        let mut buttons = GUIGroup::new(GUIButton::new(0.0, 1.0, BUTTON_WIDTH, BUTTON_HEIGHT, "Start Game", GUIEvent::ChangeState(GUIState::DifficultySelectionMenu)))
-                              .add(GUIButton::new(0.0, -1.0, BUTTON_WIDTH, BUTTON_HEIGHT, "Settings", GUIEvent::ChangeState(GUIState::SettingsMenu)));
+    /// Create menu from layout.
+    ///
+    /// Argument `width` is screen_width/2.0.
+    fn new(layout: &GUILayout, locale: &Locale, width: f32) -> BasicGUILayer {
+        BasicGUILayer::new_with_buttons(layout, locale, width, GUIGroupBuilder::new(), Vec::new(), 0.0)
+    }
 
-        // Disable Exit button in emscripten build.
-        if cfg!(not(target_os = "emscripten")) {
-            buttons = buttons.add(GUIButton::new(0.0, -3.0, BUTTON_WIDTH, BUTTON_HEIGHT, "Exit", GUIEvent::Exit));
-        }
+    /// Create menu from layout. Layout's buttons are added after buttons in
+    /// argument `buttons` and their y coordinates are moved by `y_offset`.
+    ///
+    /// Argument `width` is screen_width/2.0.
+    fn new_with_buttons(layout: &GUILayout, locale: &Locale, width: f32, mut buttons: GUIGroupBuilder<GUIButton>, mut button_anchors: Vec<(f32, Anchor)>, y_offset: f32) -> BasicGUILayer {
+        let mut selected = layout.selected;
+
+        for (i, button) in layout.buttons.iter().enumerate() {
+            // Disable Exit button in emscripten build.
+            if cfg!(target_os = "emscripten") {
+                if let GUIEvent::Exit | GUIEvent::Confirm(ConfirmAction::Exit) = button.event {
+                    if i < layout.selected {
+                        selected -= 1;
+                    }
+                    continue;
+                }
+            }
 
-        BasicGUILayer {
-            buttons,
-            texts: vec![GUIText::new(0.0, 3.0, "Space Boss Battles")],
+            let x = button.anchor.x(button.x, width);
+            buttons.add(GUIButton::new(x, button.y + y_offset, button.width, button.height, &locale.text(&button.text), button.event));
+            button_anchors.push((button.x, button.anchor));
         }
-    }
 
-    /// Create difficulty selection menu.
-    fn difficulty_selection_menu() -> BasicGUILayer {
-        let mut buttons = GUIGroup::new(GUIButton::new(0.0, 1.5, BUTTON_WIDTH, BUTTON_HEIGHT, "Easy", GUIEvent::NewGame(Difficulty::Easy)))
-            .add(GUIButton::new(0.0, 0.2, BUTTON_WIDTH, BUTTON_HEIGHT, "Normal", GUIEvent::NewGame(Difficulty::Normal)))
-            .add(GUIButton::new(0.0, -1.1, BUTTON_WIDTH, BUTTON_HEIGHT, "Hard", GUIEvent::NewGame(Difficulty::Hard)))
-            .add(GUIButton::new(0.0, -2.7, BUTTON_WIDTH, BUTTON_HEIGHT, "Main Menu", GUIEvent::ChangeState(GUIState::MainMenu)));
+        let mut texts = Vec::new();
+        let mut text_anchors = Vec::new();
 
-        // Set default selection to "Normal".
-        buttons.selection_down();
+        for text in &layout.texts {
+            let x = text.anchor.x(text.x, width);
+            let mut gui_text = GUIText::new_with_alignment(x, text.y, &locale.text(&text.text), text.alignment);
 
-        BasicGUILayer {
-            buttons,
-            texts: vec![GUIText::new(0.0, 3.0, "Select game difficulty")],
+            if let Some(font_size) = text.font_size {
+                gui_text.set_font_size(font_size);
+            }
+
+            texts.push(gui_text);
+            text_anchors.push((text.x, text.anchor));
         }
-    }
 
-    /// Create player wins screen.
-    fn player_wins_screen() -> BasicGUILayer {
+        let mut buttons = buttons.create_gui_group();
+        buttons.set_selected(selected);
+
         BasicGUILayer {
-            buttons: GUIGroup::new(GUIButton::new(0.0, 1.0, BUTTON_WIDTH, BUTTON_HEIGHT, "Main Menu", GUIEvent::ChangeState(GUIState::MainMenu))),
-            // TODO: Player wins screen's GUIText is too long for non widescreen resolutions.
-            texts: vec![GUIText::new(0.0, 3.0, "Congratulations, you won the game")],
+            buttons,
+            texts,
+            button_anchors,
+            text_anchors,
         }
     }
 
-    /// Create game over screen.
-    fn game_over_screen() -> BasicGUILayer {
-        BasicGUILayer {
-            buttons: GUIGroup::new(GUIButton::new(0.0, 1.0, BUTTON_WIDTH, BUTTON_HEIGHT, "Main Menu", GUIEvent::ChangeState(GUIState::MainMenu))),
-            texts: vec![GUIText::new(0.0, 3.0, "Game Over")],
+    /// Update positions of buttons and texts.
+    ///
+    /// Argument `width` is screen_width/2.0.
+    fn update_position_from_half_screen_width(&mut self, width: f32) {
+        for (button, &(x, anchor)) in self.buttons.get_components_mut().iter_mut().zip(&self.button_anchors) {
+            button.set_x(anchor.x(x, width));
         }
-    }
 
-    /// Create next level screen.
-    fn next_level_screen() -> BasicGUILayer {
-        BasicGUILayer {
-            buttons: GUIGroup::new(GUIButton::new(0.0, 1.0, BUTTON_WIDTH, BUTTON_HEIGHT, "Next Level", GUIEvent::NextLevel)),
-            texts: vec![GUIText::new(0.0, 3.0, "Congratulations, you won")],
+        for (text, &(x, anchor)) in self.texts.iter_mut().zip(&self.text_anchors) {
+            text.set_x(anchor.x(x, width));
         }
     }
 }
@@ -372,18 +902,18 @@ impl GUILayerInputHandler for BasicGUILayer {
 }
 
 /// New type `PauseMenu` because selected button
-/// must be reset to "Continue" after pressing "Main Menu" button.
+/// must be reset to "Continue" when pause menu is opened.
 pub struct PauseMenu(BasicGUILayer);
 
 impl PauseMenu {
-    fn new() -> PauseMenu {
-        PauseMenu(
-            BasicGUILayer {
-                buttons: GUIGroup::new(GUIButton::new(0.0, 1.0, BUTTON_WIDTH, BUTTON_HEIGHT, "Continue", GUIEvent::ChangeState(GUIState::Game)))
-                                .add(GUIButton::new(0.0, -1.0, BUTTON_WIDTH, BUTTON_HEIGHT, "Main Menu", GUIEvent::ChangeState(GUIState::MainMenu))),
-                texts: vec![GUIText::new(0.0, 3.0, "Game Paused")],
-            }
-        )
+    /// Create pause menu from layout.
+    fn new(layout: &GUILayout, locale: &Locale, width: f32) -> PauseMenu {
+        PauseMenu(BasicGUILayer::new(layout, locale, width))
+    }
+
+    /// Select the first button.
+    fn reset_selection(&mut self) {
+        self.0.buttons.set_selected(0);
     }
 }
 
@@ -393,40 +923,96 @@ impl GUILayer for PauseMenu {
 
 impl GUILayerInputHandler for PauseMenu {
     fn get_buttons_mut(&mut self) -> &mut GUIGroup<GUIButton> { self.0.get_buttons_mut() }
+}
 
-    /// Reset currently selected button to "Continue" after pressing "Main Menu" button.
-    fn layer_specific_operations(&mut self, event: &mut GUIEvent) {
-        if let &mut GUIEvent::ChangeState(GUIState::MainMenu) = event {
-            self.0.buttons.selection_up();
+/// Modal dialog with accept and cancel buttons.
+///
+/// Dialog is recreated every time it is opened, so cancel button is selected by default.
+pub struct ConfirmDialog {
+    layer: BasicGUILayer,
+    background: [GUIColorRectangle; 1],
+    action: ConfirmAction,
+}
+
+impl ConfirmDialog {
+    /// Create new `ConfirmDialog` for `action`.
+    fn new(action: ConfirmAction, locale: &Locale) -> ConfirmDialog {
+        let x = CONFIRM_DIALOG_BUTTON_WIDTH/2.0 + 0.2;
+        let y = -0.5;
+
+        let buttons = GUIGroup::new(GUIButton::new(-x, y, CONFIRM_DIALOG_BUTTON_WIDTH, BUTTON_HEIGHT, &locale.text("confirm_dialog.accept"), GUIEvent::ConfirmAccept(action)))
+                          .add(GUIButton::new(x, y, CONFIRM_DIALOG_BUTTON_WIDTH, BUTTON_HEIGHT, &locale.text("confirm_dialog.cancel"), GUIEvent::ConfirmCancel(action)));
+
+        let mut layer = BasicGUILayer {
+            buttons,
+            texts: vec![GUIText::new(0.0, 1.0, &locale.text(action.message()))],
+            button_anchors: vec![(-x, Anchor::Center), (x, Anchor::Center)],
+            text_anchors: vec![(0.0, Anchor::Center)],
+        };
+
+        layer.buttons.set_selected(1);
+
+        ConfirmDialog {
+            layer,
+            background: [GUIColorRectangle::new(Point2::new(0.0, 0.3), CONFIRM_DIALOG_WIDTH, CONFIRM_DIALOG_HEIGHT, CONFIRM_DIALOG_BACKGROUND_COLOR)],
+            action,
         }
     }
 }
 
+impl GUILayer for ConfirmDialog {
+    fn components<'a>(&'a self) -> GUIComponentReferences<'a> {
+        self.layer.components().set_rectangles(&self.background)
+    }
+}
+
+impl GUILayerInputHandler for ConfirmDialog {
+    fn get_buttons_mut(&mut self) -> &mut GUIGroup<GUIButton> { self.layer.get_buttons_mut() }
+
+    /// Buttons are side by side, so left and right keys also change the selection.
+    fn layer_specific_input_handling<T: Input>(&mut self, input: &mut T) -> Option<GUIEvent> {
+        if input.key_hit_left() {
+            self.layer.buttons.selection_up();
+        } else if input.key_hit_right() {
+            self.layer.buttons.selection_down();
+        }
+
+        None
+    }
+}
+
 
 /// New type `GameStatus` because game status
 /// screen contains only two `GUIHealthBar`.
 pub struct GameStatus {
     health_bars: [GUIHealthBar; 2],
+    /// Current player and enemy health.
+    health: [u32; 2],
 }
 
 impl GameStatus {
-    /// Create new `GameStatus`.
-    fn new() -> GameStatus {
+    /// Create new `GameStatus` from layout's first two bars.
+    fn new(layout: &GUILayout) -> GameStatus {
+        let bar = |i: usize| {
+            let bar = &layout.bars[i];
+            GUIHealthBar::new(bar.alignment, 0.0, bar.y, bar.width, bar.max_value, bar.low_value, true)
+        };
+
         GameStatus {
-            health_bars: [
-                GUIHealthBar::new(GUIComponentAlignment::Left, 0.0, 4.0, 3.0, 100, 25, true),
-                GUIHealthBar::new(GUIComponentAlignment::Right, 0.0, 4.0, 3.0, 100, 25, true),
-            ],
+            health_bars: [bar(0), bar(1)],
+            health: [0, 0],
         }
     }
 
     /// Updates players health bar.
     pub fn set_player_health(&mut self, health: u32) {
+        self.health[0] = health;
         self.health_bars[0].update_health(health);
     }
 
     /// Updates enemy health bar.
     pub fn set_enemy_health(&mut self, health: u32) {
+        self.health[1] = health;
         self.health_bars[1].update_health(health);
     }
 
@@ -443,161 +1029,389 @@ impl GUILayer for GameStatus {
     }
 }
 
-// TODO: Audio volume sliders mouse support.
+/// Component which displays setting's value in `SettingsMenu`.
+#[derive(Copy, Clone)]
+enum SettingWidget {
+    /// Index to `SettingsMenu`'s sliders.
+    Slider(usize),
+    /// Index to `SettingsMenu`'s checkboxes.
+    Checkbox(usize),
+    /// Index to `SettingsMenu`'s dropdowns.
+    Dropdown(usize),
+}
 
 /// Create settings menu from `Settings`, create
 /// updated setting values and send them with `GUIEvent`.
 pub struct SettingsMenu {
     layer: BasicGUILayer,
-    value_indicators: Vec<GUIHealthBar>,
+    sliders: Vec<GUISlider>,
+    checkboxes: Vec<GUICheckbox>,
+    dropdowns: Vec<GUIDropdown>,
+    /// Setting list rows of sliders, checkboxes and dropdowns.
+    slider_rows: Vec<usize>,
+    checkbox_rows: Vec<usize>,
+    dropdown_rows: Vec<usize>,
+    /// Displayed settings in the same order as setting buttons.
+    settings: Vec<(SettingId, SettingWidget)>,
+    /// Index of setting which slider is currently dragged with mouse.
+    dragged_slider: Option<usize>,
+    scroll_list: GUIScrollList,
 }
 
 impl SettingsMenu {
-    /// Creates new settings menu from `Settings`.
-    fn new(settings: &Settings) -> SettingsMenu {
-        let x_button = -2.0;
-        let x_text = 3.0;
-        let mut y = 2.7;
+    /// Creates new settings menu from layout and setting values
+    /// indexed with `SettingId`.
+    ///
+    /// Integer and float settings are displayed with sliders, boolean
+    /// settings with checkboxes and enum settings with dropdowns. Settings which don't
+    /// fit into the setting list's viewport are displayed by scrolling. Layout's buttons
+    /// are placed after the viewport, so their y coordinates are relative to
+    /// the position of the next setting after the last visible setting.
+    ///
+    /// Argument `width` is screen_width/2.0.
+    ///
+    /// # Panics
+    /// * If layout doesn't have a setting list.
+    fn new(layout: &GUILayout, setting_values: &[SettingValue], locale: &Locale, width: f32) -> SettingsMenu {
+        let setting_list = layout.setting_list.as_ref().expect("settings menu layout must have a setting list");
+        let x_button = setting_list.x;
+        let x_widget = setting_list.widget_x;
+        let y = setting_list.y;
 
         let mut gui_group_builder = GUIGroupBuilder::new();
-        let mut texts = Vec::new();
-        let mut value_indicators = Vec::new();
-
-        for setting in settings.get_settings() {
-            gui_group_builder.add(GUIButton::new(x_button, y, BUTTON_WIDTH, BUTTON_HEIGHT, setting.get_name(), GUIEvent::ChangeSetting(setting.get_value())));
-
-            match setting.get_value() {
-                SettingType::Boolean(_, true) => texts.push(GUIText::new(x_text, y, "Enabled")),
-                SettingType::Boolean(_, false) => texts.push(GUIText::new(x_text, y, "Disabled")),
-                SettingType::Integer(_, value) => {
-                    let mut value_indicator = GUIHealthBar::new(GUIComponentAlignment::Center, x_text, y, 3.0, 100, 0, false);
-                    value_indicator.update_health(value as u32);
-                    value_indicator.update_borders();
-                    value_indicators.push(value_indicator);
-                }
-            }
+        let mut sliders = Vec::new();
+        let mut checkboxes = Vec::new();
+        let mut dropdowns = Vec::new();
+        let mut slider_rows = Vec::new();
+        let mut checkbox_rows = Vec::new();
+        let mut dropdown_rows = Vec::new();
+        let mut displayed_settings = Vec::new();
+        let mut button_anchors = Vec::new();
+
+        for (row, &id) in ALL_SETTINGS.iter().enumerate() {
+            let definition = id.definition();
+            let value = setting_values[id as usize];
+            let event = GUIEvent::ChangeSetting(id, value);
+
+            gui_group_builder.add(GUIButton::new(x_button, y, BUTTON_WIDTH, BUTTON_HEIGHT, &definition.display_name(locale), event));
+            button_anchors.push((x_button, Anchor::Center));
+
+            let widget = match definition.kind {
+                SettingKind::Boolean => {
+                    checkboxes.push(GUICheckbox::new(x_widget, y, value.boolean(), event));
+                    checkbox_rows.push(row);
+                    SettingWidget::Checkbox(checkboxes.len() - 1)
+                },
+                SettingKind::Integer { min, max, step } => {
+                    sliders.push(GUISlider::new(x_widget, y, SLIDER_WIDTH, min as f32, max as f32, step as f32, value.integer() as f32, event));
+                    slider_rows.push(row);
+                    SettingWidget::Slider(sliders.len() - 1)
+                },
+                SettingKind::Float { min, max, step } => {
+                    sliders.push(GUISlider::new(x_widget, y, SLIDER_WIDTH, min, max, step, value.float(), event));
+                    slider_rows.push(row);
+                    SettingWidget::Slider(sliders.len() - 1)
+                },
+                kind @ SettingKind::Enum { .. } => {
+                    let options = option_display_texts(kind, locale);
+                    dropdowns.push(GUIDropdown::new(x_widget, y, DROPDOWN_WIDTH, options, value.enum_index(), event));
+                    dropdown_rows.push(row);
+                    SettingWidget::Dropdown(dropdowns.len() - 1)
+                },
+            };
 
-            y -= 1.15;
+            displayed_settings.push((id, widget));
         }
 
-        texts.push(GUIText::new(0.0, 3.8, "Settings"));
+        let scroll_list = GUIScrollList::new(setting_list.scroll_bar_x, y, setting_list.spacing, setting_list.visible_rows, displayed_settings.len());
+
+        let mut settings_menu = SettingsMenu {
+            layer: BasicGUILayer::new_with_buttons(layout, locale, width, gui_group_builder, button_anchors, scroll_list.bottom_y()),
+            sliders,
+            checkboxes,
+            dropdowns,
+            slider_rows,
+            checkbox_rows,
+            dropdown_rows,
+            settings: displayed_settings,
+            dragged_slider: None,
+            scroll_list,
+        };
 
-        let buttons = gui_group_builder.create_gui_group();
+        settings_menu.update_setting_positions();
+        settings_menu.update_widget_states();
 
-        y -= 0.50;
-        let buttons = buttons.add(GUIButton::new(x_button, y, BUTTON_WIDTH, BUTTON_HEIGHT, "Main Menu", GUIEvent::ChangeState(GUIState::MainMenu)));
+        settings_menu
+    }
 
-        SettingsMenu {
-            layer: BasicGUILayer {buttons, texts},
-            value_indicators,
+    /// Select button and scroll the setting list if the selected button is a setting.
+    fn set_selected(&mut self, index: usize) {
+        self.layer.buttons.set_selected(index);
+        self.scroll_to_selected_setting();
+        self.update_widget_states();
+    }
+
+    /// Scroll the setting list so that selected setting is visible.
+    fn scroll_to_selected_setting(&mut self) {
+        let selected = self.layer.buttons.selected_index();
+
+        if self.scroll_list.scroll_to_row(selected) {
+            self.update_setting_positions();
         }
     }
 
-    /// Toggles boolean setting value if there exist an `GUIButton` with same values
-    /// as arguments `setting` and `value`. Function will also update text related to that button.
-    ///
-    /// Returns the new setting value inside `SettingType` if matching button is found.
-    fn update_boolean_setting(&mut self, setting: BooleanSetting, value: bool) -> Option<SettingType> {
-        for (button, text) in self.layer.buttons.get_components_mut().iter_mut().zip(self.layer.texts.iter_mut()) {
+    /// Move setting buttons and widgets inside the setting
+    /// list's viewport to their rows.
+    fn update_setting_positions(&mut self) {
+        for row in self.scroll_list.visible_rows() {
+            let widget = self.settings[row].1;
+            let y = self.scroll_list.row_y(row);
 
-            if let GUIEvent::ChangeSetting(SettingType::Boolean(events_boolean_setting, value2)) = button.event_data() {
-                if setting == events_boolean_setting && value == value2 {
+            self.layer.buttons.get_components_mut()[row].set_y(y);
 
-                    if value {
-                        text.change_text("Disabled");
-                    } else {
-                        text.change_text("Enabled");
-                    }
-                    let new_setting = SettingType::Boolean(setting, !value);
-                    button.set_event_data(GUIEvent::ChangeSetting(new_setting));
-                    return Some(new_setting);
-                }
+            match widget {
+                SettingWidget::Slider(index) => self.sliders[index].set_y(y),
+                SettingWidget::Checkbox(index) => self.checkboxes[index].set_y(y),
+                SettingWidget::Dropdown(index) => self.dropdowns[index].set_y(y),
             }
-
         }
+    }
 
-        None
+    /// Current setting values indexed with `SettingId`.
+    fn values(&self) -> Vec<SettingValue> {
+        let buttons = self.layer.buttons.get_components();
+
+        self.settings.iter().enumerate().map(|(i, &(id, _))| {
+            match buttons[i].event_data() {
+                GUIEvent::ChangeSetting(_, value) => value,
+                _ => panic!("button of setting \"{}\" has no setting value", id.definition().key),
+            }
+        }).collect()
     }
 
-    /// Set boolean setting value if there exist an `GUIButton` with same setting
-    /// as argument `setting`. Function will also update text related to that button.
-    pub fn set_boolean_setting(&mut self, setting: BooleanSetting, new_value: bool) {
-        for (button, text) in self.layer.buttons.get_components_mut().iter_mut().zip(self.layer.texts.iter_mut()) {
+    /// Set setting value to the setting's `GUIButton` and update
+    /// widget related to that button.
+    pub fn set_setting_value(&mut self, id: SettingId, value: SettingValue) {
+        let event = GUIEvent::ChangeSetting(id, value);
 
-            if let GUIEvent::ChangeSetting(SettingType::Boolean(events_boolean_setting, _)) = button.event_data() {
-                if setting == events_boolean_setting {
+        for (i, &(setting_id, widget)) in self.settings.iter().enumerate() {
+            if setting_id != id {
+                continue;
+            }
 
-                    if new_value {
-                        text.change_text("Enabled");
-                    } else {
-                        text.change_text("Disabled");
-                    }
-                    let new_setting = SettingType::Boolean(setting, new_value);
-                    button.set_event_data(GUIEvent::ChangeSetting(new_setting));
-                }
+            self.layer.buttons.get_components_mut()[i].set_event_data(event);
+
+            match (widget, value) {
+                (SettingWidget::Slider(index), SettingValue::Integer(number)) => self.sliders[index].set_value(number as f32),
+                (SettingWidget::Slider(index), SettingValue::Float(number)) => self.sliders[index].set_value(number),
+                (SettingWidget::Checkbox(index), _) => self.checkboxes[index].set_checked(value.boolean()),
+                (SettingWidget::Dropdown(index), _) => self.dropdowns[index].set_selected(value.enum_index()),
+                (SettingWidget::Slider(_), _) => (),
+            }
+
+            match widget {
+                SettingWidget::Slider(index) => self.sliders[index].set_event_data(event),
+                SettingWidget::Checkbox(index) => self.checkboxes[index].set_event_data(event),
+                SettingWidget::Dropdown(index) => self.dropdowns[index].set_event_data(event),
             }
         }
     }
 
-    /// Tries adding argument `number` to current value of currently selected volume slider.
+    /// Tries changing value of currently selected setting by argument `steps` times the setting's step size.
     ///
-    /// To determine is volume slider selected, the method will check does the currently selected button
-    /// contain an `IntegerSetting`. If button contains an `IntegerSetting`, the button's and slider's integer value will
+    /// If currently selected button contains a setting, the button's value and the widget will
     /// be updated and the new value will be returned as `GUIEvent`.
-    fn update_currently_selected_integer_setting(&mut self, number: i32) -> Option<GUIEvent> {
-         if let GUIEvent::ChangeSetting(SettingType::Integer(integer_setting, value)) = self.layer.buttons.event_of_currently_selected_component() {
+    fn step_currently_selected_setting(&mut self, steps: i32) -> Option<GUIEvent> {
+        if let GUIEvent::ChangeSetting(id, value) = self.layer.buttons.event_of_currently_selected_component() {
+            let new_value = id.definition().kind.step(value, steps);
+            self.set_setting_value(id, new_value);
+
+            Some(GUIEvent::ChangeSetting(id, new_value))
+        } else {
+            None
+        }
+    }
+
+    /// Handle mouse input for widgets and then default input handling.
+    ///
+    /// Pressing mouse button on a slider starts dragging the slider. Pressing
+    /// a checkbox toggles it and pressing a dropdown changes its option. Mouse wheel
+    /// scrolls the setting list and the list follows selection changes. Mouse
+    /// selects only setting buttons inside the setting list's viewport.
+    fn handle_input_with_widgets<T: Input>(&mut self, input: &mut T) -> Option<GUIEvent> {
+        let mut event = None;
+
+        let wheel_scroll = input.mouse_wheel_scroll();
+
+        if wheel_scroll != 0 && self.dragged_slider.is_none() && self.scroll_list.scroll(-wheel_scroll) {
+            self.update_setting_positions();
+
+            if let Some(index) = self.button_at(input.mouse_location()) {
+                self.layer.buttons.set_selected(index);
+            }
+        }
 
-            // TODO: add new component which will check value range
-            let result = value + number;
+        if input.mouse_button_press() {
+            event = self.press_widget(*input.mouse_location());
+        } else if let Some(row) = self.dragged_slider {
+            if !input.mouse_button_down() {
+                self.dragged_slider = None;
+            } else if input.mouse_motion() {
+                event = self.drag_slider(row, *input.mouse_location());
+            }
+        }
 
-            let value = if result < 0 {
-                0
-            } else if 100 < result {
-                100
+        if event.is_none() && self.dragged_slider.is_none() {
+            let selected = self.layer.buttons.selected_index();
+
+            event = if input.mouse_button_hit() {
+                self.button_at(input.mouse_location()).map(|index| {
+                    let mut event = self.layer.buttons.get_components()[index].event_data();
+                    self.layer_specific_operations(&mut event);
+                    event
+                })
+            } else if input.mouse_motion() {
+                if let Some(index) = self.button_at(input.mouse_location()) {
+                    self.layer.buttons.set_selected(index);
+                }
+
+                None
             } else {
-                result
+                self.handle_input(input)
             };
 
-            let updated_gui_event = GUIEvent::ChangeSetting(SettingType::Integer(integer_setting, value));
-            self.layer.buttons.set_event_of_currently_selected_component(updated_gui_event);
+            if self.layer.buttons.selected_index() != selected {
+                self.scroll_to_selected_setting();
+            }
+        }
+
+        self.update_widget_states();
+
+        event
+    }
+
+    /// Index of button at `point`. Only setting buttons
+    /// inside the setting list's viewport are checked.
+    fn button_at(&self, point: &Point2<f32>) -> Option<usize> {
+        let setting_count = self.settings.len();
+        let buttons = self.layer.buttons.get_components();
+
+        self.scroll_list.collision(&buttons[..setting_count], point).or_else(|| {
+            buttons[setting_count..].iter().position(|button| button.collision(point)).map(|i| setting_count + i)
+        })
+    }
+
+    /// Change setting of widget at `point`.
+    fn press_widget(&mut self, point: Point2<f32>) -> Option<GUIEvent> {
+        for row in self.scroll_list.visible_rows() {
+            let (id, widget) = self.settings[row];
+
+            let new_value = match widget {
+                SettingWidget::Slider(index) if self.sliders[index].collision(&point) => {
+                    self.dragged_slider = Some(row);
+                    self.sliders[index].set_value_from_point(&point);
+                    slider_setting_value(id, self.sliders[index].value())
+                },
+                SettingWidget::Checkbox(index) if self.checkboxes[index].collision(&point) => {
+                    SettingValue::Boolean(!self.checkboxes[index].checked())
+                },
+                SettingWidget::Dropdown(index) if self.dropdowns[index].collision(&point) => {
+                    let steps = self.dropdowns[index].step_from_point(&point);
+                    id.definition().kind.step(SettingValue::Enum(self.dropdowns[index].selected()), steps)
+                },
+                _ => continue,
+            };
+
+            self.layer.buttons.set_selected(row);
+            self.set_setting_value(id, new_value);
+
+            return Some(GUIEvent::ChangeSetting(id, new_value));
+        }
+
+        None
+    }
+
+    /// Update value of dragged slider. Returns `None` if value didn't change.
+    fn drag_slider(&mut self, row: usize, point: Point2<f32>) -> Option<GUIEvent> {
+        if let (id, SettingWidget::Slider(index)) = self.settings[row] {
+            let old_value = self.sliders[index].value();
+            self.sliders[index].set_value_from_point(&point);
 
-            if let IntegerSetting::MusicVolume = integer_setting {
-                self.value_indicators[0].update_health(value as u32);
-            } else if let IntegerSetting::SoundEffectVolume = integer_setting {
-                self.value_indicators[1].update_health(value as u32);
+            if self.sliders[index].value() != old_value {
+                let new_value = slider_setting_value(id, self.sliders[index].value());
+                self.set_setting_value(id, new_value);
+                return Some(GUIEvent::ChangeSetting(id, new_value));
             }
+        }
 
-            Some(updated_gui_event)
-        } else {
-            None
+        None
+    }
+
+    /// Set widget of selected setting to selected state.
+    fn update_widget_states(&mut self) {
+        let selected = self.layer.buttons.selected_index();
+
+        for (row, &(_, widget)) in self.settings.iter().enumerate() {
+            let state = || if row == selected { GUIComponentState::Selected } else { GUIComponentState::Normal };
+
+            match widget {
+                SettingWidget::Slider(index) => self.sliders[index].set_state(state()),
+                SettingWidget::Checkbox(index) => self.checkboxes[index].set_state(state()),
+                SettingWidget::Dropdown(index) => self.dropdowns[index].set_state(state()),
+            }
         }
     }
 }
 
+/// Convert slider value to setting value.
+fn slider_setting_value(id: SettingId, value: f32) -> SettingValue {
+    match id.definition().kind {
+        SettingKind::Float { .. } => SettingValue::Float(value),
+        _ => SettingValue::Integer(value.round() as i32),
+    }
+}
+
+/// Display texts of all options of enum setting.
+fn option_display_texts(kind: SettingKind, locale: &Locale) -> Vec<String> {
+    match kind {
+        SettingKind::Enum { options } => (0..options.len()).map(|i| kind.display_text(SettingValue::Enum(i), locale)).collect(),
+        _ => Vec::new(),
+    }
+}
+
 impl GUILayer for SettingsMenu {
     fn components<'a>(&'a self) -> GUIComponentReferences<'a> {
-        self.layer.components().set_health_bars(&self.value_indicators)
+        let buttons = self.layer.buttons.get_components();
+        let setting_count = self.settings.len();
+
+        self.layer.components()
+            .set_buttons(&buttons[setting_count..])
+            .set_list_buttons(&buttons[self.scroll_list.visible_rows()])
+            .set_sliders(self.scroll_list.visible_items(&self.sliders, &self.slider_rows))
+            .set_checkboxes(self.scroll_list.visible_items(&self.checkboxes, &self.checkbox_rows))
+            .set_dropdowns(self.scroll_list.visible_items(&self.dropdowns, &self.dropdown_rows))
+            .set_rectangles(self.scroll_list.rectangles())
     }
 }
 
 impl GUILayerInputHandler for SettingsMenu {
     fn get_buttons_mut(&mut self) -> &mut GUIGroup<GUIButton> { self.layer.get_buttons_mut() }
 
-    /// Toggle `BooleanSetting`.
+    /// Toggle boolean setting or change enum setting to the next option.
     fn layer_specific_operations(&mut self, event: &mut GUIEvent) {
-        if let &mut GUIEvent::ChangeSetting(SettingType::Boolean(setting_event, value)) = event {
-            if let Some(updated_setting) = self.update_boolean_setting(setting_event, value) {
-                *event = GUIEvent::ChangeSetting(updated_setting);
-            }
+        if let GUIEvent::ChangeSetting(id, value) = *event {
+            let new_value = id.definition().kind.activate(value);
+            self.set_setting_value(id, new_value);
+            *event = GUIEvent::ChangeSetting(id, new_value);
         }
     }
 
-    /// Change slider values with left and right keys.
+    /// Change setting values with left and right keys.
     fn layer_specific_input_handling<T: Input>(&mut self, input: &mut T) -> Option<GUIEvent> {
         if input.key_hit_left() {
-            self.update_currently_selected_integer_setting(-20)
+            self.step_currently_selected_setting(-1)
         } else if input.key_hit_right() {
-            self.update_currently_selected_integer_setting(20)
+            self.step_currently_selected_setting(1)
         } else {
             None
         }
@@ -14,6 +14,8 @@ MIT License
 
 //! Input handling.
 
+use std::mem;
+
 use cgmath::Point2;
 
 use utils::TimeMilliseconds;
@@ -29,6 +31,9 @@ pub enum Key {
     Shoot,
     Select,
     Back,
+    DebugOverlay,
+    Console,
+    Backspace,
 }
 
 /// Interface for game components requiring user input information.
@@ -58,14 +63,30 @@ pub trait Input {
     fn key_hit_enter(&mut self) -> bool;
     /// Key hit for back key.
     fn key_hit_back(&mut self) -> bool;
-
-    /// Button hit for any mouse button.
+    /// Key hit for debug overlay key.
+    fn key_hit_debug_overlay(&mut self) -> bool;
+    /// Key hit for developer console key.
+    fn key_hit_console(&mut self) -> bool;
+    /// Key hit for backspace key.
+    fn key_hit_backspace(&mut self) -> bool;
+    /// Text typed with keyboard. Resets to empty string.
+    fn text_input(&mut self) -> String;
+
+    /// Button hit for any mouse button. Button hit occurs
+    /// when button is released.
     fn mouse_button_hit(&mut self) -> bool;
+    /// Button press for any mouse button. Resets to false.
+    fn mouse_button_press(&mut self) -> bool;
+    /// Is any mouse button down currently. Useful for dragging.
+    fn mouse_button_down(&self) -> bool;
     /// Is mouse location update occurred.
     /// Resets to false.
     fn mouse_motion(&mut self) -> bool;
     /// Current location of mouse in world coordinates.
     fn mouse_location(&self) -> &Point2<f32>;
+    /// Mouse wheel scroll in lines. Positive values scroll up.
+    /// Resets to zero.
+    fn mouse_wheel_scroll(&mut self) -> i32;
 }
 
 /// Handles user input events and stores current input state.
@@ -77,6 +98,8 @@ pub trait Input {
 pub struct InputManager {
     keyboard: KeyboardManager,
     mouse: MouseManager,
+    /// Text typed since the last update.
+    text_input: String,
 }
 
 impl InputManager {
@@ -85,6 +108,7 @@ impl InputManager {
         InputManager {
             keyboard: KeyboardManager::new(),
             mouse: MouseManager::new(),
+            text_input: String::new(),
         }
     }
 
@@ -108,9 +132,25 @@ impl InputManager {
         self.mouse.update_mouse_button_up(point);
     }
 
-    /// Resets `MouseManager` button hits and updates `KeyboardManager`
+    /// Handle mouse button down event.
+    pub fn update_mouse_button_down(&mut self, point: Point2<f32>) {
+        self.mouse.update_mouse_button_down(point);
+    }
+
+    /// Handle text input event.
+    pub fn update_text_input(&mut self, text: &str) {
+        self.text_input.push_str(text);
+    }
+
+    /// Handle mouse wheel event. Argument `lines` is positive when scrolling up.
+    pub fn update_mouse_wheel(&mut self, lines: i32) {
+        self.mouse.wheel_scroll += lines;
+    }
+
+    /// Resets `MouseManager` button hits and text input, and updates `KeyboardManager`
     pub fn update(&mut self, current_time: &TimeMilliseconds) {
         self.mouse.reset_button_hits();
+        self.text_input.clear();
         self.keyboard.update(current_time);
     }
 }
@@ -136,17 +176,33 @@ impl Input for InputManager {
     fn key_hit_right(&mut self) -> bool  { self.keyboard.key_hit_right.key_hit() }
     fn key_hit_enter(&mut self) -> bool  { return_and_reset(&mut self.keyboard.key_hit_enter) }
     fn key_hit_back(&mut self) -> bool   { return_and_reset(&mut self.keyboard.key_hit_back) }
+    fn key_hit_debug_overlay(&mut self) -> bool { return_and_reset(&mut self.keyboard.key_hit_debug_overlay) }
+    fn key_hit_console(&mut self) -> bool { return_and_reset(&mut self.keyboard.key_hit_console) }
+    fn key_hit_backspace(&mut self) -> bool { self.keyboard.key_hit_backspace.key_hit() }
+    fn text_input(&mut self) -> String { mem::replace(&mut self.text_input, String::new()) }
 
     fn mouse_button_hit(&mut self) -> bool      { return_and_reset(&mut self.mouse.mouse_button_hit) }
+    fn mouse_button_press(&mut self) -> bool    { return_and_reset(&mut self.mouse.mouse_button_press) }
+    fn mouse_button_down(&self) -> bool         { self.mouse.buttons_down > 0 }
     fn mouse_motion(&mut self) -> bool          { return_and_reset(&mut self.mouse.mouse_motion) }
     fn mouse_location(&self) -> &Point2<f32>    { &self.mouse.mouse_location }
+    fn mouse_wheel_scroll(&mut self) -> i32 {
+        let lines = self.mouse.wheel_scroll;
+        self.mouse.wheel_scroll = 0;
+        lines
+    }
 }
 
-/// Store mouse location and button hit
+/// Store mouse location, button hit and button state
 struct MouseManager {
     mouse_motion: bool,
     mouse_button_hit: bool,
+    mouse_button_press: bool,
+    /// Count of mouse buttons which are currently down.
+    buttons_down: u32,
     mouse_location: Point2<f32>,
+    /// Mouse wheel scroll in lines since the last reset.
+    wheel_scroll: i32,
 }
 
 impl MouseManager {
@@ -155,13 +211,18 @@ impl MouseManager {
         MouseManager {
             mouse_motion: false,
             mouse_button_hit: false,
+            mouse_button_press: false,
+            buttons_down: 0,
             mouse_location: Point2::new(0.0, 0.0),
+            wheel_scroll: 0,
         }
     }
 
-    /// Reset mouse button hit.
+    /// Reset mouse button hit, press and wheel scroll.
     pub fn reset_button_hits(&mut self) {
         self.mouse_button_hit = false;
+        self.mouse_button_press = false;
+        self.wheel_scroll = 0;
     }
 
     /// Handle mouse motion event.
@@ -173,6 +234,14 @@ impl MouseManager {
     /// Handle mouse button up event.
     pub fn update_mouse_button_up(&mut self, point: Point2<f32>) {
         self.mouse_button_hit = true;
+        self.buttons_down = self.buttons_down.saturating_sub(1);
+        self.mouse_location = point;
+    }
+
+    /// Handle mouse button down event.
+    pub fn update_mouse_button_down(&mut self, point: Point2<f32>) {
+        self.mouse_button_press = true;
+        self.buttons_down += 1;
         self.mouse_location = point;
     }
 }
@@ -188,14 +257,18 @@ struct KeyboardManager {
     left: bool,
     right: bool,
     shoot: bool,
+    backspace: bool,
 
     key_hit_left: KeyHitGenerator,
     key_hit_right: KeyHitGenerator,
     key_hit_up: KeyHitGenerator,
     key_hit_down: KeyHitGenerator,
+    key_hit_backspace: KeyHitGenerator,
 
     key_hit_enter: bool,
     key_hit_back: bool,
+    key_hit_debug_overlay: bool,
+    key_hit_console: bool,
 }
 
 impl KeyboardManager {
@@ -208,14 +281,18 @@ impl KeyboardManager {
             left: false,
             right: false,
             shoot: false,
+            backspace: false,
 
             key_hit_left: KeyHitGenerator::new(),
             key_hit_right: KeyHitGenerator::new(),
             key_hit_up: KeyHitGenerator::new(),
             key_hit_down: KeyHitGenerator::new(),
+            key_hit_backspace: KeyHitGenerator::new(),
 
             key_hit_enter: false,
             key_hit_back: false,
+            key_hit_debug_overlay: false,
+            key_hit_console: false,
         }
     }
 
@@ -246,6 +323,12 @@ impl KeyboardManager {
             Key::Shoot => self.shoot = key_down_field,
             Key::Select => self.key_hit_enter = key_hit_field,
             Key::Back  => self.key_hit_back = key_hit_field,
+            Key::DebugOverlay => self.key_hit_debug_overlay = key_hit_field,
+            Key::Console => self.key_hit_console = key_hit_field,
+            Key::Backspace => {
+                self.backspace = key_down_field;
+                self.key_hit_backspace.update_from_key_event(key_event, current_time);
+            },
         }
     }
 
@@ -253,11 +336,14 @@ impl KeyboardManager {
     fn reset_key_hits(&mut self) {
         self.key_hit_enter = false;
         self.key_hit_back = false;
+        self.key_hit_debug_overlay = false;
+        self.key_hit_console = false;
 
         self.key_hit_up.clear();
         self.key_hit_down.clear();
         self.key_hit_left.clear();
         self.key_hit_right.clear();
+        self.key_hit_backspace.clear();
     }
 
     /// Reset key hit fields and `KeyHitGenerator`s and updates `KeyHitGenerator`s
@@ -268,6 +354,7 @@ impl KeyboardManager {
         self.key_hit_down.update(current_time, self.down);
         self.key_hit_left.update(current_time, self.left);
         self.key_hit_right.update(current_time, self.right);
+        self.key_hit_backspace.update(current_time, self.backspace);
     }
 }
 
@@ -0,0 +1,126 @@
+/*
+src/locale.rs, 2017-09-27
+
+Copyright (c) 2017 Juuso Tuononen
+
+This file is licensed under
+
+Apache License, Version 2.0
+
+or
+
+MIT License
+*/
+
+//! Translations of user-facing text.
+//!
+//! Every language has a string table file `locales/LANGUAGE.toml` in
+//! the game data directory. String tables are TOML files where
+//! texts are grouped with tables:
+//!
+//! ```toml
+//! [main_menu]
+//! start_game = "Start Game"
+//! ```
+//!
+//! Text from the example is found with key `main_menu.start_game`. Texts missing
+//! from the selected language are searched from the default language.
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::Read;
+
+use toml::Value;
+use toml::value::Table;
+
+use paths::GamePaths;
+
+/// Language which contains every text of the game. Texts
+/// missing from other languages are searched from this language.
+pub const DEFAULT_LANGUAGE: &'static str = "en";
+
+/// String table of one language.
+pub struct Locale {
+    texts: HashMap<String, String>,
+    /// Texts of `DEFAULT_LANGUAGE`.
+    fallback: HashMap<String, String>,
+}
+
+impl Locale {
+    /// Load string tables of argument `language` and `DEFAULT_LANGUAGE`.
+    ///
+    /// Loading errors are logged and missing string tables are
+    /// replaced with empty string tables.
+    pub fn load(paths: &GamePaths, language: &str) -> Locale {
+        let fallback = load_string_table(paths, DEFAULT_LANGUAGE);
+
+        let texts = if language == DEFAULT_LANGUAGE {
+            HashMap::new()
+        } else {
+            load_string_table(paths, language)
+        };
+
+        Locale { texts, fallback }
+    }
+
+    /// Get text with `key`.
+    ///
+    /// Returns `None` if text is missing from both the selected
+    /// language and the default language.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.texts.get(key).or_else(|| self.fallback.get(key)).map(|text| text.as_str())
+    }
+
+    /// Get text with `key`. If the text is missing, a warning
+    /// is logged and the key is returned.
+    pub fn text(&self, key: &str) -> String {
+        match self.get(key) {
+            Some(text) => text.to_string(),
+            None => {
+                warn!("locale: text \"{}\" is missing", key);
+                key.to_string()
+            }
+        }
+    }
+}
+
+/// Load string table file of language.
+///
+/// Errors are logged and empty string table is returned if loading fails.
+fn load_string_table(paths: &GamePaths, language: &str) -> HashMap<String, String> {
+    let file_path = paths.data_file(&format!("locales/{}.toml", language));
+    let mut texts = HashMap::new();
+
+    let mut file_text = String::new();
+
+    if let Err(error) = File::open(&file_path).and_then(|mut file| file.read_to_string(&mut file_text)) {
+        error!("locale: couldn't read file {}: {}", file_path, error);
+        return texts;
+    }
+
+    match file_text.parse::<Value>() {
+        Ok(Value::Table(table)) => add_texts(&mut texts, "", table),
+        Ok(_) => error!("locale: file {} is not a TOML table", file_path),
+        Err(error) => error!("locale: file {} parsing error: {}", file_path, error),
+    }
+
+    texts
+}
+
+/// Add strings from TOML table to `texts`. Keys of strings in
+/// nested tables are joined with dots.
+fn add_texts(texts: &mut HashMap<String, String>, key_prefix: &str, table: Table) {
+    for (key, value) in table {
+        let key = if key_prefix.is_empty() {
+            key
+        } else {
+            format!("{}.{}", key_prefix, key)
+        };
+
+        match value {
+            Value::String(text) => { texts.insert(key, text); },
+            Value::Table(table) => add_texts(texts, &key, table),
+            _ => warn!("locale: value of \"{}\" is not a string", key),
+        }
+    }
+}
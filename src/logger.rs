@@ -0,0 +1,299 @@
+/*
+src/logger.rs, 2017-09-10
+
+Copyright (c) 2017 Juuso Tuononen
+
+This file is licensed under
+
+Apache License, Version 2.0
+
+or
+
+MIT License
+*/
+
+//! Leveled logging to standard output and optional log file.
+//!
+//! Game's components write log messages with `log` crate's macros
+//! like `info!` and `error!`. Module path of the message is used as
+//! message's target, so log levels can be set separately for different
+//! modules like `settings` or `window::sdl2`.
+
+use std::fs::{self, File, OpenOptions};
+use std::io::prelude::*;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use log::{self, Log, Metadata, Record, LevelFilter};
+
+/// Log file name. Log file will be created to the same
+/// directory where the settings file is.
+pub const LOG_FILE_NAME: &'static str = "space_boss_battles.log";
+
+/// Log file will be rotated when it gets bigger than this.
+const LOG_FILE_MAX_SIZE_BYTES: u64 = 1024*1024;
+
+/// How many old log files will be kept in addition to the current log file.
+const LOG_FILE_OLD_FILE_COUNT: u32 = 3;
+
+/// Module path prefix which is removed from log message targets.
+const CRATE_MODULE_PATH_PREFIX: &'static str = "space_boss_battles::";
+
+/// Default log level and log levels for specific targets.
+#[derive(Clone, Debug)]
+pub struct LogLevelSettings {
+    default_level: LevelFilter,
+    targets: Vec<(String, LevelFilter)>,
+}
+
+impl LogLevelSettings {
+    /// Create new `LogLevelSettings` with default level `Info`.
+    pub fn new() -> LogLevelSettings {
+        LogLevelSettings {
+            default_level: LevelFilter::Info,
+            targets: Vec::new(),
+        }
+    }
+
+    /// Parse log level settings from text.
+    ///
+    /// Text must be comma separated list of log levels. List
+    /// item `LEVEL` sets the default log level and list item `TARGET=LEVEL` sets log level
+    /// for specific target.
+    ///
+    /// Supported log levels are `off`, `error`, `warn`, `info`, `debug` and `trace`.
+    ///
+    /// # Example
+    /// `warn,settings=debug,window::sdl2=trace`
+    ///
+    /// Returns with `Err(error_message)` if there is parsing error.
+    pub fn parse(text: &str) -> Result<LogLevelSettings, String> {
+        let mut settings = LogLevelSettings::new();
+
+        for item in text.split(',') {
+            let item = item.trim();
+
+            if item == "" {
+                continue;
+            }
+
+            let mut iterator = item.split('=');
+
+            match (iterator.next(), iterator.next(), iterator.next()) {
+                (Some(level), None, None) => settings.default_level = parse_level(level)?,
+                (Some(target), Some(level), None) => {
+                    let level = parse_level(level)?;
+                    settings.targets.push((target.to_string(), level));
+                },
+                _ => return Err(format!("invalid log level setting \"{}\"", item)),
+            }
+        }
+
+        Ok(settings)
+    }
+
+    /// Log level for specific target. If there is multiple target
+    /// settings matching with the target, the longest target setting
+    /// will be used.
+    fn level_for_target(&self, target: &str) -> LevelFilter {
+        let target = short_target(target);
+
+        let mut level = self.default_level;
+        let mut matching_target_length = 0;
+
+        for &(ref setting_target, setting_level) in &self.targets {
+            let matches = target == setting_target ||
+                (target.starts_with(setting_target.as_str()) && target[setting_target.len()..].starts_with("::"));
+
+            if matches && setting_target.len() >= matching_target_length {
+                level = setting_level;
+                matching_target_length = setting_target.len();
+            }
+        }
+
+        level
+    }
+
+    /// Most verbose log level from all settings.
+    fn max_level(&self) -> LevelFilter {
+        let mut max_level = self.default_level;
+
+        for &(_, level) in &self.targets {
+            if level > max_level {
+                max_level = level;
+            }
+        }
+
+        max_level
+    }
+}
+
+/// Parse log level from text.
+fn parse_level(text: &str) -> Result<LevelFilter, String> {
+    let level = match text.trim().to_lowercase().as_str() {
+        "off" => LevelFilter::Off,
+        "error" => LevelFilter::Error,
+        "warn" => LevelFilter::Warn,
+        "info" => LevelFilter::Info,
+        "debug" => LevelFilter::Debug,
+        "trace" => LevelFilter::Trace,
+        _ => return Err(format!("unknown log level \"{}\"", text)),
+    };
+
+    Ok(level)
+}
+
+/// Removes crate name from log message target.
+fn short_target(target: &str) -> &str {
+    if target.starts_with(CRATE_MODULE_PATH_PREFIX) {
+        &target[CRATE_MODULE_PATH_PREFIX.len()..]
+    } else {
+        target
+    }
+}
+
+/// Log file which will be rotated when it gets too big.
+///
+/// Old log files are named like `space_boss_battles.log.1` where
+/// bigger number means older log file.
+struct RotatingLogFile {
+    path: PathBuf,
+    file: File,
+    size: u64,
+}
+
+impl RotatingLogFile {
+    /// Rotate old log files and create new log file.
+    fn new(path: PathBuf) -> Result<RotatingLogFile, String> {
+        let file = RotatingLogFile::rotate_and_create(&path)?;
+
+        Ok(RotatingLogFile {
+            path,
+            file,
+            size: 0,
+        })
+    }
+
+    /// Rename existing log files and create new empty log file.
+    fn rotate_and_create(path: &Path) -> Result<File, String> {
+        if let Some(dir) = path.parent() {
+            if dir != Path::new("") {
+                fs::create_dir_all(dir).map_err(|error| error.to_string())?;
+            }
+        }
+
+        for i in (1..LOG_FILE_OLD_FILE_COUNT).rev() {
+            let old_file = old_log_file_path(path, i);
+
+            if old_file.exists() {
+                fs::rename(&old_file, old_log_file_path(path, i + 1)).map_err(|error| error.to_string())?;
+            }
+        }
+
+        if path.exists() {
+            fs::rename(path, old_log_file_path(path, 1)).map_err(|error| error.to_string())?;
+        }
+
+        OpenOptions::new().write(true).create(true).truncate(true).open(path).map_err(|error| error.to_string())
+    }
+
+    /// Write line to log file. Log file will be rotated before writing
+    /// if file size is over `LOG_FILE_MAX_SIZE_BYTES`.
+    fn write_line(&mut self, line: &str) {
+        if self.size >= LOG_FILE_MAX_SIZE_BYTES {
+            match RotatingLogFile::rotate_and_create(&self.path) {
+                Ok(file) => {
+                    self.file = file;
+                    self.size = 0;
+                },
+                // Keep writing to the current file if rotation fails.
+                Err(_) => (),
+            }
+        }
+
+        if let Ok(()) = writeln!(self.file, "{}", line) {
+            self.size += line.len() as u64 + 1;
+        }
+    }
+
+    /// Flush log file.
+    fn flush(&mut self) {
+        let _ = self.file.flush();
+    }
+}
+
+/// Path for old log file with specific number.
+fn old_log_file_path(path: &Path, number: u32) -> PathBuf {
+    let mut file_name = path.file_name().map(|name| name.to_os_string()).unwrap_or_default();
+    file_name.push(format!(".{}", number));
+    path.with_file_name(file_name)
+}
+
+/// Logger which writes log messages to standard output and
+/// optionally to a log file.
+struct Logger {
+    level_settings: LogLevelSettings,
+    log_file: Option<Mutex<RotatingLogFile>>,
+}
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= self.level_settings.level_for_target(metadata.target())
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+
+        let line = format!("[{} {}] {}", record.level(), short_target(record.target()), record.args());
+
+        println!("{}", line);
+
+        if let Some(ref log_file) = self.log_file {
+            if let Ok(mut log_file) = log_file.lock() {
+                log_file.write_line(&line);
+            }
+        }
+    }
+
+    fn flush(&self) {
+        if let Some(ref log_file) = self.log_file {
+            if let Ok(mut log_file) = log_file.lock() {
+                log_file.flush();
+            }
+        }
+    }
+}
+
+/// Set global logger for `log` crate's macros.
+///
+/// If argument `log_file_path` is `Some(path)`, log messages will also be written to
+/// the log file. If log file creation fails, an error message will be logged
+/// and logging continues without the log file.
+///
+/// # Panics
+/// If global logger is already set.
+pub fn init(level_settings: LogLevelSettings, log_file_path: Option<PathBuf>) {
+    let mut log_file_error = None;
+
+    let log_file = match log_file_path {
+        Some(path) => match RotatingLogFile::new(path.clone()) {
+            Ok(log_file) => Some(Mutex::new(log_file)),
+            Err(error) => {
+                log_file_error = Some(format!("couldn't create log file {}: {}", path.display(), error));
+                None
+            }
+        },
+        None => None,
+    };
+
+    let max_level = level_settings.max_level();
+
+    log::set_boxed_logger(Box::new(Logger { level_settings, log_file })).expect("logger is already set");
+    log::set_max_level(max_level);
+
+    if let Some(error) = log_file_error {
+        error!("{}", error);
+    }
+}
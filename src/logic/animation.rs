@@ -0,0 +1,151 @@
+/*
+src/logic/animation.rs, 2017-09-20
+
+Copyright (c) 2017 Juuso Tuononen
+
+This file is licensed under
+
+Apache License, Version 2.0
+
+or
+
+MIT License
+*/
+
+//! Frame based sprite animations.
+//!
+//! Every animation has a sprite sheet image, where animation frames are
+//! squares placed next to each other from left to right. Game objects
+//! store `AnimationPlayer` and renderer uses player's current frame
+//! to select correct part of the sprite sheet.
+
+use utils::{Timer, GameTimeManager};
+
+/// What happens when last frame of an animation ends.
+#[derive(Copy, Clone, PartialEq)]
+pub enum Playback {
+    /// Start again from the first frame.
+    Loop,
+    /// Stay at the last frame and set animation finished.
+    OneShot,
+}
+
+/// Frame durations and playback mode of an animation.
+pub struct AnimationDefinition {
+    /// Duration of every frame in milliseconds. Animation's
+    /// frame count is length of this slice.
+    pub frame_durations_milliseconds: &'static [u32],
+    pub playback: Playback,
+}
+
+/// All animations.
+#[derive(Copy, Clone, PartialEq)]
+pub enum Animations {
+    EnemyThruster,
+    ShieldShimmer,
+    Explosion,
+    ExplosionSpark,
+    AnimationCount,
+}
+
+impl Animations {
+    /// Get animation's definition.
+    pub fn definition(self) -> AnimationDefinition {
+        let (frame_durations_milliseconds, playback): (&'static [u32], Playback) = match self {
+            Animations::EnemyThruster => (&[60, 50, 70, 50], Playback::Loop),
+            Animations::ShieldShimmer => (&[150, 150, 150, 150], Playback::Loop),
+            Animations::Explosion => (&[80, 80, 90, 100, 110, 130, 150, 200], Playback::OneShot),
+            Animations::ExplosionSpark => (&[100, 120, 150, 200], Playback::OneShot),
+            Animations::AnimationCount => (&[], Playback::OneShot),
+        };
+
+        AnimationDefinition {
+            frame_durations_milliseconds,
+            playback,
+        }
+    }
+
+    /// Number of frames in the animation.
+    pub fn frame_count(self) -> usize {
+        self.definition().frame_durations_milliseconds.len()
+    }
+}
+
+/// Current state of an animation.
+pub struct AnimationPlayer {
+    animation: Animations,
+    timer: Timer,
+    frame: usize,
+    finished: bool,
+}
+
+impl AnimationPlayer {
+    /// Create new `AnimationPlayer`. Animation starts
+    /// from game time zero, so call method `restart` to
+    /// start the animation from the current time.
+    pub fn new(animation: Animations) -> AnimationPlayer {
+        AnimationPlayer {
+            animation,
+            timer: Timer::new(),
+            frame: 0,
+            finished: false,
+        }
+    }
+
+    /// Start animation again from the first frame.
+    pub fn restart(&mut self, current_time: &GameTimeManager) {
+        self.timer.reset(current_time.time());
+        self.frame = 0;
+        self.finished = false;
+    }
+
+    /// Update current frame.
+    pub fn update(&mut self, current_time: &GameTimeManager) {
+        if self.finished {
+            return;
+        }
+
+        let definition = self.animation.definition();
+        let durations = definition.frame_durations_milliseconds;
+
+        let total_duration: u32 = durations.iter().sum();
+        let mut time = self.timer.milliseconds(current_time.time());
+
+        if time >= total_duration {
+            match definition.playback {
+                Playback::Loop => time %= total_duration,
+                Playback::OneShot => {
+                    self.frame = durations.len().saturating_sub(1);
+                    self.finished = true;
+                    return;
+                }
+            }
+        }
+
+        self.frame = 0;
+
+        for &duration in durations {
+            if time < duration {
+                break;
+            }
+
+            time -= duration;
+            self.frame += 1;
+        }
+    }
+
+    /// Animation which this player plays.
+    pub fn animation(&self) -> Animations {
+        self.animation
+    }
+
+    /// Index of current frame.
+    pub fn frame(&self) -> usize {
+        self.frame
+    }
+
+    /// Returns true if one shot animation has ended.
+    pub fn finished(&self) -> bool {
+        self.finished
+    }
+}
@@ -0,0 +1,272 @@
+/*
+src/logic/background.rs, 2017-09-25
+
+Copyright (c) 2017 Juuso Tuononen
+
+This file is licensed under
+
+Apache License, Version 2.0
+
+or
+
+MIT License
+*/
+
+//! Parallax background.
+//!
+//! Background is made of layers which move at different speeds. Layer is
+//! either tiles of background texture or a procedurally generated
+//! star field. Every level has its own set of layers.
+
+use cgmath::{Matrix4, Vector2, Vector4, vec2};
+
+use rand::{Rng, SeedableRng, XorShiftRng};
+
+use logic::common::*;
+use logic::particle::BlendMode;
+
+use utils::GameTimeManager;
+
+use renderer::{ModelMatrix, SCREEN_TOP_Y_VALUE_IN_WORLD_COORDINATES};
+
+const BACKGROUND_MOVING_SPEED: f32 = -0.02;
+const BACKGROUND_SQUARE_SIDE_LENGTH: f32 = 9.0;
+
+/// Width of the area where stars are placed. Stars
+/// move to the other side of the area when they reach the area's edge.
+const STAR_FIELD_WIDTH: f32 = BACKGROUND_SQUARE_SIDE_LENGTH * 4.0;
+
+/// Content of background layer.
+#[derive(Copy, Clone)]
+pub enum BackgroundLayerKind {
+    /// Scrolling tiles of background texture.
+    Texture {
+        side_length: f32,
+    },
+    /// Randomly placed square stars.
+    Stars {
+        count: usize,
+        min_size: f32,
+        max_size: f32,
+        /// Seed for the star positions.
+        seed: u32,
+    },
+}
+
+/// Settings of one background layer.
+#[derive(Copy, Clone)]
+pub struct BackgroundLayerSettings {
+    pub kind: BackgroundLayerKind,
+    /// Movement speed to x direction. Negative values move layer to the left.
+    pub speed: f32,
+    /// Texture color is multiplied with this color. Stars are
+    /// rendered with this color.
+    pub color: Vector4<f32>,
+    pub blend_mode: BlendMode,
+}
+
+/// Background layers of level. Layers are listed from the
+/// farthest layer to the nearest layer.
+pub fn level_background(level: u32) -> Vec<BackgroundLayerSettings> {
+    let texture = |speed, color| BackgroundLayerSettings {
+        kind: BackgroundLayerKind::Texture { side_length: BACKGROUND_SQUARE_SIDE_LENGTH },
+        speed,
+        color,
+        blend_mode: BlendMode::Alpha,
+    };
+
+    let stars = |count, min_size, max_size, seed, speed, color| BackgroundLayerSettings {
+        kind: BackgroundLayerKind::Stars { count, min_size, max_size, seed },
+        speed,
+        color,
+        blend_mode: BlendMode::Additive,
+    };
+
+    match level {
+        0 => vec![
+            texture(BACKGROUND_MOVING_SPEED, Vector4::new(1.0, 1.0, 1.0, 1.0)),
+            stars(60, 0.03, 0.06, 1, -0.03, Vector4::new(0.8, 0.8, 1.0, 0.6)),
+            stars(20, 0.06, 0.1, 2, -0.06, Vector4::new(1.0, 1.0, 1.0, 0.8)),
+        ],
+        1 => vec![
+            texture(-0.015, Vector4::new(0.6, 0.75, 1.0, 1.0)),
+            stars(80, 0.03, 0.05, 3, -0.025, Vector4::new(0.5, 0.7, 1.0, 0.6)),
+            stars(30, 0.05, 0.09, 4, -0.05, Vector4::new(0.7, 0.9, 1.0, 0.8)),
+        ],
+        2 => vec![
+            texture(-0.025, Vector4::new(1.0, 0.65, 0.55, 1.0)),
+            stars(50, 0.03, 0.06, 5, -0.035, Vector4::new(1.0, 0.7, 0.4, 0.6)),
+            stars(15, 0.08, 0.12, 6, -0.08, Vector4::new(1.0, 0.9, 0.6, 0.8)),
+        ],
+        _ => vec![
+            texture(-0.03, Vector4::new(0.8, 0.55, 1.0, 1.0)),
+            stars(100, 0.02, 0.05, 7, -0.04, Vector4::new(0.9, 0.6, 1.0, 0.5)),
+            stars(40, 0.05, 0.08, 8, -0.07, Vector4::new(1.0, 0.8, 1.0, 0.7)),
+            stars(10, 0.1, 0.14, 9, -0.12, Vector4::new(1.0, 1.0, 1.0, 0.9)),
+        ],
+    }
+}
+
+/// Background tile or star that moves and resets it's position.
+pub struct Background {
+    data: Data<f32>,
+    x_limit: f32,
+    /// Distance to move when background goes over `x_limit`.
+    x_wrap_distance: f32,
+    speed: f32,
+}
+
+impl Background {
+    /// Create new `Background`.
+    fn new(position: Vector2<f32>, side_length: f32, x_limit: f32, x_wrap_distance: f32, speed: f32) -> Background {
+        Background {
+            data: Data::new_square(position, side_length),
+            x_limit,
+            x_wrap_distance,
+            speed,
+        }
+    }
+
+    /// Moves background forward, and resets background position if it's x coordinate.
+    /// goes under the current limit.
+    fn update(&mut self, current_time: &GameTimeManager) {
+        let speed = self.speed;
+        self.move_position(speed*current_time.delta_time(), 0.0);
+
+        if self.x() <= self.x_limit {
+            let (x, y) = (self.x() + self.x_wrap_distance, self.y());
+            self.set_position(x, y);
+            self.data_mut().skip_interpolation();
+        }
+    }
+}
+
+impl GameObject for Background {}
+
+impl ModelMatrix for Background {
+    fn model_matrix(&self) -> &Matrix4<f32> {
+        &self.data.model_matrix
+    }
+
+    fn interpolated_model_matrix(&self, interpolation_factor: f32) -> Matrix4<f32> {
+        self.data.interpolated_model_matrix(interpolation_factor)
+    }
+}
+
+impl GameObjectData<f32> for Background {
+    fn data(&self) -> &Data<f32> {
+        &self.data
+    }
+    fn data_mut(&mut self) -> &mut Data<f32> {
+        &mut self.data
+    }
+}
+
+/// Layer of backgrounds moving at the same speed.
+pub struct BackgroundLayer {
+    settings: BackgroundLayerSettings,
+    backgrounds: Vec<Background>,
+}
+
+impl BackgroundLayer {
+    /// Create new `BackgroundLayer`.
+    fn new(settings: BackgroundLayerSettings) -> BackgroundLayer {
+        let backgrounds = match settings.kind {
+            BackgroundLayerKind::Texture { side_length } => {
+                // Four tiles next to each other make an "infinite" background.
+                (-1..3).map(|i| {
+                    let position = vec2(i as f32 * side_length, 0.0);
+                    Background::new(position, side_length, -2.0 * side_length, 4.0 * side_length, settings.speed)
+                }).collect()
+            },
+            BackgroundLayerKind::Stars { count, min_size, max_size, seed } => {
+                // XorShiftRng seed must not be all zeros.
+                let mut rng = XorShiftRng::from_seed([seed, 0x9e37_79b9, 0x7f4a_7c15, 0x85eb_ca6b]);
+                let half_width = STAR_FIELD_WIDTH / 2.0;
+                let height = SCREEN_TOP_Y_VALUE_IN_WORLD_COORDINATES;
+
+                (0..count).map(|_| {
+                    let position = vec2(rng.gen_range(-half_width, half_width), rng.gen_range(-height, height));
+                    let size = rng.gen_range(min_size, max_size);
+                    Background::new(position, size, -half_width, STAR_FIELD_WIDTH, settings.speed)
+                }).collect()
+            },
+        };
+
+        BackgroundLayer { settings, backgrounds }
+    }
+
+    /// Get backgrounds of the layer.
+    pub fn backgrounds(&self) -> &Vec<Background> {
+        &self.backgrounds
+    }
+
+    /// Returns true if layer is rendered with background texture.
+    pub fn textured(&self) -> bool {
+        match self.settings.kind {
+            BackgroundLayerKind::Texture { .. } => true,
+            BackgroundLayerKind::Stars { .. } => false,
+        }
+    }
+
+    /// Color of layer.
+    pub fn color(&self) -> &Vector4<f32> {
+        &self.settings.color
+    }
+
+    /// Blend mode of layer.
+    pub fn blend_mode(&self) -> BlendMode {
+        self.settings.blend_mode
+    }
+}
+
+/// Parallax background made of multiple layers.
+pub struct MovingBackground {
+    layers: Vec<BackgroundLayer>,
+}
+
+impl MovingBackground {
+    /// Create new `MovingBackground` with the first level's layers.
+    pub fn new() -> MovingBackground {
+        let mut background = MovingBackground { layers: Vec::new() };
+        background.set_level(0);
+        background
+    }
+
+    /// Replace current layers with layers of argument `level`.
+    pub fn set_level(&mut self, level: u32) {
+        self.layers = level_background(level).into_iter().map(BackgroundLayer::new).collect();
+    }
+
+    /// Updates all layers.
+    pub fn update(&mut self, current_time: &GameTimeManager) {
+        for layer in &mut self.layers {
+            for background in &mut layer.backgrounds {
+                background.update(current_time);
+            }
+        }
+    }
+
+    /// Get layers from the farthest layer to the nearest layer.
+    pub fn layers(&self) -> &Vec<BackgroundLayer> {
+        &self.layers
+    }
+
+    /// Store current transforms of backgrounds.
+    pub fn store_previous_transforms(&mut self) {
+        for layer in &mut self.layers {
+            for background in &mut layer.backgrounds {
+                background.data.store_previous_transform();
+            }
+        }
+    }
+
+    /// Moves every background's x coordinate.
+    pub fn move_position_x(&mut self, x: f32) {
+        for layer in &mut self.layers {
+            for background in &mut layer.backgrounds {
+                background.move_position(x, 0.0);
+            }
+        }
+    }
+}
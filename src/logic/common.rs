@@ -14,6 +14,8 @@ MIT License
 
 //! Basic functionality for game logic.
 
+use std::f32::consts;
+
 use cgmath::prelude::*;
 use cgmath::{Vector4, Matrix4, Rad, Vector2, BaseFloat, Point2, MetricSpace};
 
@@ -204,6 +206,10 @@ pub struct Data<T: BaseFloat> {
     /// Model matrix for rendering. Updating model matrix
     /// is required if there is visual position or rotation changes to game object.
     pub model_matrix: Matrix4<T>,
+    /// Position and rotation from the previous logic update. Renderer interpolates
+    /// between these and current position and rotation. If this is `None`, there
+    /// will be no interpolation.
+    pub previous_transform: Option<(Vector2<T>, T)>,
     pub position: Vector2<T>,
     /// Default direction is x unit vector.
     pub direction: Vector2<T>,
@@ -226,6 +232,7 @@ impl Data<f32> {
 
         let mut data = Data {
             model_matrix: Matrix4::identity(),
+            previous_transform: None,
             position,
             direction: Vector2::unit_x(),
             width,
@@ -245,6 +252,20 @@ impl Data<f32> {
         Data::new(position, side_length, side_length)
     }
 
+    /// Change width and height. Model matrix and collision
+    /// detection radiuses will be updated.
+    pub fn set_size(&mut self, width: f32, height: f32) {
+        let x = width/2.0;
+        let y = height/2.0;
+
+        self.width = width;
+        self.height = height;
+        self.radius_outer = f32::sqrt(x*x+y*y);
+        self.radius_inner = f32::min(width, height)/2.0;
+
+        self.update_rotation(true);
+    }
+
     /// Calculates new direction vector for game object.
     ///
     /// Model matrix will be updated if update_model_matrix argument is true.
@@ -265,6 +286,59 @@ impl Data<f32> {
         self.model_matrix.w.x = self.position.x;
         self.model_matrix.w.y = self.position.y;
     }
+
+    /// Store current position and rotation as previous transform. Call
+    /// this before every logic update.
+    pub fn store_previous_transform(&mut self) {
+        self.previous_transform = Some((self.position, self.rotation));
+    }
+
+    /// Disable interpolation until the next logic update. Use this
+    /// when game object jumps to a new position.
+    pub fn skip_interpolation(&mut self) {
+        self.previous_transform = None;
+    }
+
+    /// Model matrix built from position and rotation interpolated between
+    /// previous transform and current position and rotation.
+    ///
+    /// Returns current model matrix if there is no previous transform or
+    /// if `interpolation_factor` is 1.0 or greater.
+    pub fn interpolated_model_matrix(&self, interpolation_factor: f32) -> Matrix4<f32> {
+        let (previous_position, previous_rotation) = match self.previous_transform {
+            Some(transform) if interpolation_factor < 1.0 => transform,
+            _ => return self.model_matrix,
+        };
+
+        let factor = interpolation_factor.max(0.0);
+        let position = previous_position.lerp(self.position, factor);
+
+        let mut model_matrix = if previous_rotation == self.rotation {
+            self.model_matrix
+        } else {
+            let rotation = previous_rotation + shortest_angle_difference(previous_rotation, self.rotation) * factor;
+            Matrix4::from_angle_z(Rad(rotation)) * Matrix4::from_nonuniform_scale(self.width, self.height, 1.0)
+        };
+
+        model_matrix.w.x = position.x;
+        model_matrix.w.y = position.y;
+
+        model_matrix
+    }
+}
+
+/// Signed angle from angle `from` to angle `to` which
+/// is at range [-PI, PI]. Angles are in radians.
+fn shortest_angle_difference(from: f32, to: f32) -> f32 {
+    let difference = (to - from) % (consts::PI * 2.0);
+
+    if difference > consts::PI {
+        difference - consts::PI * 2.0
+    } else if difference < -consts::PI {
+        difference + consts::PI * 2.0
+    } else {
+        difference
+    }
 }
 
 /// Trait for nicer game object container updates.
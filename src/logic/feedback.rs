@@ -0,0 +1,200 @@
+/*
+src/logic/feedback.rs, 2017-09-24
+
+Copyright (c) 2017 Juuso Tuononen
+
+This file is licensed under
+
+Apache License, Version 2.0
+
+or
+
+MIT License
+*/
+
+//! Visual feedback for hits: screen shake, hit flash and hit-stop.
+
+use cgmath::Vector2;
+
+use utils::{Timer, GameTimeManager};
+
+const CAMERA_SHAKE_TIME_MILLISECONDS: u32 = 400;
+/// Max camera offset in world coordinates.
+const CAMERA_SHAKE_MAX_OFFSET: f32 = 0.15;
+
+const HIT_FLASH_TIME_MILLISECONDS: u32 = 120;
+
+/// Shake camera by moving it to different directions
+/// with decreasing distance.
+pub struct CameraShake {
+    timer: Timer,
+    active: bool,
+    /// Accessibility setting.
+    enabled: bool,
+    offset: Vector2<f32>,
+}
+
+impl CameraShake {
+    /// Create new `CameraShake`.
+    pub fn new() -> CameraShake {
+        CameraShake {
+            timer: Timer::new(),
+            active: false,
+            enabled: true,
+            offset: Vector2::new(0.0, 0.0),
+        }
+    }
+
+    /// Start shaking. Shaking restarts if it is already active.
+    pub fn start(&mut self, current_time: &GameTimeManager) {
+        if !self.enabled {
+            return;
+        }
+
+        self.timer.reset(current_time.time());
+        self.active = true;
+    }
+
+    /// Update camera offset.
+    pub fn update(&mut self, current_time: &GameTimeManager) {
+        if !self.active {
+            return;
+        }
+
+        let milliseconds = self.timer.milliseconds(current_time.time());
+
+        if milliseconds >= CAMERA_SHAKE_TIME_MILLISECONDS {
+            self.reset();
+            return;
+        }
+
+        let strength = 1.0 - milliseconds as f32 / CAMERA_SHAKE_TIME_MILLISECONDS as f32;
+        let t = milliseconds as f32;
+
+        // Different frequencies for x and y, so camera
+        // doesn't move only on one line.
+        let x = (t * 0.09).sin();
+        let y = (t * 0.071 + 1.0).cos();
+
+        self.offset = Vector2::new(x, y) * CAMERA_SHAKE_MAX_OFFSET * strength;
+    }
+
+    /// Stop shaking.
+    pub fn reset(&mut self) {
+        self.active = false;
+        self.offset = Vector2::new(0.0, 0.0);
+    }
+
+    /// Enable or disable camera shake.
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+
+        if !enabled {
+            self.reset();
+        }
+    }
+
+    /// Current camera offset in world coordinates.
+    pub fn offset(&self) -> Vector2<f32> {
+        self.offset
+    }
+}
+
+/// Brief white flash on damaged game object.
+pub struct HitFlash {
+    timer: Timer,
+    /// Damage occurred after last update.
+    damaged: bool,
+    active: bool,
+    amount: f32,
+}
+
+impl HitFlash {
+    /// Create new `HitFlash`.
+    pub fn new() -> HitFlash {
+        HitFlash {
+            timer: Timer::new(),
+            damaged: false,
+            active: false,
+            amount: 0.0,
+        }
+    }
+
+    /// Start flash when method `update` is called next time.
+    pub fn start(&mut self) {
+        self.damaged = true;
+    }
+
+    /// Update flash amount.
+    pub fn update(&mut self, current_time: &GameTimeManager) {
+        if self.damaged {
+            self.damaged = false;
+            self.active = true;
+            self.timer.reset(current_time.time());
+        }
+
+        if !self.active {
+            return;
+        }
+
+        let milliseconds = self.timer.milliseconds(current_time.time());
+
+        if milliseconds >= HIT_FLASH_TIME_MILLISECONDS {
+            self.reset();
+        } else {
+            self.amount = 1.0 - milliseconds as f32 / HIT_FLASH_TIME_MILLISECONDS as f32;
+        }
+    }
+
+    /// Stop flash.
+    pub fn reset(&mut self) {
+        self.damaged = false;
+        self.active = false;
+        self.amount = 0.0;
+    }
+
+    /// How much game object's sprite is changed to white. Value
+    /// is at range [0.0, 1.0].
+    pub fn amount(&self) -> f32 {
+        self.amount
+    }
+}
+
+/// Freeze game for a moment.
+pub struct HitStop {
+    timer: Timer,
+    active: bool,
+    milliseconds: u32,
+}
+
+impl HitStop {
+    /// Create new `HitStop`.
+    pub fn new() -> HitStop {
+        HitStop {
+            timer: Timer::new(),
+            active: false,
+            milliseconds: 0,
+        }
+    }
+
+    /// Start hit-stop lasting argument `milliseconds`.
+    pub fn start(&mut self, milliseconds: u32, current_time: &GameTimeManager) {
+        self.timer.reset(current_time.time());
+        self.milliseconds = milliseconds;
+        self.active = true;
+    }
+
+    /// Returns true if game should stay frozen.
+    pub fn update(&mut self, current_time: &GameTimeManager) -> bool {
+        if self.active && self.timer.milliseconds(current_time.time()) >= self.milliseconds {
+            self.active = false;
+        }
+
+        self.active
+    }
+
+    /// Stop hit-stop.
+    pub fn reset(&mut self) {
+        self.active = false;
+    }
+}
@@ -15,6 +15,10 @@ MIT License
 //! Game logic.
 
 pub mod common;
+pub mod animation;
+pub mod particle;
+pub mod feedback;
+pub mod background;
 
 use std::f32::consts;
 use std::convert::From;
@@ -22,10 +26,11 @@ use std::convert::From;
 use cgmath::{Matrix4, Vector2, vec2};
 use cgmath::prelude::*;
 
-use rand::{Rng, ThreadRng};
-use rand;
-
 use logic::common::*;
+use logic::animation::{AnimationPlayer, Animations};
+use logic::particle::{ParticleSystem, Emitters};
+use logic::feedback::{CameraShake, HitFlash, HitStop};
+use logic::background::MovingBackground;
 
 use input::Input;
 
@@ -37,9 +42,6 @@ use renderer::{ModelMatrix, SCREEN_TOP_Y_VALUE_IN_WORLD_COORDINATES};
 
 use audio::{SoundEffectPlayer};
 
-const BACKGROUND_MOVING_SPEED: f32 = -0.02;
-const BACKGROUND_SQUARE_SIDE_LENGTH: f32 = 9.0;
-
 const PLAYER_MOVEMENT_SPEED: f32 = 0.05;
 const PLAYER_SQUARE_SIDE_LENGTH: f32 = 1.0;
 const PLAYER_SQUARE_SIDE_LENGTH_HALF: f32 = PLAYER_SQUARE_SIDE_LENGTH/2.0;
@@ -47,15 +49,11 @@ const PLAYER_STARTING_POSITION: Vector2<f32> = Vector2 { x: -3.0, y: 0.0 };
 pub const PLAYER_MAX_HEALTH: i32 = 100;
 const PLAYER_MILLISECONDS_BETWEEN_LASERS: u32 = 300;
 
-const LAST_LEVEL_INDEX: u32 = 3;
+pub const LAST_LEVEL_INDEX: u32 = 3;
 
-const PARTICLE_SQUARE_SIDE_LENGTH: f32 = 0.1;
-const EXPLOSION_PARTICLE_COUNT: u32 = 15;
 const EXPLOSION_MILLISECONDS_BETWEEN_PARTICLE_CREATION: u32 = 500;
 const EXPLOSION_VISIBILITY_TIME_MILLISECONDS: u32 = 2000;
 
-const FULL_CIRCLE_ANGLE_IN_RADIANS: f32 = consts::PI*2.0;
-
 const LASER_SPEED: f32 = 0.08;
 
 const ENEMY_MOVEMENT_SPEED: f32 = 0.04;
@@ -72,6 +70,9 @@ const LASER_BOMB_EXPLOSION_TIME_MILLISECONDS: u32 = 900;
 
 const LASER_CANNON_DISTANCE_FROM_ENEMY: f32 = 3.0;
 
+/// Game freezes for this time when enemy loses its shield.
+const SHIELD_LOST_HIT_STOP_MILLISECONDS: u32 = 80;
+
 const GUI_MARGIN_TOP: f32 = 1.0;
 
 /// Macro for implementing basic game object traits.
@@ -83,6 +84,10 @@ macro_rules! impl_traits {
             fn model_matrix(&self) -> &Matrix4<f32> {
                 &self.data().model_matrix
             }
+
+            fn interpolated_model_matrix(&self, interpolation_factor: f32) -> Matrix4<f32> {
+                self.data().interpolated_model_matrix(interpolation_factor)
+            }
         }
 
         impl GameObjectData<f32> for $x {
@@ -170,6 +175,17 @@ impl LogicSettings {
     }
 }
 
+/// Game state information for the debug overlay.
+pub struct DebugInfo<'a> {
+    /// Game objects which collision circles will be drawn.
+    pub objects: Vec<&'a Data<f32>>,
+    /// Movement areas of player, enemy and lasers.
+    pub areas: Vec<Rectangle>,
+    pub object_counts: Vec<(&'static str, usize)>,
+    /// Elapsed milliseconds of timers.
+    pub timers: Vec<(&'static str, u32)>,
+}
+
 /// Logic stores current state of game logic.
 pub struct Logic {
     player: Player,
@@ -180,6 +196,9 @@ pub struct Logic {
     current_difficulty: Difficulty,
     game_running: bool,
     explosion: Explosion,
+    particle_system: ParticleSystem,
+    camera_shake: CameraShake,
+    hit_stop: HitStop,
     index_buffer: Vec<usize>,
 }
 
@@ -194,7 +213,10 @@ impl Logic {
             level: 0,
             current_difficulty: Difficulty::Normal,
             game_running: true,
-            explosion: Explosion::new(EXPLOSION_PARTICLE_COUNT, EXPLOSION_MILLISECONDS_BETWEEN_PARTICLE_CREATION),
+            explosion: Explosion::new(EXPLOSION_MILLISECONDS_BETWEEN_PARTICLE_CREATION),
+            particle_system: ParticleSystem::new(),
+            camera_shake: CameraShake::new(),
+            hit_stop: HitStop::new(),
             index_buffer: Vec::with_capacity(25),
         };
 
@@ -207,17 +229,51 @@ impl Logic {
     /// Updates game logic.
     pub fn update<T: Input, P: SoundEffectPlayer>(&mut self, input: &T, gui: &mut GUI, sound_effect_manager: &mut P, current_time: &GameTimeManager) {
 
+        // Game is frozen during hit-stop.
+
+        if self.hit_stop.update(current_time) {
+            return;
+        }
+
         // Basic game updating.
 
         if self.game_running {
-            self.player.update(input, &mut self.enemy, &self.logic_settings, sound_effect_manager, &mut self.index_buffer, current_time);
-            self.enemy.update(&mut self.player, &self.logic_settings, sound_effect_manager, &mut self.index_buffer, current_time);
+            self.player.update(input, &mut self.enemy, &self.logic_settings, sound_effect_manager, &mut self.particle_system, &mut self.index_buffer, current_time);
+            self.enemy.update(&mut self.player, &self.logic_settings, sound_effect_manager, &mut self.particle_system, &mut self.index_buffer, current_time);
             self.moving_background.update(current_time);
         }
 
+        // Hit feedback.
+
+        if self.enemy.laser_bomb_exploded {
+            self.enemy.laser_bomb_exploded = false;
+            self.camera_shake.start(current_time);
+        }
+
+        if self.enemy.shield_lost {
+            self.enemy.shield_lost = false;
+            self.hit_stop.start(SHIELD_LOST_HIT_STOP_MILLISECONDS, current_time);
+        }
+
+        self.camera_shake.update(current_time);
+        self.player.hit_flash.update(current_time);
+        self.enemy.hit_flash.update(current_time);
+
+        // Engine trails.
+
+        let player_trail_position = vec2(self.player.x() - self.player.data.width * 0.5, self.player.y());
+        let player_trail_enabled = self.game_running && self.player.visible;
+        self.particle_system.set_continuous_emission(Emitters::PlayerEngineTrail, player_trail_position, consts::PI, player_trail_enabled, current_time);
+
+        let enemy_trail_position = vec2(self.enemy.x() + self.enemy.data.width * 0.95, self.enemy.y());
+        let enemy_trail_enabled = self.game_running && self.enemy.visible;
+        self.particle_system.set_continuous_emission(Emitters::EnemyEngineTrail, enemy_trail_position, 0.0, enemy_trail_enabled, current_time);
+
+        self.particle_system.update(&mut self.index_buffer, current_time);
+
         // Handle game ending and health updates to GUI.
 
-        self.explosion.update(sound_effect_manager, &mut self.index_buffer, current_time);
+        self.explosion.update(sound_effect_manager, &mut self.particle_system, current_time);
 
         if let Some(health) = self.player.health() {
             gui.get_game_status().set_player_health(health);
@@ -261,6 +317,68 @@ impl Logic {
         }
     }
 
+    /// Store current transforms of all game objects for
+    /// render interpolation. Call this before every logic update.
+    pub fn store_previous_transforms(&mut self) {
+        self.player.store_previous_transforms();
+        self.enemy.store_previous_transforms();
+        self.explosion.store_previous_transforms();
+        self.particle_system.store_previous_transforms();
+        self.moving_background.store_previous_transforms();
+    }
+
+    /// Collect game state information for the debug overlay.
+    pub fn debug_info<'a>(&'a self, current_time: &GameTimeManager) -> DebugInfo<'a> {
+        let player = &self.player;
+        let enemy = &self.enemy;
+        let time = current_time.time();
+
+        let mut objects = vec![player.data(), enemy.data()];
+
+        for cannon in vec![&enemy.laser_cannon_top, &enemy.laser_cannon_bottom] {
+            if cannon.visible() {
+                objects.push(cannon.data());
+            }
+        }
+
+        if enemy.shield.visible() {
+            objects.push(enemy.shield.data());
+        }
+
+        objects.extend(player.lasers.iter().map(|laser| laser.data()));
+        objects.extend(enemy.lasers.iter().map(|laser| laser.data()));
+        objects.extend(enemy.laser_bombs.iter().map(|bomb| bomb.laser.data()));
+
+        let areas = vec![
+            Player::movement_area(&self.logic_settings),
+            enemy.movement_area(&self.logic_settings),
+            Laser::allowed_area(&self.logic_settings),
+        ];
+
+        let object_counts = vec![
+            ("Player lasers", player.lasers.len()),
+            ("Enemy lasers", enemy.lasers.len()),
+            ("Laser bombs", enemy.laser_bombs.len()),
+            ("Particles", self.particle_system.particle_count()),
+        ];
+
+        let mut timers = vec![
+            ("Player laser", player.laser_timer.milliseconds(time)),
+            ("Enemy laser", enemy.laser_timer.milliseconds(time)),
+            ("Laser bomb", enemy.laser_bomb_timer.milliseconds(time)),
+        ];
+
+        if enemy.shield.visible() {
+            timers.push(("Shield", enemy.shield.timer.milliseconds(time)));
+        }
+
+        if self.explosion.visible() {
+            timers.push(("Explosion", self.explosion.timer.milliseconds(time)));
+        }
+
+        DebugInfo { objects, areas, object_counts, timers }
+    }
+
     /// Get player.
     pub fn get_player(&self) -> &Player {
         &self.player
@@ -276,6 +394,11 @@ impl Logic {
         &self.explosion
     }
 
+    /// Get particle system.
+    pub fn get_particle_system(&self) -> &ParticleSystem {
+        &self.particle_system
+    }
+
     /// Get background.
     pub fn get_moving_background(&self) -> &MovingBackground {
         &self.moving_background
@@ -311,7 +434,19 @@ impl Logic {
             gui.get_game_status().set_enemy_health(health);
         }
 
+        self.moving_background.set_level(level);
         self.explosion.reset();
+        self.particle_system.reset();
+        self.camera_shake.reset();
+        self.hit_stop.reset();
+
+        // Objects may have moved to new positions.
+        self.store_previous_transforms();
+    }
+
+    /// Set seed for random number generators.
+    pub fn set_random_seed(&mut self, seed: u64) {
+        self.particle_system.set_random_seed(seed);
     }
 
     /// Change to next level and reset game.
@@ -321,69 +456,85 @@ impl Logic {
         self.reset_game(gui, difficulty, level, current_time);
     }
 
+    /// Camera offset from screen shake in world coordinates.
+    pub fn camera_offset(&self) -> Vector2<f32> {
+        self.camera_shake.offset()
+    }
+
+    /// Enable or disable screen shake.
+    pub fn set_screen_shake(&mut self, enabled: bool) {
+        self.camera_shake.set_enabled(enabled);
+    }
+
     /// Updates game world width.
     pub fn update_half_screen_width(&mut self, half_width: f32) {
         self.logic_settings.screen_width_half = half_width;
     }
-}
 
-/// Explosion particle.
-pub struct Particle {
-    data: Data<f32>,
-    speed: f32,
-    lifetime_timer: Timer,
-    lifetime_as_milliseconds: u32,
-}
+    /// Current level index.
+    pub fn level(&self) -> u32 {
+        self.level
+    }
 
-impl Particle {
-    /// Create new `Particle`.
-    fn new(current_time: &GameTimeManager, position: Vector2<f32>, angle: f32, speed: f32, lifetime_as_milliseconds: u32) -> Particle {
-        let mut particle = Particle {
-            data: Data::new_square(position, PARTICLE_SQUARE_SIDE_LENGTH),
-            speed,
-            lifetime_timer: Timer::new_from_time(current_time.time()),
-            lifetime_as_milliseconds,
-        };
-        particle.turn_without_updating_model_matrix(angle);
+    /// Current difficulty.
+    pub fn difficulty(&self) -> Difficulty {
+        self.current_difficulty
+    }
 
-        particle
+    /// Is god mode enabled.
+    pub fn god_mode(&self) -> bool {
+        self.player.invulnerable
     }
 
-    /// Updates particle and returns true if particle can be destroyed.
-    fn update(&mut self, current_time: &GameTimeManager) -> bool {
-        let speed = self.speed;
-        self.forward(speed * current_time.delta_time());
+    /// Enable or disable god mode. Player doesn't take damage in god mode.
+    pub fn set_god_mode(&mut self, enabled: bool) {
+        self.player.invulnerable = enabled;
+    }
 
-        self.lifetime_timer.check(current_time.time(), self.lifetime_as_milliseconds)
+    /// Set player's health. Health will be clamped to `PLAYER_MAX_HEALTH`.
+    pub fn set_player_health(&mut self, health: u32) {
+        self.player.health = (health as i32).min(PLAYER_MAX_HEALTH);
+        self.player.health_update = true;
     }
-}
 
-impl_traits!(Particle);
+    /// Set enemy's health. Health will be clamped to `ENEMY_MAX_HEALTH`.
+    pub fn set_enemy_health(&mut self, health: u32) {
+        self.enemy.health = (health as i32).min(ENEMY_MAX_HEALTH);
+        self.enemy.health_update = true;
+    }
+
+    /// Make enemy shoot a laser bomb. Returns false if game has ended.
+    pub fn spawn_laser_bomb(&mut self, current_time: &GameTimeManager) -> bool {
+        if !self.game_running {
+            return false;
+        }
 
+        self.enemy.create_laser_bomb(current_time);
+
+        true
+    }
+}
 
-/// Explosion manages particles and creates them.
+/// Explosion animation. Explosion's game object data
+/// is the area of explosion animation.
 pub struct Explosion {
-    position: Vector2<f32>,
+    data: Data<f32>,
+    animation: AnimationPlayer,
     visible: bool,
     timer: Timer,
-    particles: Vec<Particle>,
     particle_creation_timer: Timer,
-    rng: ThreadRng,
-    particle_count: u32,
     milliseconds_between_particle_generation: u32,
 }
 
 impl Explosion {
     /// Create new `Explosion`.
-    fn new(particle_count: u32, milliseconds_between_particle_generation: u32) -> Explosion {
+    fn new(milliseconds_between_particle_generation: u32) -> Explosion {
         Explosion {
-            position: Vector2::zero(),
+            data: Data::new_square(Vector2::zero(), 0.0),
+            animation: AnimationPlayer::new(Animations::Explosion),
             visible: false,
             timer: Timer::new(),
-            particles: Vec::with_capacity(25),
             particle_creation_timer: Timer::new(),
-            rng: rand::thread_rng(),
-            particle_count,
             milliseconds_between_particle_generation,
         }
     }
@@ -391,9 +542,10 @@ impl Explosion {
     /// Moves explosion to location of argument game object, and starts explosion.
     pub fn start_explosion<T: GameObject>(&mut self, object: &T, current_time: &GameTimeManager) {
         self.timer.reset(current_time.time());
-        self.position = *object.position();
+        let size = object.data().width.max(object.data().height) * 1.5;
+        self.data = Data::new_square(*object.position(), size);
+        self.animation.restart(current_time);
         self.visible = true;
-        self.particles.clear();
     }
 
     /// Return true if explosion is finished.
@@ -406,21 +558,17 @@ impl Explosion {
         }
     }
 
-    /// If explosion is visible, update current particles and create new particles if its time to create particles.
-    pub fn update<P: SoundEffectPlayer>(&mut self, sounds: &mut P, index_buffer: &mut Vec<usize>, current_time: &GameTimeManager) {
+    /// If explosion is visible, update animation and create spark particles if its time to create particles.
+    pub fn update<P: SoundEffectPlayer>(&mut self, sounds: &mut P, particles: &mut ParticleSystem, current_time: &GameTimeManager) {
         if !self.visible {
             return;
         }
 
-        self.particles.update(index_buffer, &mut | particle | {
-            particle.update(current_time)
-        });
+        self.animation.update(current_time);
 
         if self.particle_creation_timer.check(current_time.time(), self.milliseconds_between_particle_generation) {
             sounds.explosion();
-            for _ in 0..self.particle_count {
-                self.particles.push(Particle::new(current_time, self.position, FULL_CIRCLE_ANGLE_IN_RADIANS * self.rng.gen::<f32>(), (self.rng.gen::<f32>()*0.02).max(0.01), self.rng.gen::<u32>()%400+500));
-            }
+            particles.emit(Emitters::ExplosionSparks, self.data.position, 0.0, current_time);
         }
     }
 
@@ -434,12 +582,18 @@ impl Explosion {
         self.visible
     }
 
-    /// Get current particles.
-    pub fn particles(&self) -> &Vec<Particle> {
-        &self.particles
+    /// Get explosion animation.
+    pub fn animation(&self) -> &AnimationPlayer {
+        &self.animation
+    }
+
+    /// Store current transform of explosion.
+    fn store_previous_transforms(&mut self) {
+        self.data.store_previous_transform();
     }
 }
 
+impl_traits!(Explosion);
 
 /// Player game object and logic.
 pub struct Player {
@@ -451,6 +605,9 @@ pub struct Player {
     health_update: bool,
     visible: bool,
     enemy_hit_damage_timer: Timer,
+    hit_flash: HitFlash,
+    /// Player doesn't take damage. Resetting the player doesn't change this.
+    invulnerable: bool,
 }
 
 impl Player {
@@ -465,6 +622,8 @@ impl Player {
             health_update: true,
             visible: true,
             enemy_hit_damage_timer: Timer::new(),
+            hit_flash: HitFlash::new(),
+            invulnerable: false,
         }
     }
 
@@ -476,6 +635,14 @@ impl Player {
         self.health_update = true;
         self.laser_timer.reset(current_time.time());
         self.visible = true;
+        self.hit_flash.reset();
+    }
+
+    /// Area where player can move.
+    fn movement_area(logic_settings: &LogicSettings) -> Rectangle {
+        let width = logic_settings.screen_width_half - PLAYER_SQUARE_SIDE_LENGTH_HALF;
+        let height = SCREEN_TOP_Y_VALUE_IN_WORLD_COORDINATES - PLAYER_SQUARE_SIDE_LENGTH_HALF;
+        Rectangle::new(-width, width, -height, height - GUI_MARGIN_TOP)
     }
 
     /// Updates player logic.
@@ -484,6 +651,7 @@ impl Player {
             enemy: &mut Enemy,
             logic_settings: &LogicSettings,
             sounds: &mut P,
+            particles: &mut ParticleSystem,
             index_buffer: &mut Vec<usize>,
             current_time: &GameTimeManager) {
         // Move player.
@@ -508,9 +676,7 @@ impl Player {
 
         // Keep player on the screen.
 
-        let width = logic_settings.screen_width_half - PLAYER_SQUARE_SIDE_LENGTH_HALF;
-        let height = SCREEN_TOP_Y_VALUE_IN_WORLD_COORDINATES - PLAYER_SQUARE_SIDE_LENGTH_HALF;
-        let area = Rectangle::new(-width, width, -height, height - GUI_MARGIN_TOP);
+        let area = Player::movement_area(logic_settings);
         self.stay_at_area(&area);
 
         // Create new laser if player shoots.
@@ -524,7 +690,7 @@ impl Player {
 
         // Update player lasers.
 
-        self.clean_and_update_lasers(enemy, logic_settings, sounds, index_buffer, current_time);
+        self.clean_and_update_lasers(enemy, logic_settings, sounds, particles, index_buffer, current_time);
 
         // Check if there is collision between player and enemy.
 
@@ -548,10 +714,20 @@ impl Player {
         &self.lasers
     }
 
+    /// Store current transforms of player and player's lasers.
+    fn store_previous_transforms(&mut self) {
+        self.data.store_previous_transform();
+
+        for laser in &mut self.lasers {
+            laser.data.store_previous_transform();
+        }
+    }
+
     fn clean_and_update_lasers<P: SoundEffectPlayer>(&mut self,
             enemy: &mut Enemy,
             logic_settings: &LogicSettings,
             sounds: &mut P,
+            particles: &mut ParticleSystem,
             index_buffer: &mut Vec<usize>,
             current_time: &GameTimeManager) {
         self.lasers.update(index_buffer, &mut |laser| {
@@ -565,21 +741,25 @@ impl Player {
 
             if let EnemyType::Shield = enemy.enemy_type {
                 if enemy.shield.visible && enemy.shield.circle_collision(laser) {
+                    laser.create_impact_particles(Emitters::ShieldHit, particles, current_time);
                     true
                 } else if enemy.laser_cannon_bottom.circle_collision(laser) {
                     if enemy.laser_cannon_bottom.parent_object_shield_enabled {
                         sounds.player_laser_hits_laser_cannon();
                     }
                     enemy.laser_cannon_bottom.parent_object_shield_enabled = false;
+                    laser.create_impact_particles(Emitters::LaserImpact, particles, current_time);
                     true
                 } else if enemy.laser_cannon_top.circle_collision(laser) {
                     if enemy.laser_cannon_top.parent_object_shield_enabled {
                         sounds.player_laser_hits_laser_cannon();
                     }
                     enemy.laser_cannon_top.parent_object_shield_enabled = false;
+                    laser.create_impact_particles(Emitters::LaserImpact, particles, current_time);
                     true
                 } else if !enemy.shield.visible && enemy.circle_collision(laser)  {
                     enemy.update_health(-logic_settings.player_laser_damage);
+                    laser.create_impact_particles(Emitters::LaserImpact, particles, current_time);
                     true
                 } else {
                     false
@@ -587,6 +767,7 @@ impl Player {
             } else {
                 if enemy.circle_collision(laser) {
                     enemy.update_health(-logic_settings.player_laser_damage);
+                    laser.create_impact_particles(Emitters::LaserImpact, particles, current_time);
                     return true
                 } else {
                     false
@@ -597,13 +778,23 @@ impl Player {
 
     /// Adds argument amount to player health. This function will keep health greater or equal to zero.
     /// Note that there is no overflow checking.
+    ///
+    /// Damage is ignored if player is invulnerable.
     pub fn update_health(&mut self, amount: i32) {
+        if amount < 0 && self.invulnerable {
+            return;
+        }
+
         self.health += amount;
 
         if self.health < 0 {
             self.health = 0;
         }
 
+        if amount < 0 {
+            self.hit_flash.start();
+        }
+
         self.health_update = true;
     }
 
@@ -621,6 +812,11 @@ impl Player {
     pub fn visible(&self) -> bool {
         self.visible
     }
+
+    /// Amount of white flash after player is damaged.
+    pub fn hit_flash(&self) -> f32 {
+        self.hit_flash.amount()
+    }
 }
 
 impl_traits!(Player);
@@ -656,14 +852,19 @@ impl Laser {
         }
     }
 
+    /// Area where lasers are not destroyed.
+    fn allowed_area(logic_settings: &LogicSettings) -> Rectangle {
+        let width = logic_settings.screen_width_half + 1.0;
+        let height = 5.5;
+        Rectangle::new(-width, width, -height, height)
+    }
+
     /// Move laser forward and set laser to be destroyed if laser is not on the screen.
     fn update(&mut self, logic_settings: &LogicSettings, current_time: &GameTimeManager) {
         let speed = self.speed * current_time.delta_time();
         self.forward(speed);
 
-        let width = logic_settings.screen_width_half + 1.0;
-        let height = 5.5;
-        let area = Rectangle::new(-width, width, -height, height);
+        let area = Laser::allowed_area(logic_settings);
 
         if self.outside_allowed_area(&area) {
             self.destroy = true;
@@ -674,6 +875,12 @@ impl Laser {
     pub fn color(&self) -> LaserColor {
         self.color
     }
+
+    /// Create particle burst at laser's position. Particles
+    /// move to the opposite direction of the laser.
+    fn create_impact_particles(&self, emitter: Emitters, particles: &mut ParticleSystem, current_time: &GameTimeManager) {
+        particles.emit(emitter, self.data.position, self.data.rotation + consts::PI, current_time);
+    }
 }
 
 impl CanDestroy for Laser {
@@ -704,6 +911,12 @@ pub struct Enemy {
     laser_bomb_enabled: bool,
     shield: Shield,
     laser_x_position_margin: f32,
+    thruster_animation: AnimationPlayer,
+    hit_flash: HitFlash,
+    /// Some laser bomb exploded during the last update.
+    laser_bomb_exploded: bool,
+    /// Shield was disabled during the last update.
+    shield_lost: bool,
 }
 
 impl Enemy {
@@ -726,6 +939,10 @@ impl Enemy {
             laser_bomb_enabled: true,
             shield: Shield::new(Vector2::zero()),
             laser_x_position_margin: 0.0,
+            thruster_animation: AnimationPlayer::new(Animations::EnemyThruster),
+            hit_flash: HitFlash::new(),
+            laser_bomb_exploded: false,
+            shield_lost: false,
         }
     }
 
@@ -738,6 +955,10 @@ impl Enemy {
         self.laser_bomb_timer.reset(current_time.time());
         self.laser_timer.reset(current_time.time());
         self.visible = true;
+        self.thruster_animation.restart(current_time);
+        self.hit_flash.reset();
+        self.laser_bomb_exploded = false;
+        self.shield_lost = false;
 
         if level == 0 || level == 2 {
             self.enemy_type = EnemyType::Normal;
@@ -766,13 +987,28 @@ impl Enemy {
         self.shield.reset(self.data.position, self.enemy_type);
     }
 
+    /// Area where enemy moves. Enemy with shield has smaller area.
+    fn movement_area(&self, logic_settings: &LogicSettings) -> Rectangle {
+        let width = logic_settings.screen_width_half - ENEMY_SQUARE_SIDE_LENGTH_HALF;
+        let height = if let EnemyType::Shield = self.enemy_type {
+            1.0
+        } else {
+            4.0
+        };
+
+        Rectangle::new(-width, width, -height, height - GUI_MARGIN_TOP)
+    }
+
     /// Update enemy logic.
     fn update<P: SoundEffectPlayer>(&mut self,
             player: &mut Player,
             logic_settings: &LogicSettings,
             sounds: &mut P,
+            particles: &mut ParticleSystem,
             index_buffer: &mut Vec<usize>,
             current_time: &GameTimeManager) {
+        self.thruster_animation.update(current_time);
+
         // Enemy movement.
         let speed = self.speed;
 
@@ -780,14 +1016,7 @@ impl Enemy {
 
         // Change enemy movement direction if enemy hits its movement borders.
 
-        let width = logic_settings.screen_width_half - ENEMY_SQUARE_SIDE_LENGTH_HALF;
-        let height = if let EnemyType::Shield = self.enemy_type {
-            1.0
-        } else {
-            4.0
-        };
-
-        let area = Rectangle::new(-width, width, -height, height - GUI_MARGIN_TOP);
+        let area = self.movement_area(logic_settings);
 
         if self.stay_at_area(&area) {
             self.speed *= -1.0;
@@ -820,6 +1049,7 @@ impl Enemy {
                 true
             } else if player.circle_collision(laser) {
                 player.update_health(-logic_settings.enemy_laser_damage);
+                laser.create_impact_particles(Emitters::LaserImpact, particles, current_time);
                 true
             } else {
                 false
@@ -832,13 +1062,18 @@ impl Enemy {
 
             {
                 let lasers = &mut self.lasers;
+                let laser_bomb_exploded = &mut self.laser_bomb_exploded;
                 self.laser_bombs.update(index_buffer, &mut |laser_bomb| {
-                    laser_bomb.update(current_time, logic_settings, lasers, sounds);
+                    if laser_bomb.update(current_time, logic_settings, lasers, sounds, particles) {
+                        *laser_bomb_exploded = true;
+                    }
 
                     if laser_bomb.destroy() {
                         true
                     } else if player.circle_collision(laser_bomb) {
                         player.update_health(-LASER_BOMB_DAMAGE);
+                        particles.emit(Emitters::BombDetonation, laser_bomb.data().position, 0.0, current_time);
+                        *laser_bomb_exploded = true;
                         true
                     } else {
                         false
@@ -879,6 +1114,7 @@ impl Enemy {
 
             if self.shield.visible && !self.laser_cannon_top.parent_object_shield_enabled && !self.laser_cannon_bottom.parent_object_shield_enabled {
                 self.shield.disable(current_time);
+                self.shield_lost = true;
             }
 
             // Enable laser cannon laser shooting depending on current enemy health.
@@ -903,6 +1139,22 @@ impl Enemy {
         &self.lasers
     }
 
+    /// Store current transforms of enemy and all of enemy's game objects.
+    fn store_previous_transforms(&mut self) {
+        self.data.store_previous_transform();
+        self.laser_cannon_top.data.store_previous_transform();
+        self.laser_cannon_bottom.data.store_previous_transform();
+        self.shield.data.store_previous_transform();
+
+        for laser in &mut self.lasers {
+            laser.data.store_previous_transform();
+        }
+
+        for laser_bomb in &mut self.laser_bombs {
+            laser_bomb.laser.data.store_previous_transform();
+        }
+    }
+
     /// Creates new enemy laser. Laser game object will be turned
     /// with value given as argument turn_angle. This value must be in radians.
     fn create_laser(&mut self, turn_angle: f32) {
@@ -943,6 +1195,10 @@ impl Enemy {
             self.health = 0;
         }
 
+        if amount < 0 {
+            self.hit_flash.start();
+        }
+
         self.health_update = true;
     }
 
@@ -962,6 +1218,11 @@ impl Enemy {
         self.visible
     }
 
+    /// Amount of white flash after enemy is damaged.
+    pub fn hit_flash(&self) -> f32 {
+        self.hit_flash.amount()
+    }
+
     /// Get laser cannon positioned at top.
     pub fn get_laser_cannon_top(&self) -> &LaserCannon {
         &self.laser_cannon_top
@@ -981,6 +1242,11 @@ impl Enemy {
     pub fn get_shield(&self) -> &Shield {
         &self.shield
     }
+
+    /// Get enemy's thruster animation.
+    pub fn get_thruster_animation(&self) -> &AnimationPlayer {
+        &self.thruster_animation
+    }
 }
 
 impl_traits!(Enemy);
@@ -991,6 +1257,7 @@ pub struct Shield {
     data: Data<f32>,
     visible: bool,
     timer: Timer,
+    animation: AnimationPlayer,
 }
 
 impl Shield {
@@ -1001,6 +1268,7 @@ impl Shield {
             data: Data::new_square(position, size),
             visible: false,
             timer: Timer::new(),
+            animation: AnimationPlayer::new(Animations::ShieldShimmer),
         }
     }
 
@@ -1020,6 +1288,7 @@ impl Shield {
     /// Return true if shield is enabled during this update method call.
     fn update(&mut self, parent_position_y: f32, current_time: &GameTimeManager) -> bool {
         self.set_position_y(parent_position_y);
+        self.animation.update(current_time);
 
         if !self.visible && self.timer.check(current_time.time(), 10_000) {
             self.visible = true;
@@ -1034,6 +1303,11 @@ impl Shield {
         self.visible
     }
 
+    /// Get shield's shimmer animation.
+    pub fn animation(&self) -> &AnimationPlayer {
+        &self.animation
+    }
+
     /// Disables shield.
     pub fn disable(&mut self, current_time: &GameTimeManager) {
         self.timer.reset(current_time.time());
@@ -1142,11 +1416,14 @@ impl LaserBomb {
 
     /// Updates laser logic and if there is enough time from laser bomb creation,
     /// the laser bomb will explode and create some lasers.
-    fn update<P: SoundEffectPlayer>(&mut self, current_time: &GameTimeManager, logic_settings: &LogicSettings, parent_lasers: &mut Vec<Laser>, sounds: &mut P) {
+    ///
+    /// Returns true if laser bomb exploded.
+    fn update<P: SoundEffectPlayer>(&mut self, current_time: &GameTimeManager, logic_settings: &LogicSettings, parent_lasers: &mut Vec<Laser>, sounds: &mut P, particles: &mut ParticleSystem) -> bool {
         self.laser.update(logic_settings, current_time);
 
         if self.timer.check(current_time.time(), LASER_BOMB_EXPLOSION_TIME_MILLISECONDS) {
             sounds.laser_bomb_explosion();
+            particles.emit(Emitters::BombDetonation, self.laser.data.position, 0.0, current_time);
             let laser_count : u16 = 15;
             let mut angle = 0.0;
             let angle_between_lasers = (consts::PI*2.0) / f32::from(laser_count);
@@ -1161,7 +1438,11 @@ impl LaserBomb {
             }
 
             self.laser.destroy = true;
+
+            return true;
         }
+
+        false
     }
 }
 
@@ -1177,6 +1458,10 @@ impl ModelMatrix for LaserBomb {
     fn model_matrix(&self) -> &Matrix4<f32> {
         &self.data().model_matrix
     }
+
+    fn interpolated_model_matrix(&self, interpolation_factor: f32) -> Matrix4<f32> {
+        self.data().interpolated_model_matrix(interpolation_factor)
+    }
 }
 
 impl GameObjectData<f32> for LaserBomb {
@@ -1187,82 +1472,3 @@ impl GameObjectData<f32> for LaserBomb {
         &mut self.laser.data
     }
 }
-
-/// Background image that moves and resets it's position.
-pub struct Background {
-    data: Data<f32>,
-    x_limit: f32,
-    x_reset_position: f32,
-    speed: f32,
-}
-
-impl Background {
-    /// Create new `Background`.
-    ///
-    /// Background will be positioned at specific index in an "array" of backgrounds.
-    /// Index zero is coordinate (0,0). Coordinate y stays always at zero.
-    /// Backgrounds will not overlap because of the side length argument.
-    ///
-    /// Note that because of the current movement limits, the index must be at bounds of [-1; 2]
-    fn new(i: i32, side_length: f32) -> Background {
-        Background {
-            data: Data::new_square(vec2(i as f32 * side_length, 0.0), side_length),
-            x_limit: -2.0*side_length,
-            x_reset_position: 2.0*side_length,
-            speed: BACKGROUND_MOVING_SPEED,
-        }
-    }
-
-    /// Moves background forward, and resets background position if it's x coordinate.
-    /// goes under the current limit.
-    fn update(&mut self, current_time: &GameTimeManager) {
-        let speed = self.speed;
-        self.move_position(speed*current_time.delta_time(), 0.0);
-
-        if self.x() <= self.x_limit {
-            self.data_mut().position.x = self.x_reset_position;
-        }
-    }
-}
-
-
-impl_traits!(Background);
-
-/// Many moving backgrounds in an array to make and "infinite" background.
-pub struct MovingBackground {
-    backgrounds: [Background; 4],
-}
-
-impl MovingBackground {
-    /// Create new `MovingBackground`
-    pub fn new() -> MovingBackground {
-        let size = BACKGROUND_SQUARE_SIDE_LENGTH;
-        let backgrounds = [
-            Background::new(-1, size),
-            Background::new(0, size),
-            Background::new(1, size),
-            Background::new(2, size),
-        ];
-
-        MovingBackground { backgrounds }
-    }
-
-    /// Updates all backgrounds.
-    fn update(&mut self, current_time: &GameTimeManager) {
-       for background in &mut self.backgrounds {
-           background.update(current_time);
-       }
-    }
-
-    /// Get backgrounds.
-    pub fn get_backgrounds(&self) -> &[Background; 4] {
-        &self.backgrounds
-    }
-
-    /// Moves every background's x coordinate.
-    pub fn move_position_x(&mut self, x: f32) {
-        for background in self.backgrounds.iter_mut() {
-            background.move_position(x, 0.0);
-        }
-    }
-}
\ No newline at end of file
@@ -0,0 +1,460 @@
+/*
+src/logic/particle.rs, 2017-09-21
+
+Copyright (c) 2017 Juuso Tuononen
+
+This file is licensed under
+
+Apache License, Version 2.0
+
+or
+
+MIT License
+*/
+
+//! Particle emitters.
+//!
+//! Every emitter has settings which define how many particles are
+//! created, how they move and how their color and size change during
+//! particle's lifetime. Emitters can create particles continuously
+//! (engine trails) or as bursts (laser impacts, explosions).
+
+use std::f32::consts;
+
+use cgmath::{Matrix4, Vector2, Vector4};
+use cgmath::prelude::*;
+
+use rand::{Rng, SeedableRng, XorShiftRng};
+use rand;
+
+use logic::common::*;
+use logic::animation::{AnimationPlayer, Animations};
+
+use utils::{Timer, GameTimeManager};
+
+use renderer::ModelMatrix;
+
+/// How particle colors are combined with colors already on the screen.
+#[derive(Copy, Clone, PartialEq)]
+pub enum BlendMode {
+    /// Normal alpha blending.
+    Alpha,
+    /// Add particle color to the screen. Useful for glowing particles.
+    Additive,
+}
+
+/// Settings of a particle emitter.
+pub struct EmitterSettings {
+    /// Particles created per second when continuous emission is enabled.
+    pub rate_per_second: f32,
+    /// Particles created with one burst.
+    pub burst_count: u32,
+    /// Minimum and maximum particle lifetime in milliseconds.
+    pub lifetime_milliseconds: (u32, u32),
+    /// Minimum and maximum particle speed.
+    pub speed: (f32, f32),
+    /// Max angle in radians between particle's and emitter's direction.
+    pub spread_angle: f32,
+    /// Particle color at the start of particle's lifetime.
+    pub start_color: Vector4<f32>,
+    /// Particle color at the end of particle's lifetime.
+    pub end_color: Vector4<f32>,
+    /// Particle side length at the start of particle's lifetime.
+    pub start_size: f32,
+    /// Particle side length at the end of particle's lifetime.
+    pub end_size: f32,
+    pub blend_mode: BlendMode,
+    /// If this is `None` particles are rendered as colored squares.
+    pub animation: Option<Animations>,
+}
+
+/// All emitters of `ParticleSystem`.
+#[derive(Copy, Clone, PartialEq)]
+pub enum Emitters {
+    PlayerEngineTrail,
+    EnemyEngineTrail,
+    LaserImpact,
+    ShieldHit,
+    BombDetonation,
+    ExplosionSparks,
+    EmitterCount,
+}
+
+impl Emitters {
+    /// Get emitter's settings.
+    pub fn settings(self) -> EmitterSettings {
+        match self {
+            Emitters::PlayerEngineTrail => EmitterSettings {
+                rate_per_second: 40.0,
+                burst_count: 0,
+                lifetime_milliseconds: (250, 400),
+                speed: (0.02, 0.04),
+                spread_angle: 0.25,
+                start_color: Vector4::new(0.4, 0.8, 1.0, 0.8),
+                end_color: Vector4::new(0.1, 0.2, 1.0, 0.0),
+                start_size: 0.15,
+                end_size: 0.05,
+                blend_mode: BlendMode::Additive,
+                animation: None,
+            },
+            Emitters::EnemyEngineTrail => EmitterSettings {
+                rate_per_second: 40.0,
+                burst_count: 0,
+                lifetime_milliseconds: (250, 400),
+                speed: (0.02, 0.04),
+                spread_angle: 0.25,
+                start_color: Vector4::new(1.0, 0.6, 0.2, 0.8),
+                end_color: Vector4::new(1.0, 0.1, 0.0, 0.0),
+                start_size: 0.2,
+                end_size: 0.05,
+                blend_mode: BlendMode::Additive,
+                animation: None,
+            },
+            Emitters::LaserImpact => EmitterSettings {
+                rate_per_second: 0.0,
+                burst_count: 6,
+                lifetime_milliseconds: (150, 300),
+                speed: (0.03, 0.06),
+                spread_angle: consts::PI / 3.0,
+                start_color: Vector4::new(1.0, 1.0, 0.6, 1.0),
+                end_color: Vector4::new(1.0, 0.4, 0.0, 0.0),
+                start_size: 0.12,
+                end_size: 0.02,
+                blend_mode: BlendMode::Additive,
+                animation: None,
+            },
+            Emitters::ShieldHit => EmitterSettings {
+                rate_per_second: 0.0,
+                burst_count: 8,
+                lifetime_milliseconds: (300, 500),
+                speed: (0.01, 0.03),
+                spread_angle: consts::PI / 2.0,
+                start_color: Vector4::new(0.4, 0.7, 1.0, 0.9),
+                end_color: Vector4::new(0.2, 0.4, 1.0, 0.0),
+                start_size: 0.2,
+                end_size: 0.35,
+                blend_mode: BlendMode::Additive,
+                animation: None,
+            },
+            Emitters::BombDetonation => EmitterSettings {
+                rate_per_second: 0.0,
+                burst_count: 20,
+                lifetime_milliseconds: (300, 600),
+                speed: (0.02, 0.06),
+                spread_angle: consts::PI,
+                start_color: Vector4::new(0.6, 0.6, 1.0, 1.0),
+                end_color: Vector4::new(0.2, 0.2, 1.0, 0.0),
+                start_size: 0.3,
+                end_size: 0.1,
+                blend_mode: BlendMode::Additive,
+                animation: None,
+            },
+            Emitters::ExplosionSparks => EmitterSettings {
+                rate_per_second: 0.0,
+                burst_count: 15,
+                lifetime_milliseconds: (500, 900),
+                speed: (0.01, 0.02),
+                spread_angle: consts::PI,
+                start_color: Vector4::new(1.0, 1.0, 1.0, 1.0),
+                end_color: Vector4::new(1.0, 1.0, 1.0, 0.0),
+                start_size: 0.2,
+                end_size: 0.2,
+                blend_mode: BlendMode::Alpha,
+                animation: Some(Animations::ExplosionSpark),
+            },
+            Emitters::EmitterCount => EmitterSettings {
+                rate_per_second: 0.0,
+                burst_count: 0,
+                lifetime_milliseconds: (0, 0),
+                speed: (0.0, 0.0),
+                spread_angle: 0.0,
+                start_color: Vector4::zero(),
+                end_color: Vector4::zero(),
+                start_size: 0.0,
+                end_size: 0.0,
+                blend_mode: BlendMode::Alpha,
+                animation: None,
+            },
+        }
+    }
+}
+
+/// Particle created by `ParticleEmitter`.
+pub struct Particle {
+    data: Data<f32>,
+    velocity: Vector2<f32>,
+    lifetime_timer: Timer,
+    lifetime_milliseconds: u32,
+    color: Vector4<f32>,
+    animation: Option<AnimationPlayer>,
+}
+
+impl Particle {
+    /// Create new `Particle`. Argument `velocity` is movement per
+    /// logic update at target logic update rate.
+    fn new(settings: &EmitterSettings, position: Vector2<f32>, velocity: Vector2<f32>, lifetime_milliseconds: u32, current_time: &GameTimeManager) -> Particle {
+        let animation = settings.animation.map(|animation| {
+            let mut player = AnimationPlayer::new(animation);
+            player.restart(current_time);
+            player
+        });
+
+        Particle {
+            data: Data::new_square(position, settings.start_size),
+            velocity,
+            lifetime_timer: Timer::new_from_time(current_time.time()),
+            lifetime_milliseconds,
+            color: settings.start_color,
+            animation,
+        }
+    }
+
+    /// Updates particle and returns true if particle can be destroyed.
+    fn update(&mut self, settings: &EmitterSettings, current_time: &GameTimeManager) -> bool {
+        let movement = self.velocity * current_time.delta_time();
+        self.move_position(movement.x, movement.y);
+
+        if let Some(ref mut animation) = self.animation {
+            animation.update(current_time);
+        }
+
+        let elapsed = self.lifetime_timer.milliseconds(current_time.time());
+
+        if elapsed >= self.lifetime_milliseconds {
+            return true;
+        }
+
+        let t = elapsed as f32 / self.lifetime_milliseconds as f32;
+
+        self.color = settings.start_color.lerp(settings.end_color, t);
+
+        let size = settings.start_size + (settings.end_size - settings.start_size) * t;
+        self.data.set_size(size, size);
+
+        false
+    }
+
+    /// Get particle's current color.
+    pub fn color(&self) -> &Vector4<f32> {
+        &self.color
+    }
+
+    /// Get particle's animation.
+    pub fn animation(&self) -> Option<&AnimationPlayer> {
+        self.animation.as_ref()
+    }
+}
+
+impl GameObject for Particle {}
+
+impl ModelMatrix for Particle {
+    fn model_matrix(&self) -> &Matrix4<f32> {
+        &self.data.model_matrix
+    }
+
+    fn interpolated_model_matrix(&self, interpolation_factor: f32) -> Matrix4<f32> {
+        self.data.interpolated_model_matrix(interpolation_factor)
+    }
+}
+
+impl GameObjectData<f32> for Particle {
+    fn data(&self) -> &Data<f32> {
+        &self.data
+    }
+    fn data_mut(&mut self) -> &mut Data<f32> {
+        &mut self.data
+    }
+}
+
+/// Creates and updates particles.
+pub struct ParticleEmitter {
+    settings: EmitterSettings,
+    particles: Vec<Particle>,
+    position: Vector2<f32>,
+    /// Direction angle in radians for continuous emission.
+    direction: f32,
+    enabled: bool,
+    emission_timer: Timer,
+    /// Particles which should have been created, but are not
+    /// yet created because count is not a whole number.
+    emission_accumulator: f32,
+}
+
+impl ParticleEmitter {
+    /// Create new `ParticleEmitter`. Continuous emission is disabled by default.
+    pub fn new(settings: EmitterSettings) -> ParticleEmitter {
+        ParticleEmitter {
+            settings,
+            particles: Vec::with_capacity(32),
+            position: Vector2::zero(),
+            direction: 0.0,
+            enabled: false,
+            emission_timer: Timer::new(),
+            emission_accumulator: 0.0,
+        }
+    }
+
+    /// Set position and direction for continuous emission.
+    pub fn set_position(&mut self, position: Vector2<f32>, direction: f32) {
+        self.position = position;
+        self.direction = direction;
+    }
+
+    /// Enable or disable continuous emission.
+    pub fn set_enabled(&mut self, enabled: bool, current_time: &GameTimeManager) {
+        if enabled && !self.enabled {
+            self.emission_timer.reset(current_time.time());
+            self.emission_accumulator = 0.0;
+        }
+
+        self.enabled = enabled;
+    }
+
+    /// Create `burst_count` particles.
+    pub fn burst<R: Rng>(&mut self, position: Vector2<f32>, direction: f32, rng: &mut R, current_time: &GameTimeManager) {
+        for _ in 0..self.settings.burst_count {
+            self.create_particle(position, direction, rng, current_time);
+        }
+    }
+
+    /// Update particles and create new particles if continuous emission is enabled.
+    pub fn update<R: Rng>(&mut self, rng: &mut R, index_buffer: &mut Vec<usize>, current_time: &GameTimeManager) {
+        {
+            let settings = &self.settings;
+            self.particles.update(index_buffer, &mut |particle| {
+                particle.update(settings, current_time)
+            });
+        }
+
+        if !self.enabled {
+            return;
+        }
+
+        let elapsed = self.emission_timer.milliseconds(current_time.time());
+        self.emission_timer.reset(current_time.time());
+        self.emission_accumulator += elapsed as f32 * self.settings.rate_per_second / 1000.0;
+
+        while self.emission_accumulator >= 1.0 {
+            let (position, direction) = (self.position, self.direction);
+            self.create_particle(position, direction, rng, current_time);
+            self.emission_accumulator -= 1.0;
+        }
+    }
+
+    /// Create one particle with random speed, direction and lifetime.
+    fn create_particle<R: Rng>(&mut self, position: Vector2<f32>, direction: f32, rng: &mut R, current_time: &GameTimeManager) {
+        let (min_speed, max_speed) = self.settings.speed;
+        let (min_lifetime, max_lifetime) = self.settings.lifetime_milliseconds;
+
+        let angle = direction + self.settings.spread_angle * (rng.gen::<f32>() * 2.0 - 1.0);
+        let speed = min_speed + (max_speed - min_speed) * rng.gen::<f32>();
+        let lifetime = min_lifetime + ((max_lifetime - min_lifetime) as f32 * rng.gen::<f32>()) as u32;
+
+        let velocity = vec_from_angle(angle) * speed;
+
+        self.particles.push(Particle::new(&self.settings, position, velocity, lifetime, current_time));
+    }
+
+    /// Remove all particles and disable continuous emission.
+    pub fn reset(&mut self) {
+        self.particles.clear();
+        self.enabled = false;
+    }
+
+    /// Get current particles.
+    pub fn particles(&self) -> &Vec<Particle> {
+        &self.particles
+    }
+
+    /// Get blend mode for rendering particles.
+    pub fn blend_mode(&self) -> BlendMode {
+        self.settings.blend_mode
+    }
+
+    /// Store current transforms of particles.
+    fn store_previous_transforms(&mut self) {
+        for particle in &mut self.particles {
+            particle.data.store_previous_transform();
+        }
+    }
+}
+
+/// Unit vector pointing to direction of argument `angle`. Angle is in radians.
+fn vec_from_angle(angle: f32) -> Vector2<f32> {
+    Vector2::new(angle.cos(), angle.sin())
+}
+
+/// Stores all particle emitters of the game.
+pub struct ParticleSystem {
+    emitters: Vec<ParticleEmitter>,
+    rng: XorShiftRng,
+}
+
+impl ParticleSystem {
+    /// Create new `ParticleSystem`.
+    pub fn new() -> ParticleSystem {
+        let emitters = vec![
+            Emitters::PlayerEngineTrail,
+            Emitters::EnemyEngineTrail,
+            Emitters::LaserImpact,
+            Emitters::ShieldHit,
+            Emitters::BombDetonation,
+            Emitters::ExplosionSparks,
+        ];
+
+        ParticleSystem {
+            emitters: emitters.into_iter().map(|emitter| ParticleEmitter::new(emitter.settings())).collect(),
+            rng: rand::weak_rng(),
+        }
+    }
+
+    /// Set seed for random number generator.
+    pub fn set_random_seed(&mut self, seed: u64) {
+        // XorShiftRng seed must not be all zeros.
+        let seed = [seed as u32, (seed >> 32) as u32, 0x193a_6754, 0xa8a7_d469];
+        self.rng = XorShiftRng::from_seed(seed);
+    }
+
+    /// Create particle burst from emitter. Argument `direction` is in radians.
+    pub fn emit(&mut self, emitter: Emitters, position: Vector2<f32>, direction: f32, current_time: &GameTimeManager) {
+        self.emitters[emitter as usize].burst(position, direction, &mut self.rng, current_time);
+    }
+
+    /// Update continuous emission position, direction and state of emitter.
+    pub fn set_continuous_emission(&mut self, emitter: Emitters, position: Vector2<f32>, direction: f32, enabled: bool, current_time: &GameTimeManager) {
+        let emitter = &mut self.emitters[emitter as usize];
+        emitter.set_position(position, direction);
+        emitter.set_enabled(enabled, current_time);
+    }
+
+    /// Update all emitters.
+    pub fn update(&mut self, index_buffer: &mut Vec<usize>, current_time: &GameTimeManager) {
+        for emitter in &mut self.emitters {
+            emitter.update(&mut self.rng, index_buffer, current_time);
+        }
+    }
+
+    /// Remove all particles.
+    pub fn reset(&mut self) {
+        for emitter in &mut self.emitters {
+            emitter.reset();
+        }
+    }
+
+    /// Get emitter.
+    pub fn emitter(&self, emitter: Emitters) -> &ParticleEmitter {
+        &self.emitters[emitter as usize]
+    }
+
+    /// Count of all particles.
+    pub fn particle_count(&self) -> usize {
+        self.emitters.iter().map(|emitter| emitter.particles().len()).sum()
+    }
+
+    /// Store current transforms of all particles.
+    pub fn store_previous_transforms(&mut self) {
+        for emitter in &mut self.emitters {
+            emitter.store_previous_transforms();
+        }
+    }
+}
@@ -26,6 +26,10 @@ extern crate gl;
 extern crate image;
 extern crate cgmath;
 extern crate rand;
+extern crate toml;
+
+#[macro_use]
+extern crate log;
 
 #[cfg(target_os = "emscripten")]
 extern crate emscripten_sys;
@@ -38,49 +42,70 @@ pub mod settings;
 pub mod audio;
 pub mod utils;
 pub mod window;
+pub mod logger;
+pub mod paths;
+pub mod locale;
+pub mod console;
 
 use std::env;
 
 use renderer::{Renderer, OpenGLRenderer};
-use logic::Logic;
+use logic::{Logic, Difficulty};
 
-use input::{InputManager};
+use input::{InputManager, Input};
 use gui::{GUI, GUIEvent, GUIState};
 
 use settings::{Settings, Arguments};
 
+use console::{ConsoleCommand, Character, SpawnObject, CONSOLE_COMMANDS};
+
+use paths::GamePaths;
+
 use audio::{AudioManager, SoundEffectPlayer, AudioPlayer, Audio, Volume};
 
-use utils::{FpsCounter, GameLoopTimer, TimeManager};
+use utils::{FpsCounter, FrameLimiter, GameLoopTimer, TimeManager};
 
 use window::{Window, RenderingContext};
 
 /// Base value for `GameTimeManager`'s delta time.
 pub const LOGIC_TARGET_FPS: u32 = 60;
 
-/// Max value for logic updates per seconds. This limit exist to avoid
-/// possible floating point errors from extremely small delta time values
-/// from `GameTimeManager`.
-///
-/// Current max value for this is 1000, because GameLoopTimer only handles milliseconds.
-pub const LOGIC_MAX_FPS: u32 = 1000;
+/// Fixed time step of logic updates in microseconds. Game objects are
+/// rendered interpolated between logic updates.
+pub const LOGIC_UPDATE_MICROSECONDS: u32 = 1_000_000/LOGIC_TARGET_FPS;
+
+#[cfg(not(feature = "glutin_window"))]
+type GameWindow = window::sdl2::SDL2Window;
 
-const LOGIC_MAX_UPDATES_MILLISECONDS: u32 = 1000/LOGIC_MAX_FPS;
+#[cfg(feature = "glutin_window")]
+type GameWindow = window::glutin::GlutinWindow;
 
 pub const COMMAND_LINE_HELP_TEXT: &str = "
 Space Boss Battles command line options:
 --help|-h         - show this text
 --fps             - print fps to standard output
+--frame-times     - print frame time statistics to standard output
 --joystick-events - print joystick events to standard output
 --music FILE_PATH - set path to music file
+--log-level LEVEL - set log level, for example \"debug\" or \"warn,settings=trace\"
+--log-file        - write log messages also to a log file
+--data-dir DIR    - set path to game data directory
+--config FILE     - set path to settings file
+--set KEY=VALUE   - set setting value, for example \"music_volume=40\"
+--fullscreen      - start in full screen mode
+--windowed        - start in windowed mode
+--window-size WxH - set window size in pixels, for example \"1280x720\"
+--difficulty NAME - start game with difficulty \"easy\", \"normal\" or \"hard\"
+--level LEVEL     - start game from level LEVEL
+--seed SEED       - set seed for random number generators
 ";
 
 /// Check command line arguments, initialize game and start game loop.
 fn main() {
     let arguments = match Arguments::parse(env::args()) {
         Ok(arguments) => arguments,
-        Err(unknown_argument) => {
-            println!("unknown argument: \"{}\"", unknown_argument);
+        Err(error_message) => {
+            println!("{}", error_message);
             println!("{}", COMMAND_LINE_HELP_TEXT);
             return;
         }
@@ -91,6 +116,19 @@ fn main() {
         return;
     }
 
+    let paths = GamePaths::new(&arguments);
+
+    let log_file_path = if arguments.log_to_file() {
+        Some(paths.log_file())
+    } else {
+        None
+    };
+
+    logger::init(arguments.log_level_settings().clone(), log_file_path);
+
+    // Settings are loaded before window creation, because
+    // settings file contains window's size and position.
+    let settings = Game::<GameWindow>::load_settings(arguments, paths);
 
     #[cfg(not(feature = "gles"))]
     let rendering_context = RenderingContext::OpenGL;
@@ -98,13 +136,9 @@ fn main() {
     #[cfg(feature = "gles")]
     let rendering_context = RenderingContext::OpenGLES;
 
-    #[cfg(not(feature = "glutin_window"))]
-    let window = window::sdl2::SDL2Window::new(rendering_context).expect("window creation failed");
+    let window = GameWindow::new(rendering_context, settings.window_geometry()).expect("window creation failed");
 
-    #[cfg(feature = "glutin_window")]
-    let window = window::glutin::GlutinWindow::new(rendering_context).expect("window creation failed");
-
-    let mut game = Game::new(arguments, window);
+    let mut game = Game::new(settings, window);
 
     #[cfg(target_os = "emscripten")]
     {
@@ -130,6 +164,8 @@ fn main() {
             game.update();
 
             game.render();
+
+            game.wait_next_frame();
         }
     }
 }
@@ -161,6 +197,7 @@ pub struct Game<W: Window> {
     quit: bool,
     input: InputManager,
     fps_counter: FpsCounter,
+    frame_limiter: FrameLimiter,
     timer: GameLoopTimer,
     gui: GUI,
     renderer: OpenGLRenderer,
@@ -168,62 +205,89 @@ pub struct Game<W: Window> {
     audio_manager: AudioManager<W::AudioPlayer>,
     update_game: bool,
     render_game: bool,
+    /// Render debug overlay on top of the game.
+    debug_overlay: bool,
     time_manager: TimeManager,
     window: W,
 }
 
 impl<W: Window> Game<W> {
+    /// Load settings with default volume values from window's audio backend.
+    pub fn load_settings(command_line_arguments: Arguments, paths: GamePaths) -> Settings {
+        Settings::new(
+            command_line_arguments,
+            paths,
+            <<<W::AudioPlayer as AudioPlayer>::Effect as Audio>::Volume as Volume>::DEFAULT_VOLUME_PERCENTAGE,
+            <<<W::AudioPlayer as AudioPlayer>::Music as Audio>::Volume as Volume>::DEFAULT_VOLUME_PERCENTAGE,
+        )
+    }
+
     /// Create new `Game`. Creates and initializes game's components.
     pub fn new(
-                command_line_arguments: Arguments,
+                settings: Settings,
                 mut window: W,
             ) -> Self {
 
         let player = window.audio_player();
 
-        let mut audio_manager = if let & Some(ref music_file_path) = command_line_arguments.music_file_path() {
-            AudioManager::new(music_file_path, player)
+        let quick_start = settings.arguments().quick_start();
+        let seed = settings.arguments().seed();
+
+        let mut audio_manager = if let & Some(ref music_file_path) = settings.arguments().music_file_path() {
+            AudioManager::new(music_file_path, settings.paths(), player)
         } else {
-            AudioManager::new("music.ogg", player)
+            AudioManager::new("music.ogg", settings.paths(), player)
         };
 
-        let settings = Settings::new(
-            command_line_arguments,
-            <<<W::AudioPlayer as AudioPlayer>::Effect as Audio>::Volume as Volume>::DEFAULT_VOLUME_PERCENTAGE,
-            <<<W::AudioPlayer as AudioPlayer>::Music as Audio>::Volume as Volume>::DEFAULT_VOLUME_PERCENTAGE,
-        );
-
         window.add_game_controller_mappings(settings.game_controller_mappings());
 
         let input = InputManager::new();
 
-        let mut renderer = OpenGLRenderer::new(&window);
+        let (window_width, window_height) = window.drawable_size();
+        let mut renderer = OpenGLRenderer::new(&window, settings.paths(), (window_width as i32, window_height as i32));
         let mut gui = GUI::new(&settings);
         gui.update_position_from_half_screen_width(renderer.half_screen_width_world_coordinates());
 
         let mut game_logic = Logic::new();
         game_logic.update_half_screen_width(renderer.half_screen_width_world_coordinates());
 
-        settings.apply_current_settings(&mut renderer, &mut gui, &mut audio_manager, &mut window);
+        if let Some(seed) = seed {
+            game_logic.set_random_seed(seed);
+        }
+
+        let mut fps_counter = FpsCounter::new();
+        fps_counter.set_frame_time_statistics(settings.print_frame_times());
+
+        let mut frame_limiter = FrameLimiter::new();
+
+        settings.apply_current_settings(&mut renderer, &mut gui, &mut game_logic, &mut audio_manager, &mut window, &mut frame_limiter);
 
         // Try to play music after getting audio volume from settings.
         audio_manager.play_music();
 
-        Game {
+        let mut game = Game {
             game_logic,
             quit: false,
             input,
-            fps_counter: FpsCounter::new(),
-            timer: GameLoopTimer::new(LOGIC_MAX_UPDATES_MILLISECONDS),
+            fps_counter,
+            frame_limiter,
+            timer: GameLoopTimer::new(LOGIC_UPDATE_MICROSECONDS),
             gui,
             renderer,
             settings,
             audio_manager,
             update_game: false,
             render_game: false,
+            debug_overlay: false,
             time_manager: TimeManager::new(),
             window,
+        };
+
+        if let Some((difficulty, level)) = quick_start {
+            game.start_game(difficulty, level);
         }
+
+        game
     }
 
     /// Return true if game should be closed.
@@ -249,20 +313,37 @@ impl<W: Window> Game<W> {
 
         self.renderer.start();
 
+        let interpolation_factor = self.timer.interpolation_factor();
+
         if self.render_game {
-            self.renderer.render(&self.game_logic, false);
+            self.renderer.render(&self.game_logic, false, interpolation_factor);
         } else {
-            self.renderer.render(&self.game_logic, true);
+            self.renderer.render(&self.game_logic, true, interpolation_factor);
         }
 
         self.renderer.render_gui(&self.gui);
 
+        if self.debug_overlay && self.render_game {
+            let debug_info = self.game_logic.debug_info(self.time_manager.game_time_manager());
+            self.renderer.render_debug_overlay(&debug_info);
+        }
+
         self.renderer.end(&mut self.window);
     }
 
+    /// Wait until it is time to start the next frame if
+    /// there is an fps cap.
+    pub fn wait_next_frame(&mut self) {
+        self.frame_limiter.wait();
+    }
+
     /// Updates logic and other game components.
+    ///
+    /// Game is paused when developer console is open.
     pub fn update(&mut self) {
-        self.time_manager.update_time(self.update_game);
+        let update_game = self.update_game && !self.gui.console_is_open();
+
+        self.time_manager.update_time(update_game);
 
         let fps_updated = self.fps_counter.update(self.time_manager.current_time(), self.settings.print_fps_count());
 
@@ -270,34 +351,53 @@ impl<W: Window> Game<W> {
             self.gui.update_fps_counter(self.fps_counter.fps());
         }
 
-        self.timer.update(self.time_manager.current_time());
+        self.gui.reload_changed_layouts(self.time_manager.current_time());
+        self.gui.update(self.time_manager.current_time());
+
+        self.timer.update(self.time_manager.current_instant());
 
-        if self.timer.update_logic() {
-            if self.update_game {
+        while self.timer.update_logic() {
+            // Store transforms also when game is paused, so paused game
+            // will not be rendered with interpolated positions.
+            self.game_logic.store_previous_transforms();
+
+            if update_game {
                 self.game_logic.update(&self.input, &mut self.gui, self.audio_manager.sound_effect_manager_mut(), self.time_manager.game_time_manager());
             }
 
-            match self.gui.handle_input(&mut self.input) {
+            if self.input.key_hit_console() {
+                self.gui.get_console().toggle();
+            }
+
+            let gui_event = if self.gui.console_is_open() {
+                self.handle_console_input();
+                None
+            } else {
+                self.gui.handle_input(&mut self.input)
+            };
+
+            match gui_event {
                 None => (),
                 Some(GUIEvent::Exit) => self.quit = true,
-                Some(GUIEvent::ChangeSetting(new_setting_value)) => {
-                    self.settings.update_setting(new_setting_value);
-                    Settings::apply_setting(new_setting_value, &mut self.renderer, &mut self.gui, &mut self.audio_manager, &mut self.window);
-                },
-                Some(GUIEvent::NewGame(difficulty)) => {
-                    self.game_logic.reset_game(&mut self.gui, difficulty, 0, self.time_manager.game_time_manager());
-                    self.set_game_rendering_and_updating(true, true);
+                Some(GUIEvent::ChangeSetting(id, value)) => {
+                    let value = self.settings.update_setting(id, value);
+                    Settings::apply_setting(id, value, &mut self.renderer, &mut self.gui, &mut self.game_logic, &mut self.audio_manager, &mut self.window, &mut self.frame_limiter);
                 },
+                Some(GUIEvent::NewGame(difficulty)) => self.start_game(difficulty, 0),
                 Some(GUIEvent::NextLevel) => {
                     self.game_logic.reset_to_next_level(&mut self.gui, self.time_manager.game_time_manager());
                     self.set_game_rendering_and_updating(true, true);
                 },
-                Some(GUIEvent::ChangeState(GUIState::Game)) => self.set_game_rendering_and_updating(true, true),
-                Some(GUIEvent::ChangeState(GUIState::PauseMenu)) |
-                Some(GUIEvent::ChangeState(GUIState::NextLevelScreen)) |
-                Some(GUIEvent::ChangeState(GUIState::GameOverScreen)) |
-                Some(GUIEvent::ChangeState(GUIState::PlayerWinsScreen)) => self.set_game_rendering_and_updating(true, false),
-                Some(GUIEvent::ChangeState(_)) => self.set_game_rendering_and_updating(false, false),
+                Some(GUIEvent::ChangeState(_)) |
+                Some(GUIEvent::PushState(_)) |
+                Some(GUIEvent::Back) |
+                Some(GUIEvent::Confirm(_)) |
+                Some(GUIEvent::ConfirmAccept(_)) |
+                Some(GUIEvent::ConfirmCancel(_)) => self.update_game_rendering_and_updating_from_gui_state(),
+            }
+
+            if self.input.key_hit_debug_overlay() {
+                self.debug_overlay = !self.debug_overlay;
             }
 
             self.input.update(self.time_manager.current_time());
@@ -305,8 +405,91 @@ impl<W: Window> Game<W> {
         }
     }
 
-    /// Save current settings.
-    pub fn save_settings(&self) {
+    /// Reset game logic to specific difficulty and level, and
+    /// change GUI to game state.
+    pub fn start_game(&mut self, difficulty: Difficulty, level: u32) {
+        self.game_logic.reset_game(&mut self.gui, difficulty, level, self.time_manager.game_time_manager());
+        self.gui.handle_gui_event(GUIEvent::NewGame(difficulty));
+        self.set_game_rendering_and_updating(true, true);
+    }
+
+    /// Run command from the developer console's command line.
+    fn handle_console_input(&mut self) {
+        let line = match self.gui.get_console().handle_input(&mut self.input) {
+            Some(line) => line,
+            None => return,
+        };
+
+        info!("console command: {}", line);
+
+        let command = match ConsoleCommand::parse(&line) {
+            Ok(command) => command,
+            Err(error) => {
+                self.gui.get_console().print(&error);
+                return;
+            }
+        };
+
+        let message = match command {
+            ConsoleCommand::Help => {
+                CONSOLE_COMMANDS.iter().map(|&(name, arguments)| format!("{} {}", name, arguments)).collect::<Vec<String>>().join("\n")
+            },
+            ConsoleCommand::Clear => {
+                self.gui.get_console().clear();
+                return;
+            },
+            ConsoleCommand::Level(level) => {
+                let difficulty = self.game_logic.difficulty();
+                self.start_game(difficulty, level);
+                format!("started level {}", level + 1)
+            },
+            ConsoleCommand::Difficulty(difficulty) => {
+                let level = self.game_logic.level();
+                self.start_game(difficulty, level);
+                "restarted level with new difficulty".to_string()
+            },
+            ConsoleCommand::God => {
+                let enabled = !self.game_logic.god_mode();
+                self.game_logic.set_god_mode(enabled);
+                format!("god mode {}", if enabled { "enabled" } else { "disabled" })
+            },
+            ConsoleCommand::SetHealth(character, health) => {
+                match character {
+                    Character::Player => self.game_logic.set_player_health(health),
+                    Character::Enemy => self.game_logic.set_enemy_health(health),
+                }
+                format!("health set to {}", health)
+            },
+            ConsoleCommand::Spawn(SpawnObject::LaserBomb) => {
+                if self.gui.state_is_open(GUIState::Game) && self.game_logic.spawn_laser_bomb(self.time_manager.game_time_manager()) {
+                    "laser bomb spawned".to_string()
+                } else {
+                    "game is not running".to_string()
+                }
+            },
+            ConsoleCommand::TimeScale(scale) => {
+                self.time_manager.set_game_time_scale(scale);
+                format!("time scale set to {}", scale)
+            },
+            ConsoleCommand::Set(id, value) => {
+                let value = self.settings.update_setting(id, value);
+                self.gui.get_settings_menu().set_setting_value(id, value);
+                Settings::apply_setting(id, value, &mut self.renderer, &mut self.gui, &mut self.game_logic, &mut self.audio_manager, &mut self.window, &mut self.frame_limiter);
+
+                let definition = id.definition();
+                format!("{} = {}", definition.key, definition.kind.format(value))
+            },
+        };
+
+        self.gui.get_console().print(&message);
+    }
+
+    /// Save current settings and window size and position.
+    pub fn save_settings(&mut self) {
+        if let Some(window_geometry) = self.window.window_geometry() {
+            self.settings.set_window_geometry(window_geometry);
+        }
+
         self.settings.save();
     }
 
@@ -315,4 +498,20 @@ impl<W: Window> Game<W> {
         self.render_game = rendering;
         self.update_game = updating;
     }
+
+    /// Update game only in game state. Render game also when
+    /// some menu is opened on top of the game and in screens
+    /// which are displayed after the game.
+    fn update_game_rendering_and_updating_from_gui_state(&mut self) {
+        match self.gui.state() {
+            GUIState::Game => self.set_game_rendering_and_updating(true, true),
+            GUIState::NextLevelScreen |
+            GUIState::GameOverScreen |
+            GUIState::PlayerWinsScreen => self.set_game_rendering_and_updating(true, false),
+            _ => {
+                let rendering = self.gui.state_is_open(GUIState::Game);
+                self.set_game_rendering_and_updating(rendering, false)
+            },
+        }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,159 @@
+/*
+src/paths.rs, 2017-09-15
+
+Copyright (c) 2017 Juuso Tuononen
+
+This file is licensed under
+
+Apache License, Version 2.0
+
+or
+
+MIT License
+*/
+
+//! File and directory locations.
+//!
+//! # Game data
+//!
+//! Game data directory contains the game's images and audio files. Game data directory
+//! is searched from these locations in the following order:
+//!
+//! 1. Directory set with command line argument `--data-dir`.
+//! 2. Directory `game_files` next to the game's executable.
+//! 3. Directory `game_files` in the current working directory.
+//!
+//! # User files
+//!
+//! On Linux, settings file is stored to `$XDG_CONFIG_HOME/space-boss-battles`.
+//! If this environment variable is not set, directory `~/.config/space-boss-battles`
+//! is used.
+//!
+//! On other platforms, user files are stored to the current working directory.
+//!
+//! Settings file location can be changed with command line argument `--config`.
+
+use std::env;
+use std::path::{Path, PathBuf};
+
+use settings::Arguments;
+use logger::LOG_FILE_NAME;
+
+/// Settings file name.
+pub const SETTINGS_FILE_NAME: &'static str = "space_boss_battles_settings.toml";
+
+/// Settings file name before the TOML settings file format. This file
+/// was stored to the current working directory.
+pub const OLD_SETTINGS_FILE_NAME: &'static str = "space_boss_battles_settings.txt";
+
+/// Name of the game data directory.
+const GAME_DATA_DIR_NAME: &'static str = "game_files";
+
+/// Name of game's directory in user's config and data directories.
+#[cfg(target_os = "linux")]
+const USER_DIR_NAME: &'static str = "space-boss-battles";
+
+/// Locations of game data and user files.
+pub struct GamePaths {
+    data_dir: PathBuf,
+    settings_file: PathBuf,
+}
+
+impl GamePaths {
+    /// Create new `GamePaths`. Locations will be determined from
+    /// command line arguments and the current platform.
+    pub fn new(arguments: &Arguments) -> GamePaths {
+        let data_dir = match *arguments.data_dir() {
+            Some(ref data_dir) => PathBuf::from(data_dir),
+            None => find_data_dir(),
+        };
+
+        let settings_file = match *arguments.config_file_path() {
+            Some(ref config_file_path) => PathBuf::from(config_file_path),
+            None => user_config_dir().join(SETTINGS_FILE_NAME),
+        };
+
+        GamePaths {
+            data_dir,
+            settings_file,
+        }
+    }
+
+    /// Path to a file in the game data directory.
+    ///
+    /// Argument `relative_path` must use `/` as path separator.
+    pub fn data_file(&self, relative_path: &str) -> String {
+        let mut path = self.data_dir.clone();
+
+        for component in relative_path.split('/') {
+            path.push(component);
+        }
+
+        path.to_string_lossy().into_owned()
+    }
+
+    /// Settings file path.
+    pub fn settings_file(&self) -> &Path {
+        &self.settings_file
+    }
+
+    /// Path of the settings file which uses the old settings file format.
+    pub fn old_settings_file(&self) -> &Path {
+        Path::new(OLD_SETTINGS_FILE_NAME)
+    }
+
+    /// Path of the log file. Log file is located at
+    /// the same directory as the settings file.
+    pub fn log_file(&self) -> PathBuf {
+        self.settings_file.with_file_name(LOG_FILE_NAME)
+    }
+}
+
+/// Find game data directory from executable's directory or
+/// current working directory.
+fn find_data_dir() -> PathBuf {
+    if let Ok(executable) = env::current_exe() {
+        if let Some(executable_dir) = executable.parent() {
+            let data_dir = executable_dir.join(GAME_DATA_DIR_NAME);
+
+            if data_dir.is_dir() {
+                return data_dir;
+            }
+        }
+    }
+
+    PathBuf::from(GAME_DATA_DIR_NAME)
+}
+
+/// User specific directory from XDG base directory environment variable
+/// or from default directory relative to home directory.
+///
+/// Returns current working directory if neither is available.
+#[cfg(target_os = "linux")]
+fn xdg_dir(environment_variable: &str, default_dir_relative_to_home: &str) -> PathBuf {
+    if let Some(dir) = env::var_os(environment_variable) {
+        let dir = PathBuf::from(dir);
+
+        // XDG base directory specification requires absolute paths.
+        if dir.is_absolute() {
+            return dir.join(USER_DIR_NAME);
+        }
+    }
+
+    match env::var_os("HOME") {
+        Some(home) => PathBuf::from(home).join(default_dir_relative_to_home).join(USER_DIR_NAME),
+        None => PathBuf::new(),
+    }
+}
+
+/// Directory for the settings file.
+#[cfg(target_os = "linux")]
+fn user_config_dir() -> PathBuf {
+    xdg_dir("XDG_CONFIG_HOME", ".config")
+}
+
+/// Directory for the settings file.
+#[cfg(not(target_os = "linux"))]
+fn user_config_dir() -> PathBuf {
+    PathBuf::new()
+}
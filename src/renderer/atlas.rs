@@ -0,0 +1,196 @@
+/*
+src/renderer/atlas.rs, 2017-09-19
+
+Copyright (c) 2017 Juuso Tuononen
+
+This file is licensed under
+
+Apache License, Version 2.0
+
+or
+
+MIT License
+*/
+
+//! Pack multiple images to one texture.
+
+use gl::texture::*;
+
+use cgmath::Vector4;
+
+/// Width of the atlas texture in pixels.
+const ATLAS_WIDTH: u32 = 256;
+
+/// Space between images in the atlas. Image's edge
+/// pixels will be copied to this area, so
+/// texture sampling near image's edges will not use
+/// pixels from other images.
+const IMAGE_PADDING: u32 = 1;
+
+/// RGBA image.
+pub struct Image {
+    pub width: u32,
+    pub height: u32,
+    /// RGBA pixel data, first row is image's top row.
+    pub data: Vec<u8>,
+}
+
+impl Image {
+    /// Create new `Image` from RGB or RGBA pixel data. RGB data
+    /// will be converted to RGBA.
+    ///
+    /// # Panics
+    /// If image width, height and color type does not match with data length.
+    pub fn new(width: u32, height: u32, data: Vec<u8>, rgba: bool) -> Image {
+        let pixel_count = (width * height) as usize;
+
+        let data = if rgba {
+            data
+        } else {
+            if data.len() != pixel_count * 3 {
+                panic!("image width, height and color type does not match with data length");
+            }
+
+            let mut rgba_data = Vec::with_capacity(pixel_count * 4);
+
+            for pixel in data.chunks(3) {
+                rgba_data.extend_from_slice(pixel);
+                rgba_data.push(255);
+            }
+
+            rgba_data
+        };
+
+        if data.len() != pixel_count * 4 {
+            panic!("image width, height and color type does not match with data length");
+        }
+
+        Image { width, height, data }
+    }
+
+    /// Split sprite sheet image to frames. Frames must be
+    /// placed next to each other from left to right.
+    ///
+    /// # Panics
+    /// If image width is not divisible with `frame_count`.
+    pub fn split_frames(&self, frame_count: usize) -> Vec<Image> {
+        let frame_count = frame_count as u32;
+
+        if frame_count == 0 || self.width % frame_count != 0 {
+            panic!("sprite sheet width is not divisible with frame count");
+        }
+
+        let frame_width = self.width / frame_count;
+
+        (0..frame_count).map(|frame| {
+            let mut data = Vec::with_capacity((frame_width * self.height * 4) as usize);
+
+            for y in 0..self.height {
+                let start = ((y * self.width + frame * frame_width) * 4) as usize;
+                data.extend_from_slice(&self.data[start..start + (frame_width * 4) as usize]);
+            }
+
+            Image { width: frame_width, height: self.height, data }
+        }).collect()
+    }
+
+    /// Get RGBA pixel. Coordinates will be clamped to image's area.
+    fn pixel(&self, x: i32, y: i32) -> &[u8] {
+        let x = x.max(0).min(self.width as i32 - 1) as usize;
+        let y = y.max(0).min(self.height as i32 - 1) as usize;
+        let i = (y * self.width as usize + x) * 4;
+
+        &self.data[i..i+4]
+    }
+}
+
+/// Texture coordinates of images in the atlas texture.
+pub struct TextureAtlas {
+    /// Texture rectangles in the same order as images were
+    /// given to method `build`.
+    rectangles: Vec<Vector4<f32>>,
+}
+
+impl TextureAtlas {
+    /// Packs images to one texture. Images are placed to rows from the tallest
+    /// image to the shortest image. Atlas texture height will be rounded up to
+    /// power of two, because OpenGL ES 2.0 doesn't support texture wrap mode repeat
+    /// with non power of two textures.
+    ///
+    /// # Panics
+    /// If some image is wider than the atlas texture.
+    pub fn build(images: &[Image]) -> (Texture, TextureAtlas) {
+        let mut order: Vec<usize> = (0..images.len()).collect();
+        order.sort_by(|&a, &b| images[b].height.cmp(&images[a].height));
+
+        // Find image positions.
+
+        let mut positions = vec![(0, 0); images.len()];
+        let mut x = 0;
+        let mut y = 0;
+        let mut row_height = 0;
+
+        for &i in &order {
+            let width = images[i].width + IMAGE_PADDING * 2;
+            let height = images[i].height + IMAGE_PADDING * 2;
+
+            if width > ATLAS_WIDTH {
+                panic!("image is wider than texture atlas");
+            }
+
+            if x + width > ATLAS_WIDTH {
+                x = 0;
+                y += row_height;
+                row_height = 0;
+            }
+
+            positions[i] = (x + IMAGE_PADDING, y + IMAGE_PADDING);
+
+            x += width;
+            row_height = row_height.max(height);
+        }
+
+        let atlas_height = (y + row_height).next_power_of_two();
+
+        // Copy images to atlas.
+
+        let mut data = vec![0; (ATLAS_WIDTH * atlas_height * 4) as usize];
+        let padding = IMAGE_PADDING as i32;
+
+        for (image, &(image_x, image_y)) in images.iter().zip(positions.iter()) {
+            for y in -padding..image.height as i32 + padding {
+                for x in -padding..image.width as i32 + padding {
+                    let atlas_x = (image_x as i32 + x) as u32;
+                    let atlas_y = (image_y as i32 + y) as u32;
+                    let i = ((atlas_y * ATLAS_WIDTH + atlas_x) * 4) as usize;
+
+                    data[i..i+4].copy_from_slice(image.pixel(x, y));
+                }
+            }
+        }
+
+        // Texture rectangles.
+        //
+        // Shaders flip texture coordinate t, so rectangle's y is
+        // moved to negative side of the t axis.
+
+        let rectangles = images.iter().zip(positions.iter()).map(|(image, &(image_x, image_y))| {
+            let width = image.width as f32 / ATLAS_WIDTH as f32;
+            let height = image.height as f32 / atlas_height as f32;
+            let x = image_x as f32 / ATLAS_WIDTH as f32;
+            let y = image_y as f32 / atlas_height as f32;
+
+            Vector4::new(x, -(y + height), width, height)
+        }).collect();
+
+        info!("texture atlas size: {}x{}", ATLAS_WIDTH, atlas_height);
+
+        (Texture::new(ATLAS_WIDTH, atlas_height, data, true), TextureAtlas { rectangles })
+    }
+
+    /// Texture rectangle of image. See `SpriteBatch::add_quad` for
+    /// texture rectangle's format.
+    pub fn texture_rectangle(&self, image_index: usize) -> &Vector4<f32> {
+        &self.rectangles[image_index]
+    }
+}
@@ -0,0 +1,223 @@
+/*
+src/renderer/batch.rs, 2017-09-18
+
+Copyright (c) 2017 Juuso Tuononen
+
+This file is licensed under
+
+Apache License, Version 2.0
+
+or
+
+MIT License
+*/
+
+//! Batched quad rendering.
+//!
+//! Instead of drawing every game object and GUI component with a separate draw call,
+//! quads are transformed to world coordinates on CPU and collected to
+//! dynamic vertex buffers. All quads which use the same shader and
+//! texture are drawn with one draw call.
+
+use std::f32::consts;
+
+use gl::buffer::*;
+
+use cgmath::{Vector2, Vector3, Vector4, Matrix4};
+use cgmath::prelude::*;
+
+use logic::common::Rectangle;
+
+/// Vertices of two triangles which make a square. Square's side length is 1.0
+/// and center is at origin.
+const QUAD_VERTICES: [(f32, f32); 6] = [
+    (0.5, -0.5),
+    (0.5, 0.5),
+    (-0.5, 0.5),
+
+    (0.5, -0.5),
+    (-0.5, 0.5),
+    (-0.5, -0.5),
+];
+
+/// Texture coordinates for `QUAD_VERTICES`.
+const QUAD_TEXTURE_COORDINATES: [(f32, f32); 6] = [
+    (1.0, 0.0),
+    (1.0, 1.0),
+    (0.0, 1.0),
+
+    (1.0, 0.0),
+    (0.0, 1.0),
+    (0.0, 0.0),
+];
+
+/// Initial capacity of vertex data buffers as quads.
+const INITIAL_QUAD_CAPACITY: usize = 128;
+
+/// Collects quads and draws them with one draw call.
+///
+/// # Vertex attribute indexes
+/// * Vertex position, index 0
+/// * Texture coordinates, index 1
+/// * Color, index 2
+pub struct SpriteBatch {
+    vertex_array: VertexArray,
+    vertex_buffer: DynamicBufferIndex,
+    texture_coordinates_buffer: DynamicBufferIndex,
+    color_buffer: DynamicBufferIndex,
+    vertices: Vec<f32>,
+    texture_coordinates: Vec<f32>,
+    colors: Vec<f32>,
+}
+
+impl SpriteBatch {
+    /// Create new empty `SpriteBatch`.
+    pub fn new() -> SpriteBatch {
+        let mut vertex_array = VertexArray::new(0);
+
+        let vertex_buffer = vertex_array.add_dynamic_buffer(3, 0);
+        let texture_coordinates_buffer = vertex_array.add_dynamic_buffer(2, 1);
+        let color_buffer = vertex_array.add_dynamic_buffer(4, 2);
+
+        let vertex_count = INITIAL_QUAD_CAPACITY * QUAD_VERTICES.len();
+
+        SpriteBatch {
+            vertex_array,
+            vertex_buffer,
+            texture_coordinates_buffer,
+            color_buffer,
+            vertices: Vec::with_capacity(vertex_count * 3),
+            texture_coordinates: Vec::with_capacity(vertex_count * 2),
+            colors: Vec::with_capacity(vertex_count * 4),
+        }
+    }
+
+    /// Add quad to the batch.
+    ///
+    /// # Arguments
+    /// * `model_matrix` - Transforms quad to world coordinates.
+    /// * `texture_rectangle` - Texture coordinates s and t will be multiplied with
+    ///   z and w components and then x and y components will be added.
+    /// * `color` - Vertex color of the quad.
+    pub fn add_quad(&mut self, model_matrix: &Matrix4<f32>, texture_rectangle: &Vector4<f32>, color: &Vector3<f32>) {
+        self.add_quad_rgba(model_matrix, texture_rectangle, &color.extend(1.0));
+    }
+
+    /// Add quad with RGBA vertex color to the batch. See method `add_quad`
+    /// for other arguments.
+    pub fn add_quad_rgba(&mut self, model_matrix: &Matrix4<f32>, texture_rectangle: &Vector4<f32>, color: &Vector4<f32>) {
+        for (&(x, y), &(s, t)) in QUAD_VERTICES.iter().zip(QUAD_TEXTURE_COORDINATES.iter()) {
+            let vertex = model_matrix * Vector4::new(x, y, 0.0, 1.0);
+            self.vertices.extend_from_slice(&[vertex.x, vertex.y, vertex.z]);
+
+            self.texture_coordinates.push(s * texture_rectangle.z + texture_rectangle.x);
+            self.texture_coordinates.push(t * texture_rectangle.w + texture_rectangle.y);
+
+            self.colors.extend_from_slice(&[color.x, color.y, color.z, color.w]);
+        }
+    }
+
+    /// Returns true if there is no quads in the batch.
+    pub fn is_empty(&self) -> bool {
+        self.vertices.is_empty()
+    }
+
+    /// Send quads to GPU, draw them and clear the batch. Remember to enable
+    /// correct shader and texture before calling this method.
+    pub fn draw(&mut self) {
+        if self.is_empty() {
+            return;
+        }
+
+        self.vertex_array.update_dynamic_buffer(self.vertex_buffer, &self.vertices);
+        self.vertex_array.update_dynamic_buffer(self.texture_coordinates_buffer, &self.texture_coordinates);
+        self.vertex_array.update_dynamic_buffer(self.color_buffer, &self.colors);
+
+        self.vertex_array.draw();
+
+        self.vertices.clear();
+        self.texture_coordinates.clear();
+        self.colors.clear();
+    }
+}
+
+/// Line segment count of circles drawn with `LineBatch`.
+const CIRCLE_SEGMENT_COUNT: usize = 32;
+
+/// Collects lines and draws them with one draw call.
+///
+/// # Vertex attribute indexes
+/// * Vertex position, index 0
+/// * Color, index 2
+pub struct LineBatch {
+    vertex_array: VertexArray,
+    vertex_buffer: DynamicBufferIndex,
+    color_buffer: DynamicBufferIndex,
+    vertices: Vec<f32>,
+    colors: Vec<f32>,
+}
+
+impl LineBatch {
+    /// Create new empty `LineBatch`.
+    pub fn new() -> LineBatch {
+        let mut vertex_array = VertexArray::new(0);
+
+        let vertex_buffer = vertex_array.add_dynamic_buffer(3, 0);
+        let color_buffer = vertex_array.add_dynamic_buffer(4, 2);
+
+        LineBatch {
+            vertex_array,
+            vertex_buffer,
+            color_buffer,
+            vertices: Vec::new(),
+            colors: Vec::new(),
+        }
+    }
+
+    /// Add line from `start` to `end` in world coordinates.
+    pub fn add_line(&mut self, start: Vector2<f32>, end: Vector2<f32>, color: &Vector4<f32>) {
+        self.vertices.extend_from_slice(&[start.x, start.y, 0.0, end.x, end.y, 0.0]);
+        self.colors.extend_from_slice(&[color.x, color.y, color.z, color.w, color.x, color.y, color.z, color.w]);
+    }
+
+    /// Add circle outline.
+    pub fn add_circle(&mut self, center: Vector2<f32>, radius: f32, color: &Vector4<f32>) {
+        let point = |i: usize| {
+            let angle = i as f32 / CIRCLE_SEGMENT_COUNT as f32 * 2.0 * consts::PI;
+            center + Vector2::new(angle.cos(), angle.sin()) * radius
+        };
+
+        for i in 0..CIRCLE_SEGMENT_COUNT {
+            self.add_line(point(i), point(i + 1), color);
+        }
+    }
+
+    /// Add rectangle outline.
+    pub fn add_rectangle(&mut self, rectangle: &Rectangle, color: &Vector4<f32>) {
+        let left_top = rectangle.left_top_corner.to_vec();
+        let right_bottom = rectangle.right_bottom_corner.to_vec();
+        let right_top = Vector2::new(right_bottom.x, left_top.y);
+        let left_bottom = Vector2::new(left_top.x, right_bottom.y);
+
+        self.add_line(left_top, right_top, color);
+        self.add_line(right_top, right_bottom, color);
+        self.add_line(right_bottom, left_bottom, color);
+        self.add_line(left_bottom, left_top, color);
+    }
+
+    /// Send lines to GPU, draw them and clear the batch. Remember to enable
+    /// correct shader before calling this method.
+    pub fn draw(&mut self) {
+        if self.vertices.is_empty() {
+            return;
+        }
+
+        self.vertex_array.update_dynamic_buffer(self.vertex_buffer, &self.vertices);
+        self.vertex_array.update_dynamic_buffer(self.color_buffer, &self.colors);
+
+        self.vertex_array.draw_lines();
+
+        self.vertices.clear();
+        self.colors.clear();
+    }
+}
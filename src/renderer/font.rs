@@ -0,0 +1,350 @@
+/*
+src/renderer/font.rs, 2017-09-26
+
+Copyright (c) 2017 Juuso Tuononen
+
+This file is licensed under
+
+Apache License, Version 2.0
+
+or
+
+MIT License
+*/
+
+//! Bitmap fonts.
+//!
+//! Fonts are loaded from BMFont text format files (.fnt). Font file
+//! describes location of every glyph in the font texture, glyph
+//! offsets, widths and kerning pairs. Only fonts with one texture page
+//! are supported.
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+use gl::texture::Texture;
+
+use cgmath::Vector4;
+
+use paths::GamePaths;
+
+use renderer::texture::Textures;
+
+/// Available fonts.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Fonts {
+    /// Font with variable glyph widths and kerning.
+    Default,
+    /// Every glyph has the same width. Useful for numbers which change often.
+    Monospace,
+    FontCount,
+}
+
+/// All fonts in the same order as in the `Fonts` enum.
+const ALL_FONTS: &'static [Fonts] = &[
+    Fonts::Default,
+    Fonts::Monospace,
+];
+
+impl Fonts {
+    /// Font file of font.
+    fn file(self) -> &'static str {
+        match self {
+            Fonts::Default => "images/font.fnt",
+            Fonts::Monospace => "images/font-monospace.fnt",
+            Fonts::FontCount => panic!("FontCount is not a font"),
+        }
+    }
+
+    /// Load all fonts and their textures.
+    ///
+    /// # Panics
+    /// * If loading of some font file or font texture fails.
+    pub fn load_all(paths: &GamePaths) -> (Vec<Font>, Vec<Texture>) {
+        let mut fonts = Vec::new();
+        let mut textures = Vec::new();
+
+        for &font in ALL_FONTS {
+            let file_path = paths.data_file(font.file());
+
+            let font = match Font::load(&file_path) {
+                Ok(font) => font,
+                Err(error) => panic!("font file {} loading failed: {}", file_path, error),
+            };
+
+            let texture_path = match Path::new(&file_path).parent() {
+                Some(directory) => directory.join(font.page_file()),
+                None => Path::new(font.page_file()).to_path_buf(),
+            };
+
+            textures.push(Textures::load(&texture_path.to_string_lossy()));
+            fonts.push(font);
+        }
+
+        (fonts, textures)
+    }
+}
+
+/// Glyph information from font file. Values are in pixels.
+#[derive(Copy, Clone, Debug)]
+pub struct Glyph {
+    /// Glyph's location in the font texture.
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+    /// Offset from cursor position to the glyph's left side.
+    pub x_offset: i32,
+    /// Offset from top of the line to the glyph's top side.
+    pub y_offset: i32,
+    /// How much cursor moves after this glyph.
+    pub x_advance: i32,
+}
+
+/// Glyph positioned by method `Font::layout`.
+pub struct PositionedGlyph {
+    /// Glyph's left side relative to the left side of the text in pixels.
+    pub x: f32,
+    /// Glyph's top side relative to top of the line in pixels. Positive values are
+    /// below top of the line.
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+    /// See `SpriteBatch::add_quad` for texture rectangle's format.
+    pub texture_rectangle: Vector4<f32>,
+}
+
+/// Bitmap font.
+pub struct Font {
+    line_height: i32,
+    texture_width: i32,
+    texture_height: i32,
+    page_file: String,
+    glyphs: HashMap<char, Glyph>,
+    kernings: HashMap<(char, char), i32>,
+}
+
+impl Font {
+    /// Load font from BMFont text format file.
+    ///
+    /// Returns `Err(error_message)` if file reading or parsing fails.
+    pub fn load(file_path: &str) -> Result<Font, String> {
+        let mut text = String::new();
+
+        File::open(file_path)
+            .and_then(|mut file| file.read_to_string(&mut text))
+            .map_err(|error| error.to_string())?;
+
+        Font::parse(&text)
+    }
+
+    /// Parse BMFont text format.
+    ///
+    /// Returns `Err(error_message)` if some required value is missing or invalid.
+    pub fn parse(text: &str) -> Result<Font, String> {
+        let mut font = Font {
+            line_height: 0,
+            texture_width: 0,
+            texture_height: 0,
+            page_file: String::new(),
+            glyphs: HashMap::new(),
+            kernings: HashMap::new(),
+        };
+
+        for (line_number, line) in text.lines().enumerate() {
+            let (tag, values) = match parse_line(line) {
+                Some(line) => line,
+                None => continue,
+            };
+
+            let error = |message: String| format!("line {}: {}", line_number + 1, message);
+
+            match tag {
+                "common" => {
+                    font.line_height = values.integer("lineHeight").map_err(&error)?;
+                    font.texture_width = values.integer("scaleW").map_err(&error)?;
+                    font.texture_height = values.integer("scaleH").map_err(&error)?;
+
+                    if values.integer("pages").map_err(&error)? != 1 {
+                        return Err(error("only fonts with one page are supported".to_string()));
+                    }
+                },
+                "page" => {
+                    font.page_file = values.text("file").map_err(&error)?.to_string();
+                },
+                "char" => {
+                    let c = values.character("id").map_err(&error)?;
+
+                    let glyph = Glyph {
+                        x: values.integer("x").map_err(&error)?,
+                        y: values.integer("y").map_err(&error)?,
+                        width: values.integer("width").map_err(&error)?,
+                        height: values.integer("height").map_err(&error)?,
+                        x_offset: values.integer("xoffset").map_err(&error)?,
+                        y_offset: values.integer("yoffset").map_err(&error)?,
+                        x_advance: values.integer("xadvance").map_err(&error)?,
+                    };
+
+                    font.glyphs.insert(c, glyph);
+                },
+                "kerning" => {
+                    let first = values.character("first").map_err(&error)?;
+                    let second = values.character("second").map_err(&error)?;
+                    let amount = values.integer("amount").map_err(&error)?;
+
+                    font.kernings.insert((first, second), amount);
+                },
+                _ => (),
+            }
+        }
+
+        if font.line_height <= 0 || font.texture_width <= 0 || font.texture_height <= 0 {
+            return Err("line height or texture size is missing".to_string());
+        }
+
+        if font.page_file.is_empty() {
+            return Err("texture page file is missing".to_string());
+        }
+
+        Ok(font)
+    }
+
+    /// Height of a line in pixels.
+    pub fn line_height(&self) -> i32 {
+        self.line_height
+    }
+
+    /// Texture file name relative to the font file.
+    pub fn page_file(&self) -> &str {
+        &self.page_file
+    }
+
+    /// Get glyph of character. Character `?` is returned
+    /// for characters which are missing from the font.
+    pub fn glyph(&self, c: char) -> Option<&Glyph> {
+        self.glyphs.get(&c).or_else(|| self.glyphs.get(&'?'))
+    }
+
+    /// Kerning amount between two characters in pixels.
+    pub fn kerning(&self, first: char, second: char) -> i32 {
+        self.kernings.get(&(first, second)).cloned().unwrap_or(0)
+    }
+
+    /// Position glyphs of one line of text.
+    ///
+    /// Returns positioned glyphs and width of the text in pixels.
+    pub fn layout(&self, text: &str) -> (Vec<PositionedGlyph>, f32) {
+        let mut glyphs = Vec::with_capacity(text.len());
+        let mut cursor = 0;
+        let mut previous = None;
+
+        for c in text.chars() {
+            let glyph = match self.glyph(c) {
+                Some(glyph) => glyph,
+                None => continue,
+            };
+
+            if let Some(previous) = previous {
+                cursor += self.kerning(previous, c);
+            }
+
+            if glyph.width > 0 && glyph.height > 0 {
+                glyphs.push(PositionedGlyph {
+                    x: (cursor + glyph.x_offset) as f32,
+                    y: glyph.y_offset as f32,
+                    width: glyph.width as f32,
+                    height: glyph.height as f32,
+                    texture_rectangle: self.texture_rectangle(glyph),
+                });
+            }
+
+            cursor += glyph.x_advance;
+            previous = Some(c);
+        }
+
+        (glyphs, cursor as f32)
+    }
+
+    /// Texture rectangle of glyph.
+    ///
+    /// Shaders flip texture coordinate t, so rectangle's y is
+    /// moved to negative side of the t axis.
+    fn texture_rectangle(&self, glyph: &Glyph) -> Vector4<f32> {
+        let texture_width = self.texture_width as f32;
+        let texture_height = self.texture_height as f32;
+
+        let width = glyph.width as f32 / texture_width;
+        let height = glyph.height as f32 / texture_height;
+        let x = glyph.x as f32 / texture_width;
+        let y = glyph.y as f32 / texture_height;
+
+        Vector4::new(x, -(y + height), width, height)
+    }
+}
+
+/// Key and value pairs of one line of font file.
+struct LineValues<'a> {
+    values: HashMap<&'a str, &'a str>,
+}
+
+impl <'a> LineValues<'a> {
+    /// Get value as text.
+    fn text(&self, key: &str) -> Result<&'a str, String> {
+        self.values.get(key).cloned().ok_or_else(|| format!("value {} is missing", key))
+    }
+
+    /// Get value as integer.
+    fn integer(&self, key: &str) -> Result<i32, String> {
+        let text = self.text(key)?;
+        text.parse::<i32>().map_err(|_| format!("value {}={} is not an integer", key, text))
+    }
+
+    /// Get value as Unicode character.
+    fn character(&self, key: &str) -> Result<char, String> {
+        let code = self.integer(key)?;
+        ::std::char::from_u32(code as u32).ok_or_else(|| format!("value {}={} is not a character", key, code))
+    }
+}
+
+/// Split line to tag and key value pairs. Values can be
+/// in quotes and quoted values can contain spaces.
+///
+/// Returns `None` if line is empty.
+fn parse_line<'a>(line: &'a str) -> Option<(&'a str, LineValues<'a>)> {
+    let mut tokens = Vec::new();
+    let mut token_start = None;
+    let mut in_quotes = false;
+
+    for (i, c) in line.char_indices() {
+        match (c, token_start) {
+            ('"', _) => in_quotes = !in_quotes,
+            (c, Some(start)) if c.is_whitespace() && !in_quotes => {
+                tokens.push(&line[start..i]);
+                token_start = None;
+            },
+            (c, None) if !c.is_whitespace() => token_start = Some(i),
+            _ => (),
+        }
+    }
+
+    if let Some(start) = token_start {
+        tokens.push(&line[start..]);
+    }
+
+    let (tag, pairs) = match tokens.split_first() {
+        Some((tag, pairs)) => (*tag, pairs),
+        None => return None,
+    };
+
+    let values = pairs.iter().filter_map(|pair| {
+        let mut parts = pair.splitn(2, '=');
+        match (parts.next(), parts.next()) {
+            (Some(key), Some(value)) => Some((key, value.trim_matches('"'))),
+            _ => None,
+        }
+    }).collect();
+
+    Some((tag, LineValues { values }))
+}
@@ -16,6 +16,13 @@ MIT License
 
 mod texture;
 mod shader;
+mod batch;
+mod atlas;
+mod post_processing;
+pub mod font;
+
+pub use renderer::post_processing::PostProcessingEffect;
+pub use renderer::font::Fonts;
 
 use window::{Window, RenderingContext};
 
@@ -23,18 +30,26 @@ use cgmath::{Vector3, Matrix4, Point2, Vector4};
 use cgmath;
 use cgmath::prelude::*;
 
-use gl::buffer::*;
 use gl::texture::*;
 use gl::gl_raw;
 use gl;
 
-use renderer::texture::Textures;
+use renderer::texture::{Textures, Sprites, animation_frame_image_index};
 use renderer::shader::*;
+use renderer::batch::{SpriteBatch, LineBatch};
+use renderer::atlas::TextureAtlas;
+use renderer::post_processing::PostProcessor;
+use renderer::font::Font;
+
+use logic::{Logic, LaserColor, DebugInfo};
+use logic::animation::AnimationPlayer;
+use logic::particle::{ParticleEmitter, Emitters, BlendMode};
+use logic::background::BackgroundLayer;
 
-use logic::{Logic, LaserColor};
+use gui::{GUI, GUIComponentReferences};
+use gui::components::{GUIText, GUIComponentAlignment};
 
-use gui::GUI;
-use gui::components::GUIText;
+use paths::GamePaths;
 
 pub const DEFAULT_SCREEN_WIDTH: i32 = 640;
 pub const DEFAULT_SCREEN_HEIGHT: i32 = 480;
@@ -42,7 +57,14 @@ pub const DEFAULT_SCREEN_HEIGHT: i32 = 480;
 const BLUE_COLOR: Vector3<f32> = Vector3 { x: 0.0, y: 0.0, z: 1.0 };
 const RED_COLOR: Vector3<f32> = Vector3 { x: 1.0, y: 0.0, z: 0.0 };
 const GREEN_LASER_COLOR: Vector3<f32> = Vector3 { x: 0.0, y: 0.5, z: 0.0 };
-const PARTICLE_COLOR: Vector3<f32> = Vector3 { x: 0.3, y: 0.3, z: 0.3 };
+const WHITE_COLOR: Vector3<f32> = Vector3 { x: 1.0, y: 1.0, z: 1.0 };
+
+const DEBUG_INNER_CIRCLE_COLOR: Vector4<f32> = Vector4 { x: 0.0, y: 1.0, z: 0.0, w: 1.0 };
+const DEBUG_OUTER_CIRCLE_COLOR: Vector4<f32> = Vector4 { x: 1.0, y: 1.0, z: 0.0, w: 0.6 };
+const DEBUG_AREA_COLOR: Vector4<f32> = Vector4 { x: 0.0, y: 1.0, z: 1.0, w: 0.6 };
+
+/// Texture rectangle for rendering the whole texture.
+const FULL_TEXTURE: Vector4<f32> = Vector4 { x: 0.0, y: 0.0, z: 1.0, w: 1.0 };
 
 // FIXME: Changing this value makes GUI element positioning
 //        and object movement limits not match screen size.
@@ -52,6 +74,12 @@ pub const SCREEN_TOP_Y_VALUE_IN_WORLD_COORDINATES: f32 = 4.5;
 pub trait ModelMatrix {
     /// Get model matrix.
     fn model_matrix(&self) -> &Matrix4<f32>;
+
+    /// Get model matrix interpolated between the previous and the current
+    /// logic update. Default implementation returns current model matrix.
+    fn interpolated_model_matrix(&self, _interpolation_factor: f32) -> Matrix4<f32> {
+        *self.model_matrix()
+    }
 }
 
 /// Color for rendering.
@@ -60,42 +88,56 @@ pub trait Color {
     fn color(&self) -> &Vector3<f32>;
 }
 
-/// Render tile from tile map.
-pub trait TileLocationInfo {
-    /// Tile's location information.
-    ///
-    /// # Vector3 components
-    /// vector[0]: movement in x direction.
-    /// vector[1]: movement in y direction.
-    /// vector[2]: scaling factor for texture coordinates.
-    ///
-    /// Shader will multiply texture coordinates with scaling factor
-    /// and then add x and y movement to multiplied texture coordinates.
-    ///
-    /// For tile rendering, scaling factor should be less than 1.0.
-    /// In practice, the scaling factor will make square represented by texture coordinates
-    /// smaller, positioned at lower left corner of the texture. Then with x and y movement
-    /// values you can move that square to a specific location on a tile map.
-    fn tile_info(&self) -> &Vector3<f32>;
+/// Shader and texture of current `SpriteBatch`.
+#[derive(Copy, Clone, PartialEq)]
+enum BatchState {
+    Texture(Textures),
+    /// Texture of font.
+    Font(Fonts),
+    Color,
 }
 
 /// OpenGL 3.0 and OpenGL ES 2.0 renderer.
 ///
 /// When compiling with feature "gles" you must only load
 /// OpenGL ES 2.0 compatible shaders.
+///
+/// Game objects and GUI components are rendered with `SpriteBatch`. Batch
+/// will be drawn when shader or texture changes, so objects are
+/// drawn in the same order as they are added to the batch.
 pub struct OpenGLRenderer {
     textures: [Texture; Textures::TextureCount as usize],
+    sprite_atlas: TextureAtlas,
+    fonts: Vec<Font>,
+    /// Font textures in the same order as `fonts`.
+    font_textures: Vec<Texture>,
     texture_shader: TextureShader,
     color_shader: ColorShader,
-    tile_map_shader: TileMapShader,
-    /// Vertex and texture coordinates of square.
-    square: VertexArray,
+    batch: SpriteBatch,
+    /// Lines of the debug overlay.
+    line_batch: LineBatch,
+    batch_state: Option<BatchState>,
+    /// Blend mode of current batch.
+    blend_mode: BlendMode,
+    /// Hit flash amount of current batch.
+    flash: f32,
     projection_matrix: Matrix4<f32>,
+    /// Camera offset of game scene or transition offset of GUI layer.
+    camera_matrix: Matrix4<f32>,
+    /// Opacity of currently rendered GUI layer. Applies also to color
+    /// rectangles which are rendered outside the GUI, so keep this at 1.0
+    /// when GUI is not rendered.
+    gui_alpha: f32,
     /// Go back to world coordinates from normalized device coordinates.
     inverse_projection_matrix: Matrix4<f32>,
     screen_width: i32,
     screen_height: i32,
     half_screen_width_world_coordinates: f32,
+    /// Interpolation factor for model matrices of the current frame.
+    interpolation_factor: f32,
+    post_processor: PostProcessor,
+    /// True if current frame is rendered with post-processing.
+    post_processing_active: bool,
 }
 
 /// Interface for renderers.
@@ -106,9 +148,17 @@ pub trait Renderer {
     /// Start rendering new frame. Call this first.
     fn start(&mut self);
     /// Render game logic.
-    fn render(&mut self, &Logic, only_background: bool);
+    ///
+    /// Argument `interpolation_factor` is at range [0.0, 1.0] and tells how much
+    /// time has elapsed from the previous logic update relative to time between logic updates.
+    /// Game objects are rendered at interpolated positions between previous and
+    /// current logic update.
+    fn render(&mut self, &Logic, only_background: bool, interpolation_factor: f32);
     /// Render GUI.
     fn render_gui(&mut self, &GUI);
+    /// Render collision circles, movement areas, object counts
+    /// and timers on top of the game.
+    fn render_debug_overlay(&mut self, debug_info: &DebugInfo);
     /// End rendering of new frame. Call this last.
     fn end<W: Window>(&mut self, &mut W);
     /// Converts screen coordinates to world coordinates.
@@ -128,72 +178,98 @@ pub trait Renderer {
 
     /// Get current screen width in pixels
     fn screen_width_pixels(&self) -> i32;
+
+    /// Enable or disable post-processing effect.
+    fn set_post_processing_effect(&mut self, effect: PostProcessingEffect, enabled: bool);
 }
 
 impl Renderer for OpenGLRenderer {
 
-    /// Clears OpenGL color buffer.
+    /// Clears OpenGL color buffer. If post-processing is enabled,
+    /// rendering is done to post-processing framebuffer.
     fn start(&mut self) {
+        self.post_processing_active = self.post_processor.begin();
+
         unsafe {
             gl_raw::Clear(gl_raw::COLOR_BUFFER_BIT);
         }
     }
 
-    fn render(&mut self, logic: &Logic, only_background: bool) {
-        self.texture_shader.use_program();
+    /// Game scene is moved with logic's camera offset.
+    fn render(&mut self, logic: &Logic, only_background: bool, interpolation_factor: f32) {
+        self.interpolation_factor = interpolation_factor;
 
-        self.textures[Textures::Background as usize].bind();
-        for background in logic.get_moving_background().get_backgrounds() {
-            self.render_rectangle_with_texture(background);
+        let camera_offset = logic.camera_offset();
+        self.set_camera_matrix(Matrix4::from_translation(Vector3::new(-camera_offset.x, -camera_offset.y, 0.0)));
+
+        for layer in logic.get_moving_background().layers() {
+            self.render_background_layer(layer);
         }
 
         if only_background {
+            self.set_camera_matrix(Matrix4::identity());
+            self.flush_batch();
+
+            if self.post_processing_active {
+                self.post_processor.render_bloom();
+            }
+
             return;
         }
 
+        let particle_system = logic.get_particle_system();
+
+        self.render_particles(particle_system.emitter(Emitters::PlayerEngineTrail));
+        self.render_particles(particle_system.emitter(Emitters::EnemyEngineTrail));
+
         if logic.get_player().visible() {
-            self.textures[Textures::Player as usize].bind();
-            self.render_rectangle_with_texture(logic.get_player());
+            self.set_flash(logic.get_player().hit_flash());
+            self.render_sprite(Sprites::Player, logic.get_player());
+            self.set_flash(0.0);
         }
 
         if logic.get_enemy().visible() {
+            // Thruster is at the right side of the enemy.
+            let thruster_matrix = logic.get_enemy().interpolated_model_matrix(self.interpolation_factor) *
+                Matrix4::from_translation(Vector3::new(0.7, 0.0, 0.0)) *
+                Matrix4::from_scale(0.5);
+            self.render_animation_with_matrix(logic.get_enemy().get_thruster_animation(), &thruster_matrix);
+
+            self.set_flash(logic.get_enemy().hit_flash());
+
             if logic.get_enemy().get_laser_cannon_top().visible() {
-                self.textures[Textures::EnemyWithShield as usize].bind();
-                self.render_rectangle_with_texture(logic.get_enemy());
+                self.render_sprite(Sprites::EnemyWithShield, logic.get_enemy());
+                self.set_flash(0.0);
 
-                if logic.get_enemy().get_laser_cannon_top().red_light() {
-                    self.textures[Textures::LaserCannonRed as usize].bind();
+                let sprite = if logic.get_enemy().get_laser_cannon_top().red_light() {
+                    Sprites::LaserCannonRed
                 } else {
-                    self.textures[Textures::LaserCannonGreen as usize].bind();
-                }
-                self.render_rectangle_with_texture(logic.get_enemy().get_laser_cannon_top());
+                    Sprites::LaserCannonGreen
+                };
+                self.render_sprite(sprite, logic.get_enemy().get_laser_cannon_top());
             } else {
-                self.textures[Textures::Enemy as usize].bind();
-                self.render_rectangle_with_texture(logic.get_enemy());
+                self.render_sprite(Sprites::Enemy, logic.get_enemy());
+                self.set_flash(0.0);
             }
 
             if logic.get_enemy().get_laser_cannon_bottom().visible() {
-                if logic.get_enemy().get_laser_cannon_bottom().red_light() {
-                    self.textures[Textures::LaserCannonRed as usize].bind();
+                let sprite = if logic.get_enemy().get_laser_cannon_bottom().red_light() {
+                    Sprites::LaserCannonRed
                 } else {
-                    self.textures[Textures::LaserCannonGreen as usize].bind();
-                }
-                self.render_rectangle_with_texture(logic.get_enemy().get_laser_cannon_bottom());
+                    Sprites::LaserCannonGreen
+                };
+                self.render_sprite(sprite, logic.get_enemy().get_laser_cannon_bottom());
             }
 
             if logic.get_enemy().get_shield().visible() {
-                self.textures[Textures::Shield as usize].bind();
-                self.render_rectangle_with_texture(logic.get_enemy().get_shield());
+                self.render_animation(logic.get_enemy().get_shield().animation(), logic.get_enemy().get_shield());
             }
         }
 
         for laser_bomb in logic.get_enemy().get_laser_bombs() {
-            self.textures[Textures::LaserBomb as usize].bind();
-            self.render_rectangle_with_texture(laser_bomb);
+            self.render_sprite(Sprites::LaserBomb, laser_bomb);
         }
 
-        self.color_shader.use_program();
-
         for laser in logic.get_player().get_lasers() {
             self.render_color_rectangle_with_color(laser, &GREEN_LASER_COLOR);
         }
@@ -206,54 +282,87 @@ impl Renderer for OpenGLRenderer {
             }
         }
 
+        self.render_particles(particle_system.emitter(Emitters::LaserImpact));
+        self.render_particles(particle_system.emitter(Emitters::ShieldHit));
+        self.render_particles(particle_system.emitter(Emitters::BombDetonation));
+
         if logic.get_explosion().visible() {
-            for particle in logic.get_explosion().particles() {
-                self.render_color_rectangle_with_color(particle, &PARTICLE_COLOR);
-            }
+            self.render_animation(logic.get_explosion().animation(), logic.get_explosion());
+        }
+
+        self.render_particles(particle_system.emitter(Emitters::ExplosionSparks));
+
+        self.set_camera_matrix(Matrix4::identity());
+        self.flush_batch();
+
+        if self.post_processing_active {
+            self.post_processor.render_bloom();
         }
     }
 
     fn render_gui(&mut self, gui: &GUI) {
-        let components = gui.components();
-
-        self.color_shader.use_program();
+        self.interpolation_factor = 1.0;
 
-        for button in components.buttons() {
-            self.render_color_rectangle(button);
+        for layer in gui.visible_layers() {
+            self.gui_alpha = layer.alpha;
+            self.set_camera_matrix(Matrix4::from_translation(Vector3::new(layer.x_offset, 0.0, 0.0)));
+            self.render_gui_components(&layer.components);
         }
 
-        for health_bar in components.health_bars() {
-            self.render_color_rectangle(health_bar);
+        self.gui_alpha = 1.0;
+        self.set_camera_matrix(Matrix4::identity());
 
-            for border in health_bar.borders().into_iter() {
-                self.render_color_rectangle_with_color(*border, health_bar.border_color());
-            }
+        if gui.get_gui_fps_counter().show_fps() {
+            self.render_text(gui.get_gui_fps_counter().text());
         }
 
-        self.tile_map_shader.use_program();
-        self.textures[Textures::Font as usize].bind();
+        self.flush_batch();
+    }
 
-        for text in components.texts() {
-            self.render_text(text);
+    /// Circles and areas are drawn at positions from the latest logic
+    /// update without interpolation, so they show what collision detection uses.
+    fn render_debug_overlay(&mut self, debug_info: &DebugInfo) {
+        for data in &debug_info.objects {
+            self.line_batch.add_circle(data.position, data.radius_outer, &DEBUG_OUTER_CIRCLE_COLOR);
+            self.line_batch.add_circle(data.position, data.radius_inner, &DEBUG_INNER_CIRCLE_COLOR);
         }
 
-        for button in components.buttons() {
-            self.render_text(button.get_text());
+        for area in &debug_info.areas {
+            self.line_batch.add_rectangle(area, &DEBUG_AREA_COLOR);
         }
 
-        if gui.get_gui_fps_counter().show_fps() {
-            for text in gui.get_gui_fps_counter().texts().into_iter() {
-                self.render_text(text);
-            }
+        self.color_shader.use_program();
+        self.color_shader.send_uniform_data(&self.projection_matrix);
+        self.line_batch.draw();
+
+        // Text lines to the left side of the screen.
+
+        let x = -self.half_screen_width_world_coordinates + 0.3;
+        let mut y = SCREEN_TOP_Y_VALUE_IN_WORLD_COORDINATES - 1.9;
+
+        let counts = debug_info.object_counts.iter().map(|&(name, count)| format!("{} {}", name, count));
+        let timers = debug_info.timers.iter().map(|&(name, milliseconds)| format!("{} {} ms", name, milliseconds));
+
+        for line in counts.chain(timers) {
+            let mut text = GUIText::new_with_alignment(x, y, &line, GUIComponentAlignment::Left);
+            text.set_font(Fonts::Monospace);
+            self.render_text(&text);
+            y -= 0.45;
         }
+
+        self.flush_batch();
     }
 
-    /// Swap color buffers and check OpenGL errors.
+    /// Apply post-processing, swap color buffers and check OpenGL errors.
     fn end<W: Window>(&mut self, window: &mut W) {
+        if self.post_processing_active {
+            self.post_processor.end();
+        }
+
         window.swap_buffers().expect("couldn't swap rendering buffers");
 
         while let Err(error) = gl::GLError::get_error() {
-            println!("OpenGL error: {:?}", error);
+            error!("OpenGL error: {:?}", error);
         }
     }
 
@@ -280,6 +389,8 @@ impl Renderer for OpenGLRenderer {
         self.screen_width = new_width_in_pixels;
         self.screen_height = new_height_in_pixels;
 
+        self.post_processor.update_screen_size(new_width_in_pixels as u32, new_height_in_pixels as u32);
+
         self.update_projection_matrix();
     }
 
@@ -290,38 +401,60 @@ impl Renderer for OpenGLRenderer {
     fn screen_width_pixels(&self) -> i32 {
         self.screen_width
     }
+
+    fn set_post_processing_effect(&mut self, effect: PostProcessingEffect, enabled: bool) {
+        self.post_processor.set_effect(effect, enabled);
+    }
 }
 
 impl OpenGLRenderer {
-    /// Creates new OpenGLRenderer.
-    pub fn new<W: Window>(window: &W) -> OpenGLRenderer {
+    /// Creates new OpenGLRenderer. Textures are loaded from the game data directory.
+    ///
+    /// Argument `screen_size` is window's size in pixels.
+    pub fn new<W: Window>(window: &W, paths: &GamePaths, (screen_width, screen_height): (i32, i32)) -> OpenGLRenderer {
         gl_raw::load_with(|name| window.gl_get_proc_address(name));
 
         unsafe {
             gl_raw::ClearColor(0.0,0.0,0.0,1.0);
+            gl_raw::Enable(gl_raw::BLEND);
+            gl_raw::BlendFunc(gl_raw::SRC_ALPHA, gl_raw::ONE_MINUS_SRC_ALPHA);
         }
 
-        println!("OpenGL context information:");
-        println!("  Version:  {:?}", gl::get_version_string());
-        println!("  Vendor:   {:?}", gl::get_vendor_string());
-        println!("  Renderer: {:?}", gl::get_renderer_string());
+        info!("OpenGL context information:");
+        info!("  Version:  {:?}", gl::get_version_string());
+        info!("  Vendor:   {:?}", gl::get_vendor_string());
+        info!("  Renderer: {:?}", gl::get_renderer_string());
+
+        let (textures, sprite_atlas) = Textures::load_all(paths);
+        let (fonts, font_textures) = Fonts::load_all(paths);
 
         let mut renderer = OpenGLRenderer {
             texture_shader: TextureShader::new(),
             color_shader: ColorShader::new(),
-            tile_map_shader: TileMapShader::new(),
-            textures: Textures::load_all(),
-            square: create_square(),
+            textures,
+            sprite_atlas,
+            fonts,
+            font_textures,
+            batch: SpriteBatch::new(),
+            line_batch: LineBatch::new(),
+            batch_state: None,
+            blend_mode: BlendMode::Alpha,
+            flash: 0.0,
             projection_matrix: Matrix4::identity(),
+            camera_matrix: Matrix4::identity(),
+            gui_alpha: 1.0,
             inverse_projection_matrix: Matrix4::identity(),
-            screen_width: DEFAULT_SCREEN_WIDTH,
-            screen_height: DEFAULT_SCREEN_HEIGHT,
+            screen_width,
+            screen_height,
             half_screen_width_world_coordinates: 1.0,
+            interpolation_factor: 1.0,
+            post_processor: PostProcessor::new(screen_width as u32, screen_height as u32),
+            post_processing_active: false,
         };
 
         // Update fields projection_matrix, inverse_projection_matrix
         // and half_screen_width_world_coordinates to have correct value.
-        renderer.update_screen_size(DEFAULT_SCREEN_WIDTH, DEFAULT_SCREEN_HEIGHT);
+        renderer.update_screen_size(screen_width, screen_height);
 
         renderer
     }
@@ -338,76 +471,246 @@ impl OpenGLRenderer {
         match self.projection_matrix.inverse_transform() {
             Some(matrix) => self.inverse_projection_matrix = matrix,
             None => {
-                println!("Calculating inverse projection matrix failed");
+                error!("Calculating inverse projection matrix failed");
                 self.inverse_projection_matrix = Matrix4::identity();
             }
         };
     }
 
-    /// Render `GUIText`. Bind correct texture before calling this method.
-    fn render_text(&mut self, text: &GUIText) {
-        for tile in text.get_tiles() {
-            self.render_tile(tile);
+    /// Draw current batch if argument `state` is different
+    /// than current batch state.
+    fn set_batch_state(&mut self, state: BatchState) {
+        if self.batch_state != Some(state) {
+            self.flush_batch();
+            self.batch_state = Some(state);
         }
     }
 
-    /// Render tile. Bind correct texture before calling this method.
-    fn render_tile<T: ModelMatrix + TileLocationInfo>(&mut self, tile: &T) {
-        self.tile_map_shader.send_uniform_data(tile.model_matrix(), &self.projection_matrix, tile.tile_info());
-        self.square.draw();
+    /// Draw current batch if argument `flash` is different
+    /// than flash amount of the current batch.
+    fn set_flash(&mut self, flash: f32) {
+        if self.flash != flash {
+            self.flush_batch();
+            self.flash = flash;
+        }
+    }
+
+    /// Draw current batch and change camera matrix.
+    fn set_camera_matrix(&mut self, camera_matrix: Matrix4<f32>) {
+        self.flush_batch();
+        self.camera_matrix = camera_matrix;
+    }
+
+    /// Draw current batch if argument `blend_mode` is different
+    /// than current blend mode.
+    fn set_blend_mode(&mut self, blend_mode: BlendMode) {
+        if self.blend_mode != blend_mode {
+            self.flush_batch();
+            self.blend_mode = blend_mode;
+        }
     }
 
-    /// Render rectangle with object specified color.
+    /// Enable shader, texture and blend mode of current batch state and draw current batch.
+    fn flush_batch(&mut self) {
+        if self.batch.is_empty() {
+            return;
+        }
+
+        unsafe {
+            match self.blend_mode {
+                BlendMode::Alpha => gl_raw::BlendFunc(gl_raw::SRC_ALPHA, gl_raw::ONE_MINUS_SRC_ALPHA),
+                BlendMode::Additive => gl_raw::BlendFunc(gl_raw::SRC_ALPHA, gl_raw::ONE),
+            }
+        }
+
+        let projection_matrix = self.projection_matrix * self.camera_matrix;
+
+        match self.batch_state {
+            Some(BatchState::Texture(texture)) => {
+                self.texture_shader.use_program();
+                self.texture_shader.send_uniform_data(&projection_matrix, self.flash);
+                self.textures[texture as usize].bind();
+            },
+            Some(BatchState::Font(font)) => {
+                self.texture_shader.use_program();
+                self.texture_shader.send_uniform_data(&projection_matrix, self.flash);
+                self.font_textures[font as usize].bind();
+            },
+            Some(BatchState::Color) => {
+                self.color_shader.use_program();
+                self.color_shader.send_uniform_data(&projection_matrix);
+            },
+            None => (),
+        }
+
+        self.batch.draw();
+    }
+
+    /// Render components of one `GUILayer`.
+    fn render_gui_components(&mut self, components: &GUIComponentReferences) {
+        for rectangle in components.rectangles() {
+            self.render_color_rectangle(rectangle);
+        }
+
+        for button in components.buttons() {
+            self.render_color_rectangle(button);
+        }
+
+        for health_bar in components.health_bars() {
+            self.render_color_rectangle(health_bar);
+
+            for border in health_bar.borders().into_iter() {
+                self.render_color_rectangle_with_color(*border, health_bar.border_color());
+            }
+        }
+
+        for slider in components.sliders() {
+            for rectangle in slider.rectangles() {
+                self.render_color_rectangle(rectangle);
+            }
+        }
+
+        for checkbox in components.checkboxes() {
+            for rectangle in checkbox.rectangles() {
+                self.render_color_rectangle(rectangle);
+            }
+        }
+
+        for dropdown in components.dropdowns() {
+            self.render_color_rectangle(dropdown.background());
+        }
+
+        for text in components.texts() {
+            self.render_text(text);
+        }
+
+        for dropdown in components.dropdowns() {
+            for text in dropdown.texts() {
+                self.render_text(text);
+            }
+        }
+
+        for button in components.buttons() {
+            self.render_text(button.get_text());
+        }
+    }
+
+    /// Render `GUIText` with text's font, font size and color.
+    /// Text's alpha is multiplied with field `gui_alpha`.
+    ///
+    /// Font size is line height in world coordinates, so
+    /// font's pixel units are scaled with `font_size/line_height`.
+    fn render_text(&mut self, text: &GUIText) {
+        let (glyphs, width) = self.fonts[text.font() as usize].layout(text.text());
+        let scale = text.font_size() / self.fonts[text.font() as usize].line_height() as f32;
+
+        let position = text.position();
+        let width = width * scale;
+
+        let left = match text.alignment() {
+            GUIComponentAlignment::Left => position.x,
+            GUIComponentAlignment::Center => position.x - width/2.0,
+            GUIComponentAlignment::Right => position.x - width,
+        };
+        let top = position.y + text.font_size()/2.0;
+
+        self.set_batch_state(BatchState::Font(text.font()));
+
+        let mut color = *text.color();
+        color.w *= self.gui_alpha;
+
+        for glyph in glyphs {
+            let glyph_width = glyph.width * scale;
+            let glyph_height = glyph.height * scale;
+
+            let mut model_matrix = Matrix4::from_nonuniform_scale(glyph_width, glyph_height, 1.0);
+            model_matrix.w.x = left + glyph.x * scale + glyph_width/2.0;
+            model_matrix.w.y = top - glyph.y * scale - glyph_height/2.0;
+
+            self.batch.add_quad_rgba(&model_matrix, &glyph.texture_rectangle, &color);
+        }
+    }
+
+    /// Render rectangle with object specified color and alpha from field `gui_alpha`.
     fn render_color_rectangle<T: ModelMatrix + Color>(&mut self, object: &T) {
-        self.color_shader.send_uniform_data(object.model_matrix(), &self.projection_matrix, object.color());
-        self.square.draw();
+        self.set_batch_state(BatchState::Color);
+        self.batch.add_quad_rgba(object.model_matrix(), &FULL_TEXTURE, &object.color().extend(self.gui_alpha));
     }
 
-    /// Render rectangle with color from argument.
+    /// Render rectangle with color from argument and alpha from
+    /// field `gui_alpha`. Model matrix will be interpolated.
     fn render_color_rectangle_with_color<T: ModelMatrix>(&mut self, object: &T, color: &Vector3<f32>) {
-        self.color_shader.send_uniform_data(object.model_matrix(), &self.projection_matrix, color);
-        self.square.draw();
+        let model_matrix = object.interpolated_model_matrix(self.interpolation_factor);
+        self.set_batch_state(BatchState::Color);
+        self.batch.add_quad_rgba(&model_matrix, &FULL_TEXTURE, &color.extend(self.gui_alpha));
     }
 
-    /// Render rectangle with texture. Bind correct texture before calling this method.
-    fn render_rectangle_with_texture<T: ModelMatrix>(&mut self, object: &T) {
-        self.texture_shader.send_uniform_data(object.model_matrix(), &self.projection_matrix);
-        self.square.draw();
+    /// Render rectangle with image from sprite texture atlas. Model matrix will be interpolated.
+    fn render_sprite<T: ModelMatrix>(&mut self, sprite: Sprites, object: &T) {
+        let model_matrix = object.interpolated_model_matrix(self.interpolation_factor);
+        self.set_batch_state(BatchState::Texture(Textures::Sprites));
+        self.batch.add_quad(&model_matrix, self.sprite_atlas.texture_rectangle(sprite as usize), &WHITE_COLOR);
     }
-}
 
-/// Create `VertexArray` with vertex and texture
-/// coordinate data of square.
-///
-/// Vertex data will be set to attribute index 0 and
-/// texture data will be set to attribute index 1 when rendering
-/// with this vertex array.
-fn create_square() -> VertexArray {
-    let mut square = VertexArray::new(6);
-
-    let size : f32 = 0.5;
-
-    let vertex_data: [f32; 18]  = [
-                size, -size, 0.0,
-                size, size, 0.0,
-                -size, size, 0.0,
-
-                size, -size, 0.0,
-                -size, size, 0.0,
-                -size, -size, 0.0,
-    ];
-    let texture_coordinates_data: [f32; 12]  = [
-                1.0, 0.0,
-                1.0, 1.0,
-                0.0, 1.0,
-
-                1.0, 0.0,
-                0.0, 1.0,
-                0.0, 0.0,
-    ];
-
-    square.add_static_buffer(&vertex_data, 3, 0);
-    square.add_static_buffer(&texture_coordinates_data, 2, 1);
-
-    square
-}
\ No newline at end of file
+    /// Render animation's current frame. Model matrix will be interpolated.
+    fn render_animation<T: ModelMatrix>(&mut self, animation: &AnimationPlayer, object: &T) {
+        let model_matrix = object.interpolated_model_matrix(self.interpolation_factor);
+        self.render_animation_with_matrix(animation, &model_matrix);
+    }
+
+    /// Render animation's current frame with model matrix from argument.
+    fn render_animation_with_matrix(&mut self, animation: &AnimationPlayer, model_matrix: &Matrix4<f32>) {
+        let image_index = animation_frame_image_index(animation.animation(), animation.frame());
+        self.set_batch_state(BatchState::Texture(Textures::Sprites));
+        self.batch.add_quad(model_matrix, self.sprite_atlas.texture_rectangle(image_index), &WHITE_COLOR);
+    }
+
+    /// Render background layer with layer's blend mode and
+    /// color. Model matrices will be interpolated.
+    fn render_background_layer(&mut self, layer: &BackgroundLayer) {
+        self.set_blend_mode(layer.blend_mode());
+
+        let batch_state = if layer.textured() {
+            BatchState::Texture(Textures::Background)
+        } else {
+            BatchState::Color
+        };
+
+        for background in layer.backgrounds() {
+            let model_matrix = background.interpolated_model_matrix(self.interpolation_factor);
+            self.set_batch_state(batch_state);
+            self.batch.add_quad_rgba(&model_matrix, &FULL_TEXTURE, layer.color());
+        }
+
+        self.set_blend_mode(BlendMode::Alpha);
+    }
+
+    /// Render particles of emitter with emitter's blend mode. Particles with an
+    /// animation are rendered with animation's current frame multiplied with
+    /// particle's color. Model matrices will be interpolated.
+    fn render_particles(&mut self, emitter: &ParticleEmitter) {
+        if emitter.particles().is_empty() {
+            return;
+        }
+
+        self.set_blend_mode(emitter.blend_mode());
+
+        for particle in emitter.particles() {
+            let model_matrix = particle.interpolated_model_matrix(self.interpolation_factor);
+
+            match particle.animation() {
+                Some(animation) => {
+                    let image_index = animation_frame_image_index(animation.animation(), animation.frame());
+                    self.set_batch_state(BatchState::Texture(Textures::Sprites));
+                    self.batch.add_quad_rgba(&model_matrix, self.sprite_atlas.texture_rectangle(image_index), particle.color());
+                },
+                None => {
+                    self.set_batch_state(BatchState::Color);
+                    self.batch.add_quad_rgba(&model_matrix, &FULL_TEXTURE, particle.color());
+                },
+            }
+        }
+
+        self.set_blend_mode(BlendMode::Alpha);
+    }
+}
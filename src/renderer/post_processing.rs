@@ -0,0 +1,269 @@
+/*
+src/renderer/post_processing.rs, 2017-09-22
+
+Copyright (c) 2017 Juuso Tuononen
+
+This file is licensed under
+
+Apache License, Version 2.0
+
+or
+
+MIT License
+*/
+
+//! Post-processing effects.
+//!
+//! When some effect is enabled, the scene is rendered to a framebuffer
+//! texture instead of the default framebuffer. Bright parts of the game
+//! scene are blurred to a half resolution bloom texture, and then scene
+//! and bloom textures are combined to the default framebuffer with
+//! the CRT and vignette effects.
+
+use gl::framebuffer::*;
+use gl::buffer::*;
+use gl::gl_raw;
+
+use cgmath::Vector2;
+
+use renderer::shader::{BloomBrightPassShader, BlurShader, CompositeShader};
+
+/// How much bloom texture is added to the scene.
+const BLOOM_INTENSITY: f32 = 1.2;
+
+/// Available post-processing effects.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum PostProcessingEffect {
+    /// Glow around lasers and other bright objects.
+    Bloom,
+    /// Scanlines and screen curvature.
+    Crt,
+    /// Darken screen edges.
+    Vignette,
+}
+
+/// Framebuffers for post-processing.
+struct RenderTargets {
+    scene: Framebuffer,
+    /// Half resolution framebuffers for blurring the bloom
+    /// texture to horizontal and vertical directions.
+    bloom: [Framebuffer; 2],
+}
+
+impl RenderTargets {
+    /// Create framebuffers for screen size `width` and `height`.
+    fn new(width: u32, height: u32) -> Result<RenderTargets, FramebufferError> {
+        let bloom_width = (width / 2).max(1);
+        let bloom_height = (height / 2).max(1);
+
+        Ok(RenderTargets {
+            scene: Framebuffer::new(width, height)?,
+            bloom: [Framebuffer::new(bloom_width, bloom_height)?, Framebuffer::new(bloom_width, bloom_height)?],
+        })
+    }
+}
+
+/// Post-processing effect chain for `OpenGLRenderer`.
+pub struct PostProcessor {
+    bloom: bool,
+    crt: bool,
+    vignette: bool,
+    /// Framebuffers are created when some effect is enabled.
+    targets: Option<RenderTargets>,
+    /// If framebuffer creation fails, it will not be tried again
+    /// until screen size changes.
+    targets_creation_failed: bool,
+    /// True if bloom texture contains bloom of the current frame.
+    bloom_ready: bool,
+    screen_width: u32,
+    screen_height: u32,
+    quad: VertexArray,
+    bright_pass_shader: BloomBrightPassShader,
+    blur_shader: BlurShader,
+    composite_shader: CompositeShader,
+}
+
+impl PostProcessor {
+    /// Create new `PostProcessor`. All effects are disabled by default.
+    ///
+    /// # Panics
+    /// If there is some error in creating the shaders.
+    pub fn new(screen_width: u32, screen_height: u32) -> PostProcessor {
+        // Full screen quad in normalized device coordinates.
+        let vertices = [
+            1.0, -1.0, 0.0,
+            1.0, 1.0, 0.0,
+            -1.0, 1.0, 0.0,
+
+            1.0, -1.0, 0.0,
+            -1.0, 1.0, 0.0,
+            -1.0, -1.0, 0.0,
+        ];
+
+        let texture_coordinates = [
+            1.0, 0.0,
+            1.0, 1.0,
+            0.0, 1.0,
+
+            1.0, 0.0,
+            0.0, 1.0,
+            0.0, 0.0,
+        ];
+
+        let mut quad = VertexArray::new(6);
+        quad.add_static_buffer(&vertices, 3, 0);
+        quad.add_static_buffer(&texture_coordinates, 2, 1);
+
+        PostProcessor {
+            bloom: false,
+            crt: false,
+            vignette: false,
+            targets: None,
+            targets_creation_failed: false,
+            bloom_ready: false,
+            screen_width,
+            screen_height,
+            quad,
+            bright_pass_shader: BloomBrightPassShader::new(),
+            blur_shader: BlurShader::new(),
+            composite_shader: CompositeShader::new(),
+        }
+    }
+
+    /// Enable or disable effect. Framebuffers are deleted
+    /// when all effects are disabled.
+    pub fn set_effect(&mut self, effect: PostProcessingEffect, enabled: bool) {
+        match effect {
+            PostProcessingEffect::Bloom => self.bloom = enabled,
+            PostProcessingEffect::Crt => self.crt = enabled,
+            PostProcessingEffect::Vignette => self.vignette = enabled,
+        }
+
+        if !self.enabled() {
+            self.targets = None;
+        }
+    }
+
+    /// Returns true if some effect is enabled.
+    pub fn enabled(&self) -> bool {
+        self.bloom || self.crt || self.vignette
+    }
+
+    /// Update screen size. Framebuffers will be recreated when
+    /// the next frame starts.
+    pub fn update_screen_size(&mut self, width: u32, height: u32) {
+        self.screen_width = width;
+        self.screen_height = height;
+        self.targets = None;
+        self.targets_creation_failed = false;
+    }
+
+    /// Start rendering new frame to the scene framebuffer.
+    ///
+    /// Returns false if post-processing is disabled or framebuffers
+    /// can't be created. Then rendering should be done to the default framebuffer.
+    pub fn begin(&mut self) -> bool {
+        self.bloom_ready = false;
+
+        if !self.enabled() || self.targets_creation_failed {
+            return false;
+        }
+
+        if self.targets.is_none() {
+            match RenderTargets::new(self.screen_width, self.screen_height) {
+                Ok(targets) => self.targets = Some(targets),
+                Err(error) => {
+                    error!("post-processing disabled, framebuffer creation failed: {:?}", error);
+                    self.targets_creation_failed = true;
+                    Framebuffer::bind_default(self.screen_width, self.screen_height);
+                    return false;
+                }
+            }
+        }
+
+        if let Some(ref mut targets) = self.targets {
+            targets.scene.bind();
+        }
+
+        true
+    }
+
+    /// Create bloom texture from current contents of the scene
+    /// framebuffer. Call this after rendering the game scene and before
+    /// rendering the GUI, so the GUI will not glow.
+    pub fn render_bloom(&mut self) {
+        if !self.bloom {
+            return;
+        }
+
+        let targets = match self.targets {
+            Some(ref mut targets) => targets,
+            None => return,
+        };
+
+        unsafe {
+            gl_raw::Disable(gl_raw::BLEND);
+        }
+
+        let (first, second) = targets.bloom.split_at_mut(1);
+        let (first, second) = (&mut first[0], &mut second[0]);
+
+        let texel_width = 1.0 / first.width() as f32;
+        let texel_height = 1.0 / first.height() as f32;
+
+        first.bind();
+        self.bright_pass_shader.use_program();
+        targets.scene.texture_mut().bind();
+        self.quad.draw();
+
+        second.bind();
+        self.blur_shader.use_program();
+        self.blur_shader.send_uniform_data(&Vector2::new(texel_width, 0.0));
+        first.texture_mut().bind();
+        self.quad.draw();
+
+        first.bind();
+        self.blur_shader.send_uniform_data(&Vector2::new(0.0, texel_height));
+        second.texture_mut().bind();
+        self.quad.draw();
+
+        targets.scene.bind();
+
+        unsafe {
+            gl_raw::Enable(gl_raw::BLEND);
+        }
+
+        self.bloom_ready = true;
+    }
+
+    /// Combine scene and bloom textures to the default framebuffer.
+    /// Call this only if method `begin` returned true.
+    pub fn end(&mut self) {
+        let targets = match self.targets {
+            Some(ref mut targets) => targets,
+            None => return,
+        };
+
+        Framebuffer::bind_default(self.screen_width, self.screen_height);
+
+        unsafe {
+            gl_raw::Disable(gl_raw::BLEND);
+        }
+
+        let bloom_intensity = if self.bloom && self.bloom_ready { BLOOM_INTENSITY } else { 0.0 };
+        let crt = if self.crt { 1.0 } else { 0.0 };
+        let vignette = if self.vignette { 1.0 } else { 0.0 };
+        let screen_size = Vector2::new(self.screen_width as f32, self.screen_height as f32);
+
+        self.composite_shader.use_program();
+        self.composite_shader.send_uniform_data(bloom_intensity, crt, vignette, &screen_size);
+
+        targets.scene.texture_mut().bind_to_unit(0);
+        targets.bloom[0].texture_mut().bind_to_unit(1);
+        self.quad.draw();
+
+        unsafe {
+            gl_raw::Enable(gl_raw::BLEND);
+        }
+    }
+}
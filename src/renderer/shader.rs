@@ -23,13 +23,13 @@ use std::ffi::CString;
 use gl::shader::*;
 use gl::uniform::*;
 
-use cgmath::{Matrix4, Vector3};
+use cgmath::{Matrix4, Vector2};
 
-/// Render with texture. Supports OpenGL 3.3 and OpenGL ES 2.0.
+/// Render sprite batches with texture. Supports OpenGL 3.3 and OpenGL ES 2.0.
 pub struct TextureShader {
     program: Program,
     projection: UniformMatrix4,
-    model: UniformMatrix4,
+    flash: UniformFloat,
 }
 
 impl TextureShader {
@@ -45,16 +45,19 @@ impl TextureShader {
         #[cfg(not(feature = "gles"))]
         let program = create_program(include_str!("../shaders/gl/vertex-shader.glsl"), include_str!("../shaders/gl/fragment-shader.glsl"));
 
-        let model = create_uniform("M", &program, "texture shader");
         let projection = create_uniform("P", &program, "texture shader");
+        let flash = create_uniform("flash", &program, "texture shader");
 
-        TextureShader { program, projection, model }
+        TextureShader { program, projection, flash }
     }
 
     /// Sends uniform data specific to this shader to GPU.
-    pub fn send_uniform_data(&mut self, model: &Matrix4<f32>, projection: &Matrix4<f32>) {
-        self.model.send(model);
+    ///
+    /// Argument `flash` is at range [0.0, 1.0] and tells how much
+    /// texture color is changed to white.
+    pub fn send_uniform_data(&mut self, projection: &Matrix4<f32>, flash: f32) {
         self.projection.send(projection);
+        self.flash.send(&flash);
     }
 
     /// Tell OpenGL to use this shader program.
@@ -63,39 +66,33 @@ impl TextureShader {
     }
 }
 
-/// Render tile map tiles. Supports OpenGL 3.3 and OpenGL ES 2.0.
-pub struct TileMapShader {
+/// Render sprite batches with vertex colors. Supports OpenGL 3.3 and OpenGL ES 2.0.
+pub struct ColorShader {
     program: Program,
     projection: UniformMatrix4,
-    model: UniformMatrix4,
-    tile_position_change_x_y_and_scaling_factor: UniformVector3,
 }
 
-impl TileMapShader {
-    /// Creates new TileMapShader
+impl ColorShader {
+    /// Creates new ColorShader
     ///
     /// # Panics
     /// If there is some error in creating the shader or uniforms.
-    pub fn new() -> TileMapShader {
+    pub fn new() -> ColorShader {
 
         #[cfg(feature = "gles")]
-        let program = create_program(include_str!("../shaders/gles/vertex-shader-tilemap-gles.glsl"), include_str!("../shaders/gles/fragment-shader-tilemap-gles.glsl"));
+        let program = create_program(include_str!("../shaders/gles/color-vertex-gles.glsl"), include_str!("../shaders/gles/color-fragment-gles.glsl"));
 
         #[cfg(not(feature = "gles"))]
-        let program = create_program(include_str!("../shaders/gl/vertex-shader-tilemap.glsl"), include_str!("../shaders/gl/fragment-shader-tilemap.glsl"));
+        let program = create_program(include_str!("../shaders/gl/color-vertex.glsl"), include_str!("../shaders/gl/color-fragment.glsl"));
 
-        let model = create_uniform("M", &program, "tilemap shader");
-        let projection = create_uniform("P", &program, "tilemap shader");
-        let tile_position_change_x_y_and_scaling_factor = create_uniform("tile_info", &program, "tilemap shader");
+        let projection = create_uniform("P", &program, "color shader");
 
-        TileMapShader { program, projection, model, tile_position_change_x_y_and_scaling_factor }
+        ColorShader { program, projection }
     }
 
     /// Sends uniform data specific to this shader to GPU.
-    pub fn send_uniform_data(&mut self, model: &Matrix4<f32>, projection: &Matrix4<f32>, tile_position_change_x_y_and_scaling_factor: &Vector3<f32>) {
-        self.model.send(model);
+    pub fn send_uniform_data(&mut self, projection: &Matrix4<f32>) {
         self.projection.send(projection);
-        self.tile_position_change_x_y_and_scaling_factor.send(tile_position_change_x_y_and_scaling_factor);
     }
 
     /// Tell OpenGL to use this shader program.
@@ -104,39 +101,118 @@ impl TileMapShader {
     }
 }
 
-/// Render with specific color. Supports OpenGL 3.3 and OpenGL ES 2.0.
-pub struct ColorShader {
+/// Extract bright colors from the scene for the bloom effect.
+/// Supports OpenGL 3.3 and OpenGL ES 2.0.
+pub struct BloomBrightPassShader {
     program: Program,
-    projection: UniformMatrix4,
-    model: UniformMatrix4,
-    color: UniformVector3,
 }
 
-impl ColorShader {
-    /// Creates new ColorShader
+impl BloomBrightPassShader {
+    /// Creates new BloomBrightPassShader
     ///
     /// # Panics
     /// If there is some error in creating the shader or uniforms.
-    pub fn new() -> ColorShader {
+    pub fn new() -> BloomBrightPassShader {
 
         #[cfg(feature = "gles")]
-        let program = create_program(include_str!("../shaders/gles/color-vertex-gles.glsl"), include_str!("../shaders/gles/color-fragment-gles.glsl"));
+        let program = create_program(include_str!("../shaders/gles/postprocess-vertex-gles.glsl"), include_str!("../shaders/gles/bloom-bright-fragment-gles.glsl"));
 
         #[cfg(not(feature = "gles"))]
-        let program = create_program(include_str!("../shaders/gl/color-vertex.glsl"), include_str!("../shaders/gl/color-fragment.glsl"));
+        let program = create_program(include_str!("../shaders/gl/postprocess-vertex.glsl"), include_str!("../shaders/gl/bloom-bright-fragment.glsl"));
 
-        let model = create_uniform("M", &program, "color shader");
-        let projection = create_uniform("P", &program, "color shader");
-        let color = create_uniform("color", &program, "color shader");
+        BloomBrightPassShader { program }
+    }
+
+    /// Tell OpenGL to use this shader program.
+    pub fn use_program(&mut self) {
+        self.program.use_program();
+    }
+}
+
+/// Blur texture to one direction. Supports OpenGL 3.3 and OpenGL ES 2.0.
+pub struct BlurShader {
+    program: Program,
+    direction: UniformVector2,
+}
+
+impl BlurShader {
+    /// Creates new BlurShader
+    ///
+    /// # Panics
+    /// If there is some error in creating the shader or uniforms.
+    pub fn new() -> BlurShader {
+
+        #[cfg(feature = "gles")]
+        let program = create_program(include_str!("../shaders/gles/postprocess-vertex-gles.glsl"), include_str!("../shaders/gles/bloom-blur-fragment-gles.glsl"));
+
+        #[cfg(not(feature = "gles"))]
+        let program = create_program(include_str!("../shaders/gl/postprocess-vertex.glsl"), include_str!("../shaders/gl/bloom-blur-fragment.glsl"));
+
+        let direction = create_uniform("direction", &program, "blur shader");
 
-        ColorShader { program, projection, model, color }
+        BlurShader { program, direction }
     }
 
     /// Sends uniform data specific to this shader to GPU.
-    pub fn send_uniform_data(&mut self, model: &Matrix4<f32>, projection: &Matrix4<f32>, color: &Vector3<f32>) {
-        self.model.send(model);
-        self.projection.send(projection);
-        self.color.send(color);
+    ///
+    /// Argument `direction` is distance between texels in blur direction
+    /// in texture coordinates.
+    pub fn send_uniform_data(&mut self, direction: &Vector2<f32>) {
+        self.direction.send(direction);
+    }
+
+    /// Tell OpenGL to use this shader program.
+    pub fn use_program(&mut self) {
+        self.program.use_program();
+    }
+}
+
+/// Combine the scene and bloom, and apply CRT and vignette effects.
+/// Supports OpenGL 3.3 and OpenGL ES 2.0.
+///
+/// # Texture units
+/// * Scene, unit 0
+/// * Bloom, unit 1
+pub struct CompositeShader {
+    program: Program,
+    bloom_sampler: UniformSampler,
+    bloom_intensity: UniformFloat,
+    crt: UniformFloat,
+    vignette: UniformFloat,
+    screen_size: UniformVector2,
+}
+
+impl CompositeShader {
+    /// Creates new CompositeShader
+    ///
+    /// # Panics
+    /// If there is some error in creating the shader or uniforms.
+    pub fn new() -> CompositeShader {
+
+        #[cfg(feature = "gles")]
+        let program = create_program(include_str!("../shaders/gles/postprocess-vertex-gles.glsl"), include_str!("../shaders/gles/postprocess-composite-fragment-gles.glsl"));
+
+        #[cfg(not(feature = "gles"))]
+        let program = create_program(include_str!("../shaders/gl/postprocess-vertex.glsl"), include_str!("../shaders/gl/postprocess-composite-fragment.glsl"));
+
+        let bloom_sampler = create_uniform("bloom_sampler", &program, "composite shader");
+        let bloom_intensity = create_uniform("bloom_intensity", &program, "composite shader");
+        let crt = create_uniform("crt", &program, "composite shader");
+        let vignette = create_uniform("vignette", &program, "composite shader");
+        let screen_size = create_uniform("screen_size", &program, "composite shader");
+
+        CompositeShader { program, bloom_sampler, bloom_intensity, crt, vignette, screen_size }
+    }
+
+    /// Sends uniform data specific to this shader to GPU.
+    ///
+    /// Effects are disabled when `bloom_intensity`, `crt` or `vignette` is zero.
+    pub fn send_uniform_data(&mut self, bloom_intensity: f32, crt: f32, vignette: f32, screen_size: &Vector2<f32>) {
+        self.bloom_sampler.send(&1);
+        self.bloom_intensity.send(&bloom_intensity);
+        self.crt.send(&crt);
+        self.vignette.send(&vignette);
+        self.screen_size.send(screen_size);
     }
 
     /// Tell OpenGL to use this shader program.
@@ -154,6 +230,7 @@ impl ColorShader {
 /// # Vertex attribute variable indexes
 /// * variable "vertex", index 0
 /// * variable "texture_coordinates_attribute", index 1
+/// * variable "color_attribute", index 2
 ///
 fn create_program(vertex_shader_code: &str, fragment_shader_code: &str) -> Program {
     let vertex_shader = load_shader(ShaderType::Vertex, vertex_shader_code);
@@ -162,11 +239,12 @@ fn create_program(vertex_shader_code: &str, fragment_shader_code: &str) -> Progr
     let mut vertex_attributes = VertexAttributeIndexBinder::new();
     vertex_attributes.add_attribute(0, "vertex");
     vertex_attributes.add_attribute(1, "texture_coordinates_attribute");
+    vertex_attributes.add_attribute(2, "color_attribute");
 
     match Program::new(vertex_shader, fragment_shader, vertex_attributes) {
         Ok(program) => program,
         Err(message) => {
-            println!("program creation error:\n{}", message);
+            error!("program creation error: {}", message);
             panic!();
         }
     }
@@ -183,7 +261,7 @@ fn load_shader(shader_type: ShaderType, source_code: &str) -> Shader {
     match Shader::new(shader_type, shader_text) {
         Ok(shader) => shader,
         Err(message) => {
-            println!("shader compile error\n{}", message);
+            error!("shader compile error: {}", message);
             panic!();
         },
     }
@@ -202,7 +280,7 @@ fn create_uniform<T: Uniform>(name: &str, program: &Program, program_name: &str)
     match uniform_result {
         Ok(uniform) => uniform,
         Err(error) => {
-            println!("error: {:?}\n uniform name: {}\n program name: {}\n", error, name, program_name);
+            error!("uniform creation error: {:?}, uniform name: {}, program name: {}", error, name, program_name);
             panic!();
         },
     }
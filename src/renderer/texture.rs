@@ -21,66 +21,131 @@ use gl::texture::*;
 use image::png::PNGDecoder;
 use image::{ImageDecoder, DecodingResult, ColorType};
 
+use paths::GamePaths;
+
+use renderer::atlas::{TextureAtlas, Image};
+
+use logic::animation::Animations;
+
 /// Available textures.
+#[derive(Copy, Clone, PartialEq)]
 pub enum Textures {
+    Background,
+    /// Texture atlas which contains all images from `Sprites` and
+    /// frames of all animations.
+    Sprites,
+    TextureCount,
+}
+
+/// Images in the sprite texture atlas.
+#[derive(Copy, Clone, PartialEq)]
+pub enum Sprites {
     Player,
     Enemy,
     EnemyWithShield,
-    Background,
-    Font,
-    Shield,
     LaserCannonGreen,
     LaserCannonRed,
     LaserBomb,
-    TextureCount,
+    SpriteCount,
 }
 
 impl Textures {
-    /// Loads textures to an array.
+    /// Loads textures to an array. Images from `Sprites` and animation
+    /// frames will be packed to one texture.
     ///
     /// # Panics
     /// * If loading of some texture fails.
-    pub fn load_all() -> [Texture; Textures::TextureCount as usize] {
-        [
-            Textures::load("game_files/images/player.png"),
-            Textures::load("game_files/images/enemy1.png"),
-            Textures::load("game_files/images/enemy2.png"),
-            Textures::load("game_files/images/background.png"),
-            Textures::load("game_files/images/tilemap-font.png"),
-            Textures::load("game_files/images/shield.png"),
-            Textures::load("game_files/images/laser_cannon_green.png"),
-            Textures::load("game_files/images/laser_cannon_red.png"),
-            Textures::load("game_files/images/laser_bomb.png"),
-        ]
+    pub fn load_all(paths: &GamePaths) -> ([Texture; Textures::TextureCount as usize], TextureAtlas) {
+        // Images must be in the same order as in the `Sprites` enum.
+        let mut atlas_images = vec![
+            load_image(&paths.data_file("images/player.png")),
+            load_image(&paths.data_file("images/enemy1.png")),
+            load_image(&paths.data_file("images/enemy2.png")),
+            load_image(&paths.data_file("images/laser_cannon_green.png")),
+            load_image(&paths.data_file("images/laser_cannon_red.png")),
+            load_image(&paths.data_file("images/laser_bomb.png")),
+        ];
+
+        for &animation in ALL_ANIMATIONS {
+            let sheet = load_image(&paths.data_file(animation_file(animation)));
+            atlas_images.extend(sheet.split_frames(animation.frame_count()));
+        }
+
+        let (sprites, atlas) = TextureAtlas::build(&atlas_images);
+
+        let textures = [
+            Textures::load(&paths.data_file("images/background.png")),
+            sprites,
+        ];
+
+        (textures, atlas)
     }
 
     /// Load texture from from RGBA or RGB image with PNG format.
     ///
     /// # Panics
-    /// * Opening the file fails.
-    /// * Can't read image dimensions, color type or data.
-    /// * If image data is not unsigned bytes.
-    /// * Image color type is not RGBA or RGB.
-    fn load(file_path: &str) -> Texture {
-        let img_file = File::open(file_path).expect("img opening fail");
-        let mut img = PNGDecoder::new(img_file);
-
-        let (width, height) = img.dimensions().expect("img dimensions fail");
-
-        let rgba;
-        match img.colortype().expect("img color type fail") {
-            ColorType::RGBA(_) => rgba = true,
-            ColorType::RGB(_) => rgba = false,
-            _ => panic!("image's color type is not RGB or RGBA"),
-        }
+    /// * Same as function `load_image`.
+    pub fn load(file_path: &str) -> Texture {
+        let image = load_image(file_path);
+        Texture::new(image.width, image.height, image.data, true)
+    }
+}
+
+/// All animations in the same order as in the `Animations` enum.
+const ALL_ANIMATIONS: &'static [Animations] = &[
+    Animations::EnemyThruster,
+    Animations::ShieldShimmer,
+    Animations::Explosion,
+    Animations::ExplosionSpark,
+];
+
+/// Sprite sheet file of animation.
+fn animation_file(animation: Animations) -> &'static str {
+    match animation {
+        Animations::EnemyThruster => "images/enemy_thruster.png",
+        Animations::ShieldShimmer => "images/shield_shimmer.png",
+        Animations::Explosion => "images/explosion.png",
+        Animations::ExplosionSpark => "images/spark.png",
+        Animations::AnimationCount => panic!("AnimationCount is not an animation"),
+    }
+}
 
-        let img_data_result = img.read_image().expect("img decoding fail");
+/// Index of animation frame's image in the sprite texture atlas.
+pub fn animation_frame_image_index(animation: Animations, frame: usize) -> usize {
+    let previous_frames: usize = ALL_ANIMATIONS.iter()
+        .take_while(|&&other| other != animation)
+        .map(|&other| other.frame_count())
+        .sum();
 
-        let img_data = match img_data_result {
-            DecodingResult::U8(data) => data,
-            _ => panic!("unknown image data"),
-        };
+    Sprites::SpriteCount as usize + previous_frames + frame
+}
 
-        Texture::new(width, height, img_data, rgba)
+/// Load image from RGBA or RGB image with PNG format.
+///
+/// # Panics
+/// * Opening the file fails.
+/// * Can't read image dimensions, color type or data.
+/// * If image data is not unsigned bytes.
+/// * Image color type is not RGBA or RGB.
+fn load_image(file_path: &str) -> Image {
+    let img_file = File::open(file_path).expect("img opening fail");
+    let mut img = PNGDecoder::new(img_file);
+
+    let (width, height) = img.dimensions().expect("img dimensions fail");
+
+    let rgba;
+    match img.colortype().expect("img color type fail") {
+        ColorType::RGBA(_) => rgba = true,
+        ColorType::RGB(_) => rgba = false,
+        _ => panic!("image's color type is not RGB or RGBA"),
     }
-}
\ No newline at end of file
+
+    let img_data_result = img.read_image().expect("img decoding fail");
+
+    let img_data = match img_data_result {
+        DecodingResult::U8(data) => data,
+        _ => panic!("unknown image data"),
+    };
+
+    Image::new(width, height, img_data, rgba)
+}
@@ -0,0 +1,356 @@
+/*
+src/settings/file.rs, 2017-09-14
+
+Copyright (c) 2017 Juuso Tuononen
+
+This file is licensed under
+
+Apache License, Version 2.0
+
+or
+
+MIT License
+*/
+
+//! Settings file loading, saving and migration.
+//!
+//! # File format
+//!
+//! Settings file is a TOML file. Settings are stored to table `settings`
+//! with setting keys from module `registry`. Window size and position in windowed
+//! mode are stored to table `window`. Game controller mappings are stored
+//! to string array `mappings` in table `game_controller_mappings`.
+//!
+//! Integer `version` is the version of the file format. If the
+//! file format changes, old versions will be migrated to the current version
+//! when the file is loaded.
+//!
+//! ## Example file
+//!
+//! ```text
+//! # Settings file for Space Boss Battles
+//!
+//! version = 2
+//!
+//! [settings]
+//! display_mode = "windowed"
+//! resolution = "640x480"
+//! fps_counter = false
+//! vsync = true
+//! music_volume = 100
+//! effect_volume = 100
+//!
+//! [window]
+//! width = 640
+//! height = 480
+//! x = 100
+//! y = 100
+//!
+//! [game_controller_mappings]
+//! # https://wiki.libsdl.org/SDL_GameControllerAddMapping
+//! mappings = [
+//!     "03000000100800000300000010010000,USB Gamepad , a:b2, b:b1, y:b0, x:b3, start:b9, guide:b12, back:b8, dpup:h0.1, dpleft:h0.8, dpdown:h0.4, dpright:h0.2, leftshoulder:b6, rightshoulder:b7, leftstick:b10, rightstick:b11, leftx:a0, lefty:a1, rightx:a3, righty:a2, lefttrigger:b4, righttrigger:b5",
+//! ]
+//! ```
+
+use std::fs::{self, File};
+use std::io::prelude::*;
+use std::path::Path;
+
+use toml::Value;
+use toml::value::Table;
+
+use window::{WindowGeometry, DisplayMode};
+
+use super::registry::{SettingId, SettingValue, ALL_SETTINGS};
+
+/// Current version of the settings file format.
+pub const SETTINGS_FILE_VERSION: i64 = 2;
+
+/// Settings and game controller mappings which are
+/// loaded from the settings file.
+pub struct SettingsFileData<'a> {
+    /// Setting values indexed with `SettingId`.
+    pub values: &'a mut [SettingValue],
+    pub window_geometry: &'a mut WindowGeometry,
+    pub controller_mappings: &'a mut Vec<String>,
+}
+
+impl <'a> SettingsFileData<'a> {
+    /// Validate and set setting value.
+    ///
+    /// Logs a warning if value is out of setting's value range.
+    fn set_value(&mut self, id: SettingId, value: SettingValue) {
+        let definition = id.definition();
+
+        match definition.kind.validate(value) {
+            Some(validated_value) => {
+                if validated_value != value {
+                    warn!("settings file: value {} for setting \"{}\" is out of range, using value {}",
+                        definition.kind.format(value), definition.key, definition.kind.format(validated_value));
+                }
+                self.values[id as usize] = validated_value;
+            },
+            None => warn!("settings file: invalid value {:?} for setting \"{}\"", value, definition.key),
+        }
+    }
+
+    /// Load settings from TOML file.
+    ///
+    /// Unknown keys and invalid or out of range values will be logged as warnings.
+    ///
+    /// Returns `Err(error_message)` if reading or parsing the file fails.
+    pub fn load(&mut self, path: &Path) -> Result<(), String> {
+        let settings_text = read_file(path)?;
+
+        let mut file = match settings_text.parse::<Value>() {
+            Ok(Value::Table(table)) => table,
+            Ok(_) => return Err("file is not a TOML table".to_string()),
+            Err(error) => return Err(error.to_string()),
+        };
+
+        let version = match file.remove("version") {
+            Some(Value::Integer(version)) => version,
+            Some(_) => return Err("version is not an integer".to_string()),
+            None => return Err("version is missing".to_string()),
+        };
+
+        if version > SETTINGS_FILE_VERSION {
+            warn!("settings file: file version {} is newer than supported version {}, some settings may not be loaded",
+                version, SETTINGS_FILE_VERSION);
+        }
+
+        migrate(&mut file, version);
+
+        for (key, value) in file {
+            match (key.as_str(), value) {
+                ("settings", Value::Table(settings)) => self.load_settings(settings),
+                ("window", Value::Table(window)) => self.load_window_geometry(window),
+                ("game_controller_mappings", Value::Table(mappings)) => self.load_controller_mappings(mappings),
+                (key, _) => warn!("settings file: unknown key \"{}\"", key),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Load settings from table `settings`.
+    fn load_settings(&mut self, settings: Table) {
+        for (key, value) in settings {
+            let id = match ALL_SETTINGS.iter().find(|id| id.definition().key == key) {
+                Some(&id) => id,
+                None => {
+                    warn!("settings file: unknown setting \"{}\"", key);
+                    continue;
+                }
+            };
+
+            match id.definition().kind.from_toml(&value) {
+                Ok(value) => self.set_value(id, value),
+                Err(error) => warn!("settings file: error when loading value {} for setting \"{}\": {}", value, key, error),
+            }
+        }
+    }
+
+    /// Load window size and position from table `window`.
+    fn load_window_geometry(&mut self, window: Table) {
+        let mut x = None;
+        let mut y = None;
+
+        for (key, value) in window {
+            match (key.as_str(), value) {
+                ("width", Value::Integer(width)) if width > 0 => self.window_geometry.width = width as u32,
+                ("height", Value::Integer(height)) if height > 0 => self.window_geometry.height = height as u32,
+                ("x", Value::Integer(value)) => x = Some(value as i32),
+                ("y", Value::Integer(value)) => y = Some(value as i32),
+                (key @ "width", value) | (key @ "height", value) | (key @ "x", value) | (key @ "y", value) => {
+                    warn!("settings file: invalid value {} for window.{}", value, key);
+                },
+                (key, _) => warn!("settings file: unknown key \"{}\" in window", key),
+            }
+        }
+
+        if let (Some(x), Some(y)) = (x, y) {
+            self.window_geometry.position = Some((x, y));
+        }
+    }
+
+    /// Load game controller mappings from table `game_controller_mappings`.
+    fn load_controller_mappings(&mut self, mappings: Table) {
+        for (key, value) in mappings {
+            match (key.as_str(), value) {
+                ("mappings", Value::Array(mappings)) => {
+                    for mapping in mappings {
+                        match mapping {
+                            Value::String(mapping) => self.controller_mappings.push(mapping),
+                            mapping => warn!("settings file: game controller mapping {} is not a string", mapping),
+                        }
+                    }
+                },
+                (key, _) => warn!("settings file: unknown key \"{}\" in game_controller_mappings", key),
+            }
+        }
+    }
+
+    /// Load settings from a settings file which uses the old file format.
+    /// Old settings file is not a TOML file and it does not have a version number.
+    ///
+    /// # Old file format
+    ///
+    /// Empty lines and lines starting with `#` are skipped.
+    ///
+    /// Section `[Settings]` contains key-value pairs `setting name=value`, where
+    /// setting name is setting's name in the GUI.
+    ///
+    /// Section `[GameControllerMappings]` contains one game controller mapping per line.
+    ///
+    /// Returns `Err(error_message)` if reading the file fails.
+    pub fn load_old_format(&mut self, path: &Path) -> Result<(), String> {
+        let settings_text = read_file(path)?;
+
+        let mut settings_parser = None;
+
+        for line in settings_text.lines() {
+            let line = line.trim();
+
+            if line == "" || line.starts_with("#") {
+                continue;
+            } else if line == "[Settings]" {
+                settings_parser = Some(OldFormatParserMode::Settings);
+                continue;
+            } else if line == "[GameControllerMappings]" {
+                settings_parser = Some(OldFormatParserMode::GameControllerMappings);
+                continue;
+            }
+
+            match settings_parser {
+                Some(OldFormatParserMode::Settings) => {
+                    let mut iterator = line.split("=");
+
+                    let (name, value) = match (iterator.next(), iterator.next()) {
+                        (Some(name), Some(value)) => (name, value),
+                        _ => {
+                            warn!("old settings file: invalid setting: {}", line);
+                            continue;
+                        }
+                    };
+
+                    // Full screen setting is replaced with display mode setting.
+                    if name == "Full screen" {
+                        let display_mode = if value == "true" { DisplayMode::BorderlessFullscreen } else { DisplayMode::Windowed };
+                        self.set_value(SettingId::DisplayMode, SettingValue::Enum(display_mode as usize));
+                        continue;
+                    }
+
+                    let id = match old_format_setting(name) {
+                        Some(id) => id,
+                        None => {
+                            warn!("old settings file: unknown setting \"{}\"", name);
+                            continue;
+                        }
+                    };
+
+                    match id.definition().kind.parse(value) {
+                        Ok(value) => self.set_value(id, value),
+                        Err(error) => warn!("old settings file: error when parsing value \"{}\" for setting \"{}\": {}", value, name, error),
+                    }
+                },
+                Some(OldFormatParserMode::GameControllerMappings) => {
+                    self.controller_mappings.push(line.to_string());
+                },
+                None => (),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Save settings to TOML file.
+///
+/// Returns `Err(error_message)` if saving fails.
+pub fn save(path: &Path, values: &[SettingValue], window_geometry: &WindowGeometry, controller_mappings: &[String]) -> Result<(), String> {
+    let mut settings_text = String::new();
+
+    settings_text.push_str("# Settings file for Space Boss Battles\n\n");
+    settings_text.push_str(&format!("version = {}\n\n[settings]\n", SETTINGS_FILE_VERSION));
+
+    for &id in ALL_SETTINGS {
+        let definition = id.definition();
+        let value = definition.kind.to_toml(values[id as usize]);
+        settings_text.push_str(&format!("{} = {}\n", definition.key, value));
+    }
+
+    settings_text.push_str(&format!("\n[window]\nwidth = {}\nheight = {}\n", window_geometry.width, window_geometry.height));
+
+    if let Some((x, y)) = window_geometry.position {
+        settings_text.push_str(&format!("x = {}\ny = {}\n", x, y));
+    }
+
+    settings_text.push_str("\n[game_controller_mappings]\n# https://wiki.libsdl.org/SDL_GameControllerAddMapping\nmappings = [\n");
+
+    for mapping in controller_mappings {
+        settings_text.push_str(&format!("    {},\n", Value::String(mapping.clone())));
+    }
+
+    settings_text.push_str("]\n");
+
+    if let Some(dir) = path.parent() {
+        if dir != Path::new("") {
+            fs::create_dir_all(dir).map_err(|error| error.to_string())?;
+        }
+    }
+
+    let mut file = File::create(path).map_err(|error| error.to_string())?;
+    file.write_all(settings_text.as_bytes()).map_err(|error| error.to_string())
+}
+
+/// Old settings file format's parser states.
+enum OldFormatParserMode {
+    Settings,
+    GameControllerMappings,
+}
+
+/// Setting of old settings file format's setting name. Old format
+/// used English setting names from the GUI, so names are listed
+/// here instead of reading them from the locale files.
+fn old_format_setting(name: &str) -> Option<SettingId> {
+    match name {
+        "FPS counter" => Some(SettingId::ShowFpsCounter),
+        "VSync" => Some(SettingId::VSync),
+        "Music volume" => Some(SettingId::MusicVolume),
+        "Effect volume" => Some(SettingId::SoundEffectVolume),
+        _ => None,
+    }
+}
+
+/// Migrate settings file contents from argument `version` to
+/// the current file format version.
+///
+/// # Versions
+/// * Version 2 replaces boolean setting `full_screen` with
+///   setting `display_mode`.
+fn migrate(file: &mut Table, version: i64) {
+    if version < 2 {
+        if let Some(&mut Value::Table(ref mut settings)) = file.get_mut("settings") {
+            if let Some(full_screen) = settings.remove("full_screen") {
+                let display_mode = match full_screen {
+                    Value::Boolean(true) => "borderless",
+                    _ => "windowed",
+                };
+
+                settings.insert("display_mode".to_string(), Value::String(display_mode.to_string()));
+            }
+        }
+    }
+}
+
+/// Read file to `String`.
+fn read_file(path: &Path) -> Result<String, String> {
+    let mut file = File::open(path).map_err(|error| error.to_string())?;
+
+    let mut text = String::new();
+    file.read_to_string(&mut text).map_err(|error| error.to_string())?;
+
+    Ok(text)
+}
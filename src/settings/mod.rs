@@ -0,0 +1,557 @@
+/*
+src/settings/mod.rs, 2017-08-24
+
+Copyright (c) 2017 Juuso Tuononen
+
+This file is licensed under
+
+Apache License, Version 2.0
+
+or
+
+MIT License
+*/
+
+//! Settings loading and saving, command line arguments.
+
+pub mod registry;
+pub mod file;
+
+use std::env::Args;
+
+use renderer::Renderer;
+
+use gui::GUI;
+
+use audio::{AudioManager, AudioPlayer};
+
+use window::{Window, DisplayMode, WindowGeometry};
+
+use logger::LogLevelSettings;
+
+use paths::GamePaths;
+
+use logic::{Logic, Difficulty, LAST_LEVEL_INDEX};
+
+use utils::FrameLimiter;
+
+pub use self::registry::{SettingId, SettingValue, SettingKind, ALL_SETTINGS, LANGUAGE_OPTIONS};
+use self::registry::{BackendDefaults, SettingApplyContext};
+use self::file::SettingsFileData;
+
+/// Save and load settings. Handle command line argument settings.
+pub struct Settings {
+    /// Setting values indexed with `SettingId`.
+    values: Vec<SettingValue>,
+    controller_mappings: Vec<String>,
+    command_line_arguments: Arguments,
+    paths: GamePaths,
+    /// Settings file values of settings which are overridden with command line arguments.
+    /// These values will be saved to the settings file instead of the current value.
+    overridden_file_values: Vec<(SettingId, SettingValue)>,
+    /// Settings file window size if window size is overridden with command line argument.
+    overridden_file_window_size: Option<(u32, u32)>,
+    window_geometry: WindowGeometry,
+}
+
+impl Settings {
+    /// Create new `Settings`.
+    ///
+    /// Settings are initialized to default values from setting definitions and
+    /// then settings are read from the settings file. Finally setting values
+    /// from command line arguments will override the current values.
+    pub fn new(command_line_arguments: Arguments, paths: GamePaths, effect_default_volume_percentage: i32, music_default_volume_percentage: i32) -> Settings {
+        let defaults = BackendDefaults {
+            effect_volume_percentage: effect_default_volume_percentage,
+            music_volume_percentage: music_default_volume_percentage,
+        };
+
+        let values = ALL_SETTINGS.iter().map(|id| id.default_value(&defaults)).collect();
+
+        let mut settings = Settings {
+            values,
+            controller_mappings: Vec::new(),
+            command_line_arguments,
+            paths,
+            overridden_file_values: Vec::new(),
+            overridden_file_window_size: None,
+            window_geometry: WindowGeometry::new(),
+        };
+
+        settings.load();
+
+        let setting_overrides = settings.command_line_arguments.setting_overrides().to_vec();
+
+        for (id, value) in setting_overrides {
+            if !settings.overridden_file_values.iter().any(|&(overridden_id, _)| overridden_id == id) {
+                let file_value = settings.get(id);
+                settings.overridden_file_values.push((id, file_value));
+            }
+
+            settings.values[id as usize] = value;
+        }
+
+        if let Some((width, height)) = settings.command_line_arguments.window_size() {
+            settings.overridden_file_window_size = Some((settings.window_geometry.width, settings.window_geometry.height));
+            settings.window_geometry.width = width;
+            settings.window_geometry.height = height;
+        }
+
+        settings
+    }
+
+    /// Parsed command line arguments.
+    pub fn arguments(&self) -> &Arguments {
+        &self.command_line_arguments
+    }
+
+    /// Locations of game data and user files.
+    pub fn paths(&self) -> &GamePaths {
+        &self.paths
+    }
+
+    /// Window size and position in windowed mode.
+    pub fn window_geometry(&self) -> &WindowGeometry {
+        &self.window_geometry
+    }
+
+    /// Update window size and position which will be saved to the settings file.
+    pub fn set_window_geometry(&mut self, window_geometry: WindowGeometry) {
+        self.window_geometry = window_geometry;
+    }
+
+    /// Get current value of a setting.
+    pub fn get(&self, id: SettingId) -> SettingValue {
+        self.values[id as usize]
+    }
+
+    /// Update setting's value.
+    ///
+    /// Value will be clamped to setting's value range. If value type doesn't
+    /// match with the setting definition, setting will not be updated.
+    ///
+    /// If setting was overridden with command line argument, the
+    /// updated value will be saved to the settings file.
+    ///
+    /// Returns setting's current value after the update.
+    pub fn update_setting(&mut self, id: SettingId, new_value: SettingValue) -> SettingValue {
+        match id.definition().kind.validate(new_value) {
+            Some(value) => {
+                self.values[id as usize] = value;
+                self.overridden_file_values.retain(|&(overridden_id, _)| overridden_id != id);
+            },
+            None => warn!("invalid value {:?} for setting {:?}", new_value, id),
+        }
+
+        self.get(id)
+    }
+
+    /// Save settings to the settings file. Directory for the settings file will
+    /// be created if it doesn't exist.
+    ///
+    /// Saves current settings from `Vec<SettingValue>` field, window geometry and game controller
+    /// mappings from `Vec<String>`. Command line argument setting overrides
+    /// will not be saved. Window size from command line argument will not be saved
+    /// if the window was not resized.
+    ///
+    /// For file format, see module `file`'s documentation.
+    ///
+    /// If saving the file fails, an error message will be logged.
+    pub fn save(&self) {
+        let path = self.paths.settings_file();
+
+        let mut values = self.values.clone();
+
+        for &(id, file_value) in &self.overridden_file_values {
+            values[id as usize] = file_value;
+        }
+
+        let mut window_geometry = self.window_geometry;
+
+        if let Some((width, height)) = self.overridden_file_window_size {
+            if self.command_line_arguments.window_size() == Some((window_geometry.width, window_geometry.height)) {
+                window_geometry.width = width;
+                window_geometry.height = height;
+            }
+        }
+
+        if let Err(error) = file::save(path, &values, &window_geometry, &self.controller_mappings) {
+            error!("couldn't save settings to {}: {}", path.display(), error);
+        }
+    }
+
+    /// Load settings from the settings file.
+    ///
+    /// If that file does not exist, settings will be loaded from old settings file
+    /// in the current working directory and saved to the new settings file.
+    /// Old settings file will not be modified.
+    ///
+    /// If opening or reading the settings file fails or there is parsing error, an error message
+    /// will be logged.
+    pub fn load(&mut self) {
+        if !self.paths.settings_file().exists() && self.paths.old_settings_file().exists() {
+            let old_path = self.paths.old_settings_file();
+
+            let result = SettingsFileData {
+                values: &mut self.values,
+                window_geometry: &mut self.window_geometry,
+                controller_mappings: &mut self.controller_mappings,
+            }.load_old_format(old_path);
+
+            match result {
+                Ok(()) => {
+                    info!("settings migrated from {} to {}", old_path.display(), self.paths.settings_file().display());
+                    self.save();
+                },
+                Err(error) => error!("couldn't load settings from {}: {}", old_path.display(), error),
+            }
+
+            return;
+        }
+
+        let result = SettingsFileData {
+            values: &mut self.values,
+            window_geometry: &mut self.window_geometry,
+            controller_mappings: &mut self.controller_mappings,
+        }.load(self.paths.settings_file());
+
+        if let Err(error) = result {
+            warn!("couldn't load settings from {}: {}", self.paths.settings_file().display(), error);
+        }
+    }
+
+    pub fn game_controller_mappings(&self) -> &Vec<String> {
+        &self.controller_mappings
+    }
+
+    /// Adds game controller mapping to `Vec<String>` located at `controller_mappings` field.
+    pub fn add_game_controller_mapping(&mut self, mapping: String) {
+        self.controller_mappings.push(mapping);
+    }
+
+    /// Is joystick event printing enabled.
+    pub fn print_joystick_events(&self) -> bool {
+        self.command_line_arguments.print_joystick_events
+    }
+
+    /// Is fps count printing enabled.
+    pub fn print_fps_count(&self) -> bool {
+        self.command_line_arguments.print_fps_count
+    }
+
+    /// Is frame time statistics printing enabled.
+    pub fn print_frame_times(&self) -> bool {
+        self.command_line_arguments.print_frame_times
+    }
+
+    /// Applies current settings from field `values`.
+    pub fn apply_current_settings<T: Renderer, W: Window, P: AudioPlayer>(&self, renderer: &mut T, gui: &mut GUI, logic: &mut Logic, audio_manager: &mut AudioManager<P>, window: &mut W, frame_limiter: &mut FrameLimiter) {
+        for &id in ALL_SETTINGS {
+            Settings::apply_setting(id, self.get(id), renderer, gui, logic, audio_manager, window, frame_limiter);
+        }
+    }
+
+    /// Apply setting provided as argument.
+    pub fn apply_setting<T: Renderer, W: Window, P: AudioPlayer>(id: SettingId, value: SettingValue, renderer: &mut T, gui: &mut GUI, logic: &mut Logic, audio_manager: &mut AudioManager<P>, window: &mut W, frame_limiter: &mut FrameLimiter) {
+        let mut context = SettingApplyContext { renderer, gui, logic, audio_manager, window, frame_limiter };
+        id.apply(value, &mut context);
+    }
+}
+
+/// Parsed command line arguments.
+///
+/// # Supported arguments
+/// * `--fps`
+/// * `--frame-times`
+/// * `--joystick-events`
+/// * `--help` or `-h`
+/// * `--music path_to_music_file`
+/// * `--log-level log_level_settings`
+/// * `--log-file`
+/// * `--data-dir path_to_game_data_directory`
+/// * `--config path_to_settings_file`
+/// * `--set setting_key=value`
+/// * `--fullscreen`
+/// * `--windowed`
+/// * `--window-size WIDTHxHEIGHT`
+/// * `--difficulty easy|normal|hard`
+/// * `--level level_number`
+/// * `--seed random_number_generator_seed`
+pub struct Arguments {
+    show_help: bool,
+    print_fps_count: bool,
+    print_frame_times: bool,
+    print_joystick_events: bool,
+    music_file_path: Option<String>,
+    log_level_settings: LogLevelSettings,
+    log_to_file: bool,
+    data_dir: Option<String>,
+    config_file_path: Option<String>,
+    setting_overrides: Vec<(SettingId, SettingValue)>,
+    window_size: Option<(u32, u32)>,
+    difficulty: Option<Difficulty>,
+    level: Option<u32>,
+    seed: Option<u64>,
+}
+
+impl Arguments {
+    /// Parse command line arguments
+    ///
+    /// Returns with Err(error_message) if there is
+    /// unknown argument, invalid argument value or missing argument value.
+    pub fn parse(args: Args) -> Result<Arguments, String> {
+        let mut arguments = Arguments {
+            show_help: false,
+            print_fps_count: false,
+            print_frame_times: false,
+            print_joystick_events: false,
+            music_file_path: None,
+            log_level_settings: LogLevelSettings::new(),
+            log_to_file: false,
+            data_dir: None,
+            config_file_path: None,
+            setting_overrides: Vec::new(),
+            window_size: None,
+            difficulty: None,
+            level: None,
+            seed: None,
+        };
+
+        let mut argument_parser_state = None;
+
+        for arg in args.skip(1) {
+            match argument_parser_state {
+                Some(ArgumentParserState::MusicFilePath) => {
+                    arguments.music_file_path = Some(arg);
+                },
+                Some(ArgumentParserState::LogLevel) => {
+                    arguments.log_level_settings = LogLevelSettings::parse(&arg)?;
+                },
+                Some(ArgumentParserState::DataDir) => {
+                    arguments.data_dir = Some(arg);
+                },
+                Some(ArgumentParserState::ConfigFilePath) => {
+                    arguments.config_file_path = Some(arg);
+                },
+                Some(ArgumentParserState::SetSetting) => {
+                    let setting_override = parse_setting_override(&arg)?;
+                    arguments.setting_overrides.push(setting_override);
+                },
+                Some(ArgumentParserState::WindowSize) => {
+                    arguments.window_size = Some(parse_window_size(&arg)?);
+                },
+                Some(ArgumentParserState::Difficulty) => {
+                    let difficulty = match arg.as_str() {
+                        "easy" => Difficulty::Easy,
+                        "normal" => Difficulty::Normal,
+                        "hard" => Difficulty::Hard,
+                        _ => return Err(format!("unknown difficulty \"{}\"", arg)),
+                    };
+                    arguments.difficulty = Some(difficulty);
+                },
+                Some(ArgumentParserState::Level) => {
+                    match arg.parse::<u32>() {
+                        Ok(level) if 1 <= level && level <= LAST_LEVEL_INDEX + 1 => arguments.level = Some(level - 1),
+                        _ => return Err(format!("level must be a number between 1 and {}", LAST_LEVEL_INDEX + 1)),
+                    }
+                },
+                Some(ArgumentParserState::Seed) => {
+                    match arg.parse::<u64>() {
+                        Ok(seed) => arguments.seed = Some(seed),
+                        Err(error) => return Err(format!("invalid seed \"{}\": {}", arg, error)),
+                    }
+                },
+                None => {
+                    if arg == "--fps" {
+                        arguments.print_fps_count = true;
+                    } else if arg == "--frame-times" {
+                        arguments.print_frame_times = true;
+                    } else if arg == "--joystick-events" {
+                        arguments.print_joystick_events = true;
+                    } else if arg == "--help" || arg == "-h" {
+                        arguments.show_help = true;
+                    } else if arg == "--log-file" {
+                        arguments.log_to_file = true;
+                    } else if arg == "--fullscreen" {
+                        arguments.setting_overrides.push((SettingId::DisplayMode, SettingValue::Enum(DisplayMode::BorderlessFullscreen as usize)));
+                    } else if arg == "--windowed" {
+                        arguments.setting_overrides.push((SettingId::DisplayMode, SettingValue::Enum(DisplayMode::Windowed as usize)));
+                    } else {
+                        match ArgumentParserState::from_argument(&arg) {
+                            Some(state) => argument_parser_state = Some(state),
+                            None => return Err(format!("unknown argument: \"{}\"", arg)),
+                        }
+                    }
+
+                    continue;
+                },
+            }
+
+            argument_parser_state = None;
+        }
+
+        if let Some(state) = argument_parser_state {
+            return Err(format!("missing value for argument \"{}\"", state.argument()));
+        }
+
+        Ok(arguments)
+    }
+
+    /// Is there argument `--help` or `-h` found.
+    pub fn show_help(&self) -> bool {
+        self.show_help
+    }
+
+    /// Possible user defined music file path.
+    pub fn music_file_path(&self) -> &Option<String> {
+        &self.music_file_path
+    }
+
+    /// Log level settings from argument `--log-level`.
+    pub fn log_level_settings(&self) -> &LogLevelSettings {
+        &self.log_level_settings
+    }
+
+    /// Is there argument `--log-file` found.
+    pub fn log_to_file(&self) -> bool {
+        self.log_to_file
+    }
+
+    /// Possible user defined game data directory.
+    pub fn data_dir(&self) -> &Option<String> {
+        &self.data_dir
+    }
+
+    /// Possible user defined settings file path.
+    pub fn config_file_path(&self) -> &Option<String> {
+        &self.config_file_path
+    }
+
+    /// Setting values from arguments `--set`, `--fullscreen` and `--windowed`
+    /// in the same order as the arguments.
+    pub fn setting_overrides(&self) -> &[(SettingId, SettingValue)] {
+        &self.setting_overrides
+    }
+
+    /// Possible user defined window size in pixels.
+    pub fn window_size(&self) -> Option<(u32, u32)> {
+        self.window_size
+    }
+
+    /// Difficulty and level index for starting the game without menus.
+    ///
+    /// Returns `None` if arguments `--difficulty` and `--level` are not found.
+    /// If only one of these arguments is found, default difficulty is normal and
+    /// default level is the first level.
+    pub fn quick_start(&self) -> Option<(Difficulty, u32)> {
+        if self.difficulty.is_none() && self.level.is_none() {
+            None
+        } else {
+            Some((self.difficulty.unwrap_or(Difficulty::Normal), self.level.unwrap_or(0)))
+        }
+    }
+
+    /// Possible user defined seed for random number generators.
+    pub fn seed(&self) -> Option<u64> {
+        self.seed
+    }
+}
+
+/// Parse argument `--set` value `setting_key=value`.
+///
+/// Returns `Err(error_message)` if there is unknown setting or
+/// value is invalid or out of range.
+fn parse_setting_override(text: &str) -> Result<(SettingId, SettingValue), String> {
+    let mut iterator = text.splitn(2, '=');
+
+    let (key, value) = match (iterator.next(), iterator.next()) {
+        (Some(key), Some(value)) => (key.trim(), value.trim()),
+        _ => return Err(format!("invalid setting \"{}\", expected format is key=value", text)),
+    };
+
+    parse_setting(key, value)
+}
+
+/// Parse setting key and value text.
+///
+/// Returns `Err(error_message)` if there is unknown setting or
+/// value is invalid or out of range.
+pub fn parse_setting(key: &str, value: &str) -> Result<(SettingId, SettingValue), String> {
+    let id = match ALL_SETTINGS.iter().find(|id| id.definition().key == key) {
+        Some(&id) => id,
+        None => return Err(format!("unknown setting \"{}\"", key)),
+    };
+
+    let kind = id.definition().kind;
+
+    let value = kind.parse(value).map_err(|error| format!("invalid value \"{}\" for setting \"{}\": {}", value, key, error))?;
+
+    match kind.validate(value) {
+        Some(validated_value) if validated_value == value => Ok((id, value)),
+        _ => Err(format!("value \"{}\" for setting \"{}\" is out of range", kind.format(value), key)),
+    }
+}
+
+/// Parse argument `--window-size` value `WIDTHxHEIGHT`.
+pub fn parse_window_size(text: &str) -> Result<(u32, u32), String> {
+    let mut iterator = text.split('x');
+
+    match (iterator.next(), iterator.next(), iterator.next()) {
+        (Some(width), Some(height), None) => {
+            match (width.parse::<u32>(), height.parse::<u32>()) {
+                (Ok(width), Ok(height)) if width > 0 && height > 0 => Ok((width, height)),
+                _ => Err(format!("invalid window size \"{}\"", text)),
+            }
+        },
+        _ => Err(format!("invalid window size \"{}\", expected format is WIDTHxHEIGHT", text)),
+    }
+}
+
+/// State for parsing the next argument.
+#[derive(Copy, Clone)]
+enum ArgumentParserState {
+    MusicFilePath,
+    LogLevel,
+    DataDir,
+    ConfigFilePath,
+    SetSetting,
+    WindowSize,
+    Difficulty,
+    Level,
+    Seed,
+}
+
+impl ArgumentParserState {
+    /// Parser state for argument which requires a value.
+    fn from_argument(argument: &str) -> Option<ArgumentParserState> {
+        let state = match argument {
+            "--music" => ArgumentParserState::MusicFilePath,
+            "--log-level" => ArgumentParserState::LogLevel,
+            "--data-dir" => ArgumentParserState::DataDir,
+            "--config" => ArgumentParserState::ConfigFilePath,
+            "--set" => ArgumentParserState::SetSetting,
+            "--window-size" => ArgumentParserState::WindowSize,
+            "--difficulty" => ArgumentParserState::Difficulty,
+            "--level" => ArgumentParserState::Level,
+            "--seed" => ArgumentParserState::Seed,
+            _ => return None,
+        };
+
+        Some(state)
+    }
+
+    /// Argument which value is parsed next.
+    fn argument(self) -> &'static str {
+        match self {
+            ArgumentParserState::MusicFilePath => "--music",
+            ArgumentParserState::LogLevel => "--log-level",
+            ArgumentParserState::DataDir => "--data-dir",
+            ArgumentParserState::ConfigFilePath => "--config",
+            ArgumentParserState::SetSetting => "--set",
+            ArgumentParserState::WindowSize => "--window-size",
+            ArgumentParserState::Difficulty => "--difficulty",
+            ArgumentParserState::Level => "--level",
+            ArgumentParserState::Seed => "--seed",
+        }
+    }
+}
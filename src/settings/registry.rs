@@ -0,0 +1,498 @@
+/*
+src/settings/registry.rs, 2017-09-12
+
+Copyright (c) 2017 Juuso Tuononen
+
+This file is licensed under
+
+Apache License, Version 2.0
+
+or
+
+MIT License
+*/
+
+//! Definitions of all settings.
+//!
+//! Every setting is defined only once with macro `settings_registry`. The
+//! definition contains setting's key, value type, value range, default value
+//! and code for applying the setting to game's components. Setting's name in
+//! the GUI is text `setting.KEY` from the locale files.
+//!
+//! `Settings`, settings file and `SettingsMenu` are built from these definitions,
+//! so adding a new setting only requires adding a new definition
+//! to this file.
+
+use toml::Value;
+
+use renderer::{Renderer, PostProcessingEffect};
+use gui::GUI;
+use logic::Logic;
+use audio::{AudioManager, AudioPlayer};
+use window::{Window, DisplayMode};
+use utils::FrameLimiter;
+use locale::Locale;
+
+use super::parse_window_size;
+
+/// Value of a setting.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum SettingValue {
+    Boolean(bool),
+    Integer(i32),
+    Float(f32),
+    /// Index to the `SettingKind::Enum`'s option list.
+    Enum(usize),
+}
+
+impl SettingValue {
+    /// Boolean value. Returns false if value is not a boolean.
+    pub fn boolean(self) -> bool {
+        match self {
+            SettingValue::Boolean(value) => value,
+            _ => false,
+        }
+    }
+
+    /// Integer value. Returns zero if value is not an integer.
+    pub fn integer(self) -> i32 {
+        match self {
+            SettingValue::Integer(value) => value,
+            _ => 0,
+        }
+    }
+
+    /// Float value. Returns zero if value is not a float.
+    pub fn float(self) -> f32 {
+        match self {
+            SettingValue::Float(value) => value,
+            _ => 0.0,
+        }
+    }
+
+    /// Option index of enum value. Returns zero if value is not an enum value.
+    pub fn enum_index(self) -> usize {
+        match self {
+            SettingValue::Enum(index) => index,
+            _ => 0,
+        }
+    }
+}
+
+/// Option of enum setting.
+#[derive(Copy, Clone, Debug)]
+pub struct SettingOption {
+    /// Option's name in the settings file.
+    pub key: &'static str,
+    /// Option's text key in the locale files. Option key
+    /// is displayed in the GUI if this is `None`.
+    pub text_key: Option<&'static str>,
+}
+
+/// Value type and value range of a setting.
+#[derive(Copy, Clone, Debug)]
+pub enum SettingKind {
+    Boolean,
+    Integer { min: i32, max: i32, step: i32 },
+    Float { min: f32, max: f32, step: f32 },
+    Enum { options: &'static [SettingOption] },
+}
+
+impl SettingKind {
+    /// Check that value has correct type and clamp the value to
+    /// setting's value range.
+    ///
+    /// Returns `None` if value type does not match with `SettingKind`.
+    pub fn validate(&self, value: SettingValue) -> Option<SettingValue> {
+        match (*self, value) {
+            (SettingKind::Boolean, SettingValue::Boolean(_)) => Some(value),
+            (SettingKind::Integer { min, max, .. }, SettingValue::Integer(number)) => {
+                Some(SettingValue::Integer(number.max(min).min(max)))
+            },
+            (SettingKind::Float { min, max, .. }, SettingValue::Float(number)) => {
+                Some(SettingValue::Float(number.max(min).min(max)))
+            },
+            (SettingKind::Enum { options }, SettingValue::Enum(index)) => {
+                if index < options.len() {
+                    Some(value)
+                } else {
+                    None
+                }
+            },
+            _ => None,
+        }
+    }
+
+    /// Change integer, float and enum values by `steps` times the setting's step size.
+    ///
+    /// Enum values will wrap around to the other end of the option list.
+    /// Boolean values will not be changed.
+    pub fn step(&self, value: SettingValue, steps: i32) -> SettingValue {
+        match (*self, value) {
+            (SettingKind::Integer { min, max, step }, SettingValue::Integer(number)) => {
+                SettingValue::Integer((number + step * steps).max(min).min(max))
+            },
+            (SettingKind::Float { min, max, step }, SettingValue::Float(number)) => {
+                SettingValue::Float((number + step * steps as f32).max(min).min(max))
+            },
+            (SettingKind::Enum { options }, SettingValue::Enum(index)) => {
+                let count = options.len() as i32;
+                let new_index = ((index as i32 + steps) % count + count) % count;
+                SettingValue::Enum(new_index as usize)
+            },
+            _ => value,
+        }
+    }
+
+    /// Value after user selects the setting from the menu.
+    ///
+    /// Boolean value will be toggled and enum value will change
+    /// to the next option. Other values will not be changed.
+    pub fn activate(&self, value: SettingValue) -> SettingValue {
+        match (*self, value) {
+            (SettingKind::Boolean, SettingValue::Boolean(value)) => SettingValue::Boolean(!value),
+            (SettingKind::Enum { .. }, SettingValue::Enum(_)) => self.step(value, 1),
+            _ => value,
+        }
+    }
+
+    /// Parse value from text.
+    ///
+    /// Value will not be clamped to setting's value range, use method `validate` for that.
+    ///
+    /// Returns `Err(error_message)` if value can't be parsed.
+    pub fn parse(&self, text: &str) -> Result<SettingValue, String> {
+        let value = match *self {
+            SettingKind::Boolean => {
+                if text == "true" {
+                    SettingValue::Boolean(true)
+                } else if text == "false" {
+                    SettingValue::Boolean(false)
+                } else {
+                    return Err("not a boolean value".to_string());
+                }
+            },
+            SettingKind::Integer { .. } => {
+                SettingValue::Integer(text.parse::<i32>().map_err(|error| error.to_string())?)
+            },
+            SettingKind::Float { .. } => {
+                SettingValue::Float(text.parse::<f32>().map_err(|error| error.to_string())?)
+            },
+            SettingKind::Enum { options } => {
+                match options.iter().position(|option| option.key == text) {
+                    Some(index) => SettingValue::Enum(index),
+                    None => return Err("unknown option".to_string()),
+                }
+            },
+        };
+
+        Ok(value)
+    }
+
+    /// Convert TOML value from the settings file to setting value.
+    ///
+    /// Value will not be clamped to setting's value range, use method `validate` for that.
+    ///
+    /// Returns `Err(error_message)` if value type is incorrect.
+    pub fn from_toml(&self, value: &Value) -> Result<SettingValue, String> {
+        match (*self, value) {
+            (SettingKind::Boolean, &Value::Boolean(value)) => Ok(SettingValue::Boolean(value)),
+            (SettingKind::Integer { .. }, &Value::Integer(number)) => {
+                if number < i32::min_value() as i64 || number > i32::max_value() as i64 {
+                    Err(format!("integer {} is too large", number))
+                } else {
+                    Ok(SettingValue::Integer(number as i32))
+                }
+            },
+            (SettingKind::Float { .. }, &Value::Float(number)) => Ok(SettingValue::Float(number as f32)),
+            (SettingKind::Float { .. }, &Value::Integer(number)) => Ok(SettingValue::Float(number as f32)),
+            (SettingKind::Enum { .. }, &Value::String(ref text)) => self.parse(text),
+            (SettingKind::Boolean, _) => Err("not a boolean value".to_string()),
+            (SettingKind::Integer { .. }, _) => Err("not an integer value".to_string()),
+            (SettingKind::Float { .. }, _) => Err("not a float value".to_string()),
+            (SettingKind::Enum { .. }, _) => Err("not a string value".to_string()),
+        }
+    }
+
+    /// Convert setting value to TOML value for the settings file.
+    pub fn to_toml(&self, value: SettingValue) -> Value {
+        match (*self, value) {
+            (SettingKind::Enum { options }, SettingValue::Enum(index)) => Value::String(options[index].key.to_string()),
+            (_, SettingValue::Boolean(value)) => Value::Boolean(value),
+            (_, SettingValue::Integer(value)) => Value::Integer(value as i64),
+            (_, SettingValue::Float(value)) => Value::Float(value as f64),
+            (_, SettingValue::Enum(index)) => Value::Integer(index as i64),
+        }
+    }
+
+    /// Convert value to text.
+    pub fn format(&self, value: SettingValue) -> String {
+        match (*self, value) {
+            (SettingKind::Enum { options }, SettingValue::Enum(index)) => options[index].key.to_string(),
+            (_, SettingValue::Boolean(value)) => value.to_string(),
+            (_, SettingValue::Integer(value)) => value.to_string(),
+            (_, SettingValue::Float(value)) => value.to_string(),
+            (_, SettingValue::Enum(index)) => index.to_string(),
+        }
+    }
+
+    /// Convert value to text which is displayed in the GUI.
+    pub fn display_text(&self, value: SettingValue, locale: &Locale) -> String {
+        match (*self, value) {
+            (_, SettingValue::Boolean(true)) => locale.text("settings_menu.enabled"),
+            (_, SettingValue::Boolean(false)) => locale.text("settings_menu.disabled"),
+            (SettingKind::Enum { options }, SettingValue::Enum(index)) => {
+                match options[index].text_key {
+                    Some(text_key) => locale.text(text_key),
+                    None => options[index].key.to_string(),
+                }
+            },
+            (_, SettingValue::Float(value)) => format!("{:.1}", value),
+            _ => self.format(value),
+        }
+    }
+}
+
+/// Key and value type of a setting.
+#[derive(Copy, Clone, Debug)]
+pub struct SettingDefinition {
+    /// Setting's name in the settings file. Changing the key of an existing setting
+    /// will make the game ignore setting's value in existing settings files.
+    pub key: &'static str,
+    pub kind: SettingKind,
+}
+
+impl SettingDefinition {
+    /// Setting's name in the GUI.
+    pub fn display_name(&self, locale: &Locale) -> String {
+        locale.text(&format!("setting.{}", self.key))
+    }
+}
+
+/// Default values which depend on the game's window and audio backend.
+pub struct BackendDefaults {
+    pub effect_volume_percentage: i32,
+    pub music_volume_percentage: i32,
+}
+
+/// Game's components which settings can change.
+pub struct SettingApplyContext<'a, T: Renderer + 'a, W: Window + 'a, P: AudioPlayer + 'a> {
+    pub renderer: &'a mut T,
+    pub gui: &'a mut GUI,
+    pub logic: &'a mut Logic,
+    pub audio_manager: &'a mut AudioManager<P>,
+    pub window: &'a mut W,
+    pub frame_limiter: &'a mut FrameLimiter,
+}
+
+impl <'a, T: Renderer, W: Window, P: AudioPlayer> SettingApplyContext<'a, T, W, P> {
+    /// Update renderer, GUI and game logic to match
+    /// with window's current size.
+    pub fn update_screen_size(&mut self) {
+        let (width, height) = self.window.drawable_size();
+        self.renderer.update_screen_size(width as i32, height as i32);
+
+        let half_screen_width = self.renderer.half_screen_width_world_coordinates();
+        self.gui.update_position_from_half_screen_width(half_screen_width);
+        self.logic.update_half_screen_width(half_screen_width);
+    }
+}
+
+/// Options for setting `SettingId::Language`. Option key
+/// is the name of language's string table file.
+pub const LANGUAGE_OPTIONS: &'static [SettingOption] = &[
+    SettingOption { key: "en", text_key: Some("language.en") },
+    SettingOption { key: "fi", text_key: Some("language.fi") },
+    SettingOption { key: "de", text_key: Some("language.de") },
+];
+
+/// Options for setting `SettingId::DisplayMode`. Order of
+/// the options must match with `DisplayMode` enum.
+pub const DISPLAY_MODE_OPTIONS: &'static [SettingOption] = &[
+    SettingOption { key: "windowed", text_key: Some("option.windowed") },
+    SettingOption { key: "borderless", text_key: Some("option.borderless") },
+    SettingOption { key: "exclusive", text_key: Some("option.exclusive") },
+];
+
+/// Options for setting `SettingId::Resolution`. Option
+/// key must have format `WIDTHxHEIGHT`.
+pub const RESOLUTION_OPTIONS: &'static [SettingOption] = &[
+    SettingOption { key: "640x480", text_key: None },
+    SettingOption { key: "800x600", text_key: None },
+    SettingOption { key: "1024x768", text_key: None },
+    SettingOption { key: "1280x720", text_key: None },
+    SettingOption { key: "1366x768", text_key: None },
+    SettingOption { key: "1600x900", text_key: None },
+    SettingOption { key: "1920x1080", text_key: None },
+];
+
+/// Options for setting `SettingId::FpsCap`. Option key
+/// must be frames per second or `unlimited`.
+pub const FPS_CAP_OPTIONS: &'static [SettingOption] = &[
+    SettingOption { key: "30", text_key: None },
+    SettingOption { key: "60", text_key: None },
+    SettingOption { key: "120", text_key: None },
+    SettingOption { key: "144", text_key: None },
+    SettingOption { key: "unlimited", text_key: Some("option.unlimited") },
+];
+
+/// Macro for defining all settings.
+///
+/// Creates enum `SettingId`, const `ALL_SETTINGS` and `SettingId`'s
+/// methods `definition`, `default_value` and `apply` from setting definitions.
+macro_rules! settings_registry {
+    ( $( $(#[$attribute:meta])* $id:ident {
+            key: $key:expr,
+            kind: $kind:expr,
+            default: |$defaults:pat| $default:expr,
+            apply: |$value:pat, $game:ident| $apply:expr,
+        } ),+ $(,)* ) => {
+
+        /// Identifier of a setting.
+        #[derive(Copy, Clone, Debug, PartialEq)]
+        pub enum SettingId {
+            $( $(#[$attribute])* $id, )+
+        }
+
+        /// All settings in the order they are defined.
+        pub const ALL_SETTINGS: &'static [SettingId] = &[ $( SettingId::$id, )+ ];
+
+        impl SettingId {
+            /// Get setting's definition.
+            pub fn definition(self) -> SettingDefinition {
+                match self {
+                    $( SettingId::$id => SettingDefinition { key: $key, kind: $kind }, )+
+                }
+            }
+
+            /// Get setting's default value.
+            pub fn default_value(self, defaults: &BackendDefaults) -> SettingValue {
+                match self {
+                    $( SettingId::$id => {
+                        let $defaults = defaults;
+                        $default
+                    }, )+
+                }
+            }
+
+            /// Apply setting value to game's components.
+            pub fn apply<T: Renderer, W: Window, P: AudioPlayer>(self, value: SettingValue, game: &mut SettingApplyContext<T, W, P>) {
+                match self {
+                    $( SettingId::$id => {
+                        let $value = value;
+                        let $game = &mut *game;
+                        $apply;
+                    }, )+
+                }
+            }
+        }
+    };
+}
+
+settings_registry! {
+    /// Language of GUI texts.
+    Language {
+        key: "language",
+        kind: SettingKind::Enum { options: LANGUAGE_OPTIONS },
+        default: |_| SettingValue::Enum(0),
+        apply: |value, game| game.gui.set_language(value.enum_index()),
+    },
+    /// Windowed or full screen mode.
+    DisplayMode {
+        key: "display_mode",
+        kind: SettingKind::Enum { options: DISPLAY_MODE_OPTIONS },
+        default: |_| SettingValue::Enum(DisplayMode::Windowed as usize),
+        apply: |value, game| {
+            game.window.set_display_mode(DisplayMode::from_index(value.enum_index()));
+            game.update_screen_size();
+        },
+    },
+    /// Window size in windowed mode and display resolution
+    /// in exclusive full screen mode.
+    Resolution {
+        key: "resolution",
+        kind: SettingKind::Enum { options: RESOLUTION_OPTIONS },
+        default: |_| SettingValue::Enum(0),
+        apply: |value, game| {
+            if let Ok((width, height)) = parse_window_size(RESOLUTION_OPTIONS[value.enum_index()].key) {
+                game.window.set_resolution(width, height);
+                game.update_screen_size();
+            }
+        },
+    },
+    /// Show `GUIFpsCounter`.
+    ShowFpsCounter {
+        key: "fps_counter",
+        kind: SettingKind::Boolean,
+        default: |_| SettingValue::Boolean(false),
+        apply: |value, game| game.gui.set_show_fps_counter(value.boolean()),
+    },
+    /// Vertical synchronization.
+    VSync {
+        key: "vsync",
+        kind: SettingKind::Boolean,
+        default: |_| SettingValue::Boolean(true),
+        apply: |value, game| game.window.set_v_sync(value.boolean()),
+    },
+    /// Max frames per second. Useful when VSync is disabled.
+    FpsCap {
+        key: "fps_cap",
+        kind: SettingKind::Enum { options: FPS_CAP_OPTIONS },
+        default: |_| SettingValue::Enum(FPS_CAP_OPTIONS.len() - 1),
+        apply: |value, game| {
+            let fps_cap = FPS_CAP_OPTIONS[value.enum_index()].key.parse::<u32>().ok();
+            game.frame_limiter.set_fps_cap(fps_cap);
+        },
+    },
+    /// Glow around lasers and other bright objects.
+    Bloom {
+        key: "bloom",
+        kind: SettingKind::Boolean,
+        default: |_| SettingValue::Boolean(false),
+        apply: |value, game| game.renderer.set_post_processing_effect(PostProcessingEffect::Bloom, value.boolean()),
+    },
+    /// CRT monitor scanlines and screen curvature.
+    CrtEffect {
+        key: "crt_effect",
+        kind: SettingKind::Boolean,
+        default: |_| SettingValue::Boolean(false),
+        apply: |value, game| game.renderer.set_post_processing_effect(PostProcessingEffect::Crt, value.boolean()),
+    },
+    /// Darken screen edges.
+    Vignette {
+        key: "vignette",
+        kind: SettingKind::Boolean,
+        default: |_| SettingValue::Boolean(false),
+        apply: |value, game| game.renderer.set_post_processing_effect(PostProcessingEffect::Vignette, value.boolean()),
+    },
+    /// Camera shake on laser bomb explosions. Can be
+    /// disabled for accessibility.
+    ScreenShake {
+        key: "screen_shake",
+        kind: SettingKind::Boolean,
+        default: |_| SettingValue::Boolean(true),
+        apply: |value, game| game.logic.set_screen_shake(value.boolean()),
+    },
+    /// Replace sliding menu transitions with fades and
+    /// disable button highlight animations.
+    ReducedMotion {
+        key: "reduced_motion",
+        kind: SettingKind::Boolean,
+        default: |_| SettingValue::Boolean(false),
+        apply: |value, game| game.gui.set_reduced_motion(value.boolean()),
+    },
+    /// Music volume percentage.
+    MusicVolume {
+        key: "music_volume",
+        kind: SettingKind::Integer { min: 0, max: 100, step: 20 },
+        default: |defaults| SettingValue::Integer(defaults.music_volume_percentage),
+        apply: |value, game| game.audio_manager.set_music_volume(value.integer()),
+    },
+    /// Sound effect volume percentage.
+    SoundEffectVolume {
+        key: "effect_volume",
+        kind: SettingKind::Integer { min: 0, max: 100, step: 20 },
+        default: |defaults| SettingValue::Integer(defaults.effect_volume_percentage),
+        apply: |value, game| game.audio_manager.set_sound_effect_volume(value.integer()),
+    },
+}
@@ -0,0 +1,35 @@
+#version 330 core
+
+/*
+src/shaders/gl/bloom-blur-fragment.glsl, 2017-09-22
+
+Copyright (c) 2017 Juuso Tuononen
+
+This file is licensed under
+
+Apache License, Version 2.0
+
+or
+
+MIT License
+*/
+
+// OpenGL 3.3 fragment shader for one direction of separable Gaussian blur.
+// Uniform "direction" is distance between texels in blur direction.
+
+in vec2 texture_coordinates;
+out vec4 color;
+
+uniform sampler2D bloom_sampler;
+uniform vec2 direction;
+
+void main() {
+    vec3 sum = texture(bloom_sampler, texture_coordinates).rgb * 0.227027;
+
+    sum += texture(bloom_sampler, texture_coordinates + direction * 1.384615).rgb * 0.316216;
+    sum += texture(bloom_sampler, texture_coordinates - direction * 1.384615).rgb * 0.316216;
+    sum += texture(bloom_sampler, texture_coordinates + direction * 3.230769).rgb * 0.070270;
+    sum += texture(bloom_sampler, texture_coordinates - direction * 3.230769).rgb * 0.070270;
+
+    color = vec4(sum, 1.0);
+}
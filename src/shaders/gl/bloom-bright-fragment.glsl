@@ -0,0 +1,30 @@
+#version 330 core
+
+/*
+src/shaders/gl/bloom-bright-fragment.glsl, 2017-09-22
+
+Copyright (c) 2017 Juuso Tuononen
+
+This file is licensed under
+
+Apache License, Version 2.0
+
+or
+
+MIT License
+*/
+
+// OpenGL 3.3 fragment shader for extracting bright colors like lasers
+// from the scene for the bloom effect.
+
+in vec2 texture_coordinates;
+out vec4 color;
+
+uniform sampler2D scene_sampler;
+
+void main() {
+    vec3 scene = texture(scene_sampler, texture_coordinates).rgb;
+    float brightness = max(scene.r, max(scene.g, scene.b));
+
+    color = vec4(scene * smoothstep(0.4, 1.0, brightness), 1.0);
+}
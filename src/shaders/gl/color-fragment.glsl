@@ -14,12 +14,12 @@ or
 MIT License
 */
 
-// OpenGL 3.3 fragment shader for rendering with specific color.
+// OpenGL 3.3 fragment shader for rendering with vertex color.
 
 out vec4 color_out;
 
-uniform vec3 color;
+in vec4 color;
 
 void main() {
-    color_out = vec4(color,1.0);
+    color_out = color;
 }
\ No newline at end of file
@@ -14,13 +14,18 @@ or
 MIT License
 */
 
-// OpenGL 3.3 vertex shader for rendering with specific color.
+// OpenGL 3.3 vertex shader for rendering colored sprite batches.
+// Vertices are already in world coordinates.
 
 in vec3 vertex;
+in vec4 color_attribute;
+
+out vec4 color;
 
-uniform mat4 M;
 uniform mat4 P;
 
 void main() {
-    gl_Position = P * M * vec4(vertex, 1.0);
+    gl_Position = P * vec4(vertex, 1.0);
+
+    color = color_attribute;
 }
@@ -15,16 +15,24 @@ MIT License
 */
 
 // OpenGL 3.3 fragment shader for rendering a texture.
+// Texture color is multiplied with vertex color and
+// then mixed with white color by uniform flash.
 
 in vec2 texture_coordinates;
-out vec4 color;
+in vec4 color;
+out vec4 color_out;
 
 uniform sampler2D texture_sampler;
+uniform float flash;
 
 void main() {
-    color = texture(texture_sampler, texture_coordinates);
+    vec4 texture_color = texture(texture_sampler, texture_coordinates);
 
-    if (color.a < 0.5) {
+    if (texture_color.a < 0.5) {
         discard;
     }
+
+    vec4 final_color = texture_color * color;
+
+    color_out = vec4(mix(final_color.rgb, vec3(1.0), flash), final_color.a);
 }
\ No newline at end of file
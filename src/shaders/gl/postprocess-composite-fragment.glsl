@@ -0,0 +1,56 @@
+#version 330 core
+
+/*
+src/shaders/gl/postprocess-composite-fragment.glsl, 2017-09-22
+
+Copyright (c) 2017 Juuso Tuononen
+
+This file is licensed under
+
+Apache License, Version 2.0
+
+or
+
+MIT License
+*/
+
+// OpenGL 3.3 fragment shader for combining the scene and bloom, and
+// applying CRT and vignette effects. Effects are disabled
+// when their uniform value is zero.
+
+in vec2 texture_coordinates;
+out vec4 color;
+
+uniform sampler2D scene_sampler;
+uniform sampler2D bloom_sampler;
+uniform float bloom_intensity;
+uniform float crt;
+uniform float vignette;
+uniform vec2 screen_size;
+
+void main() {
+    vec2 coordinates = texture_coordinates;
+
+    // CRT screen curvature.
+    vec2 centered = coordinates * 2.0 - 1.0;
+    centered *= 1.0 + crt * 0.08 * dot(centered, centered);
+    coordinates = centered * 0.5 + 0.5;
+
+    if (coordinates.x < 0.0 || coordinates.x > 1.0 || coordinates.y < 0.0 || coordinates.y > 1.0) {
+        color = vec4(0.0, 0.0, 0.0, 1.0);
+        return;
+    }
+
+    vec3 result = texture(scene_sampler, coordinates).rgb;
+    result += texture(bloom_sampler, coordinates).rgb * bloom_intensity;
+
+    // CRT scanlines. There is a dark line at every second pixel row.
+    float scanline = 0.8 + 0.2 * sin(coordinates.y * screen_size.y * 3.14159);
+    result *= mix(1.0, scanline, crt);
+
+    // Vignette.
+    float edge = 1.0 - smoothstep(0.4, 0.85, length(texture_coordinates - 0.5));
+    result *= mix(1.0, edge, vignette);
+
+    color = vec4(result, 1.0);
+}
@@ -0,0 +1,29 @@
+#version 330 core
+
+/*
+src/shaders/gl/postprocess-vertex.glsl, 2017-09-22
+
+Copyright (c) 2017 Juuso Tuononen
+
+This file is licensed under
+
+Apache License, Version 2.0
+
+or
+
+MIT License
+*/
+
+// OpenGL 3.3 vertex shader for post-processing. Renders
+// full screen quad, vertices are in normalized device coordinates.
+
+in vec3 vertex;
+in vec2 texture_coordinates_attribute;
+
+out vec2 texture_coordinates;
+
+void main() {
+    gl_Position = vec4(vertex, 1.0);
+
+    texture_coordinates = texture_coordinates_attribute;
+}
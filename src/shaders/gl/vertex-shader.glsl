@@ -14,19 +14,22 @@ or
 MIT License
 */
 
-// OpenGL 3.3 vertex shader for rendering a texture.
+// OpenGL 3.3 vertex shader for rendering textured sprite batches.
+// Vertices are already in world coordinates.
 
 in vec3 vertex;
 in vec2 texture_coordinates_attribute;
+in vec4 color_attribute;
 
 out vec2 texture_coordinates;
+out vec4 color;
 
-uniform mat4 M;
 uniform mat4 P;
 
 void main() {
 
-    gl_Position = P * M * vec4(vertex, 1.0);
+    gl_Position = P * vec4(vertex, 1.0);
 
     texture_coordinates = vec2(texture_coordinates_attribute.x,-texture_coordinates_attribute.y);
+    color = color_attribute;
 }
@@ -0,0 +1,36 @@
+#version 100
+
+/*
+src/shaders/gles/bloom-blur-fragment-gles.glsl, 2017-09-22
+
+Copyright (c) 2017 Juuso Tuononen
+
+This file is licensed under
+
+Apache License, Version 2.0
+
+or
+
+MIT License
+*/
+
+// OpenGL ES 2.0 fragment shader for one direction of separable Gaussian blur.
+// Uniform "direction" is distance between texels in blur direction.
+
+precision mediump float;
+
+varying vec2 texture_coordinates;
+
+uniform sampler2D bloom_sampler;
+uniform vec2 direction;
+
+void main() {
+    vec3 sum = texture2D(bloom_sampler, texture_coordinates).rgb * 0.227027;
+
+    sum += texture2D(bloom_sampler, texture_coordinates + direction * 1.384615).rgb * 0.316216;
+    sum += texture2D(bloom_sampler, texture_coordinates - direction * 1.384615).rgb * 0.316216;
+    sum += texture2D(bloom_sampler, texture_coordinates + direction * 3.230769).rgb * 0.070270;
+    sum += texture2D(bloom_sampler, texture_coordinates - direction * 3.230769).rgb * 0.070270;
+
+    gl_FragColor = vec4(sum, 1.0);
+}
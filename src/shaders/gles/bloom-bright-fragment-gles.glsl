@@ -0,0 +1,31 @@
+#version 100
+
+/*
+src/shaders/gles/bloom-bright-fragment-gles.glsl, 2017-09-22
+
+Copyright (c) 2017 Juuso Tuononen
+
+This file is licensed under
+
+Apache License, Version 2.0
+
+or
+
+MIT License
+*/
+
+// OpenGL ES 2.0 fragment shader for extracting bright colors like lasers
+// from the scene for the bloom effect.
+
+precision mediump float;
+
+varying vec2 texture_coordinates;
+
+uniform sampler2D scene_sampler;
+
+void main() {
+    vec3 scene = texture2D(scene_sampler, texture_coordinates).rgb;
+    float brightness = max(scene.r, max(scene.g, scene.b));
+
+    gl_FragColor = vec4(scene * smoothstep(0.4, 1.0, brightness), 1.0);
+}
@@ -14,12 +14,12 @@ or
 MIT License
 */
 
-// OpenGL ES 2.0 fragment shader for rendering with specific color.
+// OpenGL ES 2.0 fragment shader for rendering with vertex color.
 
 precision mediump float;
 
-uniform vec3 color;
+varying vec4 color;
 
 void main() {
-    gl_FragColor = vec4(color,1.0);
+    gl_FragColor = color;
 }
\ No newline at end of file
@@ -14,13 +14,18 @@ or
 MIT License
 */
 
-// OpenGL ES 2.0 vertex shader for rendering with specific color.
+// OpenGL ES 2.0 vertex shader for rendering colored sprite batches.
+// Vertices are already in world coordinates.
 
 attribute vec3 vertex;
+attribute vec4 color_attribute;
+
+varying vec4 color;
 
-uniform mat4 M;
 uniform mat4 P;
 
 void main() {
-    gl_Position = P * M * vec4(vertex, 1.0);
+    gl_Position = P * vec4(vertex, 1.0);
+
+    color = color_attribute;
 }
@@ -15,17 +15,25 @@ MIT License
 */
 
 // OpenGL ES 2.0 fragment shader for rendering a texture.
+// Texture color is multiplied with vertex color and
+// then mixed with white color by uniform flash.
 
 precision mediump float;
 
 varying vec2 texture_coordinates;
+varying vec4 color;
 
 uniform sampler2D texture_sampler;
+uniform float flash;
 
 void main() {
-    gl_FragColor = texture2D(texture_sampler, texture_coordinates);
+    vec4 texture_color = texture2D(texture_sampler, texture_coordinates);
 
-    if (gl_FragColor.a < 0.5) {
+    if (texture_color.a < 0.5) {
         discard;
     }
+
+    vec4 final_color = texture_color * color;
+
+    gl_FragColor = vec4(mix(final_color.rgb, vec3(1.0), flash), final_color.a);
 }
\ No newline at end of file
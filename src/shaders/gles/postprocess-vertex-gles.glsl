@@ -0,0 +1,29 @@
+#version 100
+
+/*
+src/shaders/gles/postprocess-vertex-gles.glsl, 2017-09-22
+
+Copyright (c) 2017 Juuso Tuononen
+
+This file is licensed under
+
+Apache License, Version 2.0
+
+or
+
+MIT License
+*/
+
+// OpenGL ES 2.0 vertex shader for post-processing. Renders
+// full screen quad, vertices are in normalized device coordinates.
+
+attribute vec3 vertex;
+attribute vec2 texture_coordinates_attribute;
+
+varying vec2 texture_coordinates;
+
+void main() {
+    gl_Position = vec4(vertex, 1.0);
+
+    texture_coordinates = texture_coordinates_attribute;
+}
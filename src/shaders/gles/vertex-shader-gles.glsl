@@ -14,18 +14,21 @@ or
 MIT License
 */
 
-// OpenGL ES 2.0 vertex shader for rendering a texture.
+// OpenGL ES 2.0 vertex shader for rendering textured sprite batches.
+// Vertices are already in world coordinates.
 
 attribute vec3 vertex;
 attribute vec2 texture_coordinates_attribute;
+attribute vec4 color_attribute;
 
 varying vec2 texture_coordinates;
+varying vec4 color;
 
-uniform mat4 M;
 uniform mat4 P;
 
 void main() {
-    gl_Position = P * M * vec4(vertex, 1.0);
+    gl_Position = P * vec4(vertex, 1.0);
 
     texture_coordinates = vec2(texture_coordinates_attribute.x,-texture_coordinates_attribute.y);
+    color = color_attribute;
 }
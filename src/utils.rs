@@ -14,20 +14,31 @@ MIT License
 
 //! Miscellaneous utilities.
 
-use std::time::Instant;
+use std::time::{Duration, Instant};
+use std::thread;
+use std::cmp;
 use LOGIC_TARGET_FPS;
-use LOGIC_MAX_FPS;
+use LOGIC_UPDATE_MICROSECONDS;
 const TARGET_FRAME_TIME_MICROSECONDS: f32 = 1_000_000.0 / LOGIC_TARGET_FPS as f32;
 
-const DELTA_TIME_AT_LOGIC_MAX_FPS: f32 = LOGIC_TARGET_FPS as f32 / LOGIC_MAX_FPS as f32;
+/// Delta time of one logic update when time scale is 1.0.
+const LOGIC_UPDATE_DELTA_TIME: f32 = LOGIC_UPDATE_MICROSECONDS as f32 / TARGET_FRAME_TIME_MICROSECONDS;
 
-const LOGIC_MAX_UPDATES_MICROSECONDS: u32 = 1_000_000/LOGIC_MAX_FPS;
+/// Max value for time which `GameLoopTimer` has not yet run logic updates for.
+/// Logic will not try to catch up all the time after long stalls like window moving.
+const GAME_LOOP_MAX_ACCUMULATED_MILLISECONDS: u64 = 250;
+
+/// `FrameLimiter` sleeps until there is this much time left to
+/// the next frame and then waits the rest of the time in a busy loop.
+/// Sleeping is not accurate enough for the whole waiting time.
+const FRAME_LIMITER_SPIN_TIME_MICROSECONDS: u32 = 2000;
 
 /// Fps counter.
 pub struct FpsCounter {
     frame_count: u32,
     update_time: Timer,
     fps: u32,
+    frame_time_statistics: Option<FrameTimeStatistics>,
 }
 
 impl FpsCounter {
@@ -37,15 +48,30 @@ impl FpsCounter {
             frame_count: 0,
             update_time: Timer::new(),
             fps: 0,
+            frame_time_statistics: None,
+        }
+    }
+
+    /// Enable or disable collecting frame time statistics.
+    pub fn set_frame_time_statistics(&mut self, enabled: bool) {
+        if enabled {
+            self.frame_time_statistics = Some(FrameTimeStatistics::new());
+        } else {
+            self.frame_time_statistics = None;
         }
     }
 
     /// Add one frame to frame count.
     pub fn frame(&mut self) {
         self.frame_count += 1;
+
+        if let Some(ref mut statistics) = self.frame_time_statistics {
+            statistics.frame();
+        }
     }
 
-    /// Print fps to standard output.
+    /// Print fps to standard output. Fps is printed only when
+    /// user requests it, so it is not filtered with log level.
     fn print(&self) {
         println!("fps: {}", self.fps);
     }
@@ -54,6 +80,9 @@ impl FpsCounter {
     ///
     /// Returns true if the update happened. If argument `print_fps` is true,
     /// print method will be called when fps update happens.
+    ///
+    /// Frame time statistics will be printed and reset when
+    /// fps update happens.
     pub fn update(&mut self, current_time: &TimeMilliseconds, print_fps: bool) -> bool {
         if self.update_time.check(current_time, 1000) {
             self.fps = self.frame_count;
@@ -62,6 +91,11 @@ impl FpsCounter {
                 self.print();
             }
 
+            if let Some(ref mut statistics) = self.frame_time_statistics {
+                statistics.print();
+                statistics.reset();
+            }
+
             self.frame_count = 0;
 
             true
@@ -77,52 +111,194 @@ impl FpsCounter {
 
 }
 
+/// Frame times between calls of method `frame`.
+pub struct FrameTimeStatistics {
+    previous_frame: Option<Instant>,
+    frame_times_microseconds: Vec<u32>,
+}
+
+impl FrameTimeStatistics {
+    /// Create new `FrameTimeStatistics`.
+    pub fn new() -> FrameTimeStatistics {
+        FrameTimeStatistics {
+            previous_frame: None,
+            frame_times_microseconds: Vec::with_capacity(200),
+        }
+    }
+
+    /// Store time from previous frame.
+    pub fn frame(&mut self) {
+        let current_time = Instant::now();
+
+        if let Some(previous_frame) = self.previous_frame {
+            let time = current_time.duration_since(previous_frame);
+            self.frame_times_microseconds.push(time.subsec_nanos() / 1000 + (time.as_secs() as u32)*1_000_000);
+        }
+
+        self.previous_frame = Some(current_time);
+    }
+
+    /// Remove stored frame times.
+    pub fn reset(&mut self) {
+        self.frame_times_microseconds.clear();
+    }
+
+    /// Print min, average and max frame times and frame time percentiles
+    /// to standard output.
+    pub fn print(&mut self) {
+        if self.frame_times_microseconds.len() == 0 {
+            return;
+        }
+
+        self.frame_times_microseconds.sort();
+
+        let frame_times = &self.frame_times_microseconds;
+        let sum: u64 = frame_times.iter().map(|&time| time as u64).sum();
+        let average = sum / frame_times.len() as u64;
+
+        let percentile = |percent: usize| {
+            let index = (frame_times.len() * percent + 99) / 100;
+            frame_times[index.max(1) - 1]
+        };
+
+        let milliseconds = |microseconds: u32| microseconds as f32 / 1000.0;
+
+        println!("frame time ms: min {:.2}, avg {:.2}, max {:.2}, 50% {:.2}, 95% {:.2}, 99% {:.2}",
+            milliseconds(frame_times[0]),
+            milliseconds(average as u32),
+            milliseconds(frame_times[frame_times.len() - 1]),
+            milliseconds(percentile(50)),
+            milliseconds(percentile(95)),
+            milliseconds(percentile(99)),
+        );
+    }
+}
+
+/// Limit frame rate by waiting before the next frame.
+pub struct FrameLimiter {
+    frame_time: Option<Duration>,
+    next_frame: Instant,
+}
+
+impl FrameLimiter {
+    /// Create new `FrameLimiter` without frame rate limit.
+    pub fn new() -> FrameLimiter {
+        FrameLimiter {
+            frame_time: None,
+            next_frame: Instant::now(),
+        }
+    }
+
+    /// Set max frames per second. Argument `None` disables
+    /// the frame rate limit.
+    pub fn set_fps_cap(&mut self, fps_cap: Option<u32>) {
+        self.frame_time = fps_cap.map(|fps| Duration::new(0, 1_000_000_000 / fps.max(1)));
+        self.next_frame = Instant::now();
+    }
+
+    /// Wait until it is time for the next frame.
+    ///
+    /// If previous frames took too much time, limiter will not try to
+    /// catch up by skipping waiting for multiple frames.
+    pub fn wait(&mut self) {
+        let frame_time = match self.frame_time {
+            Some(frame_time) => frame_time,
+            None => return,
+        };
+
+        let spin_time = Duration::new(0, FRAME_LIMITER_SPIN_TIME_MICROSECONDS * 1000);
+
+        let mut current_time = Instant::now();
+
+        if current_time < self.next_frame {
+            let wait_time = self.next_frame - current_time;
+
+            if wait_time > spin_time {
+                thread::sleep(wait_time - spin_time);
+            }
+
+            while current_time < self.next_frame {
+                current_time = Instant::now();
+            }
+        }
+
+        self.next_frame += frame_time;
+
+        if self.next_frame < current_time {
+            self.next_frame = current_time + frame_time;
+        }
+    }
+}
+
 /// Handle timing of logic updates.
+///
+/// Logic is updated with fixed time step. Elapsed time is accumulated
+/// and every full time step is one logic update. Time left over is used for
+/// interpolating rendering between logic updates.
 pub struct GameLoopTimer {
-    logic_update_time_milliseconds: u32,
-    update_logic: bool,
-    update_timer: Timer,
+    logic_update_time_microseconds: u32,
+    /// Elapsed time which logic is not yet updated for.
+    accumulated_microseconds: u32,
+    previous_update: Option<Instant>,
 }
 
 impl GameLoopTimer {
     /// Create new `GameLoopTimer`.
     ///
-    /// Argument `logic_update_time_milliseconds` is time between logic updates
-    /// in milliseconds.
-    pub fn new(logic_update_time_milliseconds: u32) -> GameLoopTimer {
+    /// Argument `logic_update_time_microseconds` is time between logic updates
+    /// in microseconds.
+    pub fn new(logic_update_time_microseconds: u32) -> GameLoopTimer {
         GameLoopTimer {
-            logic_update_time_milliseconds,
-            update_logic: false,
-            update_timer: Timer::new(),
+            logic_update_time_microseconds,
+            accumulated_microseconds: 0,
+            previous_update: None,
         }
     }
 
-    /// Set `update_logic` field true if time between logic updates is equal or more than field's `logic_update_time_milliseconds` value.
-    pub fn update(&mut self, current_time: &TimeMilliseconds) {
-        if self.update_timer.check(current_time, self.logic_update_time_milliseconds) {
-            self.update_logic = true;
-            self.update_timer.reset(current_time);
+    /// Add time elapsed from the previous call of this method to accumulated time.
+    pub fn update(&mut self, current_time: Instant) {
+        if let Some(previous_update) = self.previous_update {
+            // Max accumulated time is less than one second,
+            // so microseconds can be calculated from subsec_nanos.
+            let max_accumulated_time = Duration::from_millis(GAME_LOOP_MAX_ACCUMULATED_MILLISECONDS);
+            let elapsed = cmp::min(current_time.duration_since(previous_update), max_accumulated_time);
+            let accumulated_microseconds = elapsed.subsec_nanos() / 1000 + self.accumulated_microseconds;
+
+            self.accumulated_microseconds = cmp::min(accumulated_microseconds, max_accumulated_time.subsec_nanos() / 1000);
+        }
+
+        self.previous_update = Some(current_time);
+    }
+
+    /// If this is true, the logic should be updated. Removes one time step from
+    /// accumulated time, so call this in a loop until it returns false.
+    pub fn update_logic(&mut self) -> bool {
+        if self.accumulated_microseconds >= self.logic_update_time_microseconds {
+            self.accumulated_microseconds -= self.logic_update_time_microseconds;
+            true
         } else {
-            self.update_logic = false;
+            false
         }
     }
 
-    /// If this is true, the logic should be updated.
-    pub fn update_logic(&self) -> bool {
-        self.update_logic
+    /// Accumulated time left over from logic updates divided by
+    /// time between logic updates. Value is at range [0.0, 1.0).
+    pub fn interpolation_factor(&self) -> f32 {
+        self.accumulated_microseconds as f32 / self.logic_update_time_microseconds as f32
     }
 }
 
 /// Time handling for game logic.
 ///
-/// Provides delta time for moving objects at constant speed if FPS value is low and
+/// Provides delta time for moving objects at constant speed with fixed logic time step and
 /// game logic specific global time, so pausing the game will not have effect on game logic.
 pub struct GameTimeManager {
     current_game_time: TimeMilliseconds,
     previous_game_time: TimeMilliseconds,
     logic_update_start: Option<Instant>,
     delta_time: f32,
-    previous_frame_update: Instant,
+    /// Speed of game time compared to real time.
+    time_scale: f32,
 }
 
 impl GameTimeManager {
@@ -132,8 +308,8 @@ impl GameTimeManager {
             current_game_time: TimeMilliseconds(0),
             previous_game_time: TimeMilliseconds(0),
             logic_update_start: None,
-            delta_time: 1.0,
-            previous_frame_update: Instant::now(),
+            delta_time: LOGIC_UPDATE_DELTA_TIME,
+            time_scale: 1.0,
         }
     }
 
@@ -144,13 +320,14 @@ impl GameTimeManager {
 
     // FIXME: current_game_time will overflow after some days.
 
-    /// Updates delta time and game time.
+    /// Updates game time.
     fn update(&mut self, current_time: Instant, game_logic_running: bool) {
         // Game time calculations.
         if game_logic_running {
             if let Some(logic_start) = self.logic_update_start {
                 let time = current_time.duration_since(logic_start);
-                self.current_game_time = TimeMilliseconds(self.previous_game_time.0 + time.subsec_nanos() / 1_000_000 + (time.as_secs() as u32)*1000);
+                let milliseconds = time.subsec_nanos() / 1_000_000 + (time.as_secs() as u32)*1000;
+                self.current_game_time = TimeMilliseconds(self.previous_game_time.0 + (milliseconds as f32 * self.time_scale) as u32);
             } else {
                 self.logic_update_start = Some(current_time);
             }
@@ -160,24 +337,26 @@ impl GameTimeManager {
                 self.logic_update_start = None;
             }
         }
+    }
 
-        // Delta time calculations.
-        let microseconds_between_frames = current_time.duration_since(self.previous_frame_update).subsec_nanos() / 1000;
+    /// Set speed of game time compared to real time. Game time
+    /// continues from the current game time.
+    fn set_time_scale(&mut self, time_scale: f32, current_time: Instant) {
+        self.previous_game_time = self.current_game_time.clone();
 
-        if microseconds_between_frames < LOGIC_MAX_UPDATES_MICROSECONDS {
-            self.delta_time = DELTA_TIME_AT_LOGIC_MAX_FPS;
-        } else {
-            self.delta_time = microseconds_between_frames as f32 / TARGET_FRAME_TIME_MICROSECONDS;
+        if self.logic_update_start.is_some() {
+            self.logic_update_start = Some(current_time);
         }
 
-        self.previous_frame_update = current_time;
+        self.time_scale = time_scale;
+        self.delta_time = LOGIC_UPDATE_DELTA_TIME * time_scale;
     }
 
-    /// Difference between real frame time and target frame time. Value should be between [1.0, f32::MAX].
-    ///
-    /// Multiply all movement values in logic code with this, so objects will move at same speed when FPS is low.
+    /// Logic update time step relative to target frame time multiplied
+    /// with the time scale.
     ///
-    /// Note that delta time will not work if updates takes one second or more time.
+    /// Multiply all movement values in logic code with this, so objects will move at
+    /// the same speed regardless of logic update time step.
     pub fn delta_time(&self) -> f32 {
         self.delta_time
     }
@@ -188,6 +367,7 @@ impl GameTimeManager {
 /// Provides current time for game's components.
 pub struct TimeManager {
     current_time: TimeMilliseconds,
+    current_instant: Instant,
     start_time: Instant,
     game_time: GameTimeManager,
 }
@@ -197,6 +377,7 @@ impl TimeManager {
     pub fn new() -> TimeManager {
         TimeManager {
             current_time: TimeMilliseconds(0),
+            current_instant: Instant::now(),
             start_time: Instant::now(),
             game_time: GameTimeManager::new(),
         }
@@ -207,17 +388,29 @@ impl TimeManager {
         &self.current_time
     }
 
+    /// Get current time as `Instant` for timing which
+    /// requires more accuracy than milliseconds.
+    pub fn current_instant(&self) -> Instant {
+        self.current_instant
+    }
+
     /// Get game time manager.
     pub fn game_time_manager(&self) -> &GameTimeManager {
         &self.game_time
     }
 
-    /// Updates `TimeManager`'s current time and `GameTimeManager`'s time and delta time.
+    /// Set speed of game time. Value 1.0 is the normal speed.
+    pub fn set_game_time_scale(&mut self, time_scale: f32) {
+        self.game_time.set_time_scale(time_scale, Instant::now());
+    }
+
+    /// Updates `TimeManager`'s current time and `GameTimeManager`'s time.
     pub fn update_time(&mut self, game_logic_running: bool) {
         let current_instant = Instant::now();
 
         let time = current_instant.duration_since(self.start_time);
         self.current_time = TimeMilliseconds(time.subsec_nanos() / 1_000_000 + (time.as_secs() as u32)*1000);
+        self.current_instant = current_instant;
 
         self.game_time.update(current_instant, game_logic_running);
     }
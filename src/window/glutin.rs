@@ -12,7 +12,7 @@ use logic::Logic;
 use utils::{TimeManager, TimeMilliseconds};
 use audio::{Audio, Volume, AudioPlayer};
 
-use super::{Window, RenderingContext, WINDOW_TITLE};
+use super::{Window, RenderingContext, DisplayMode, WindowGeometry, WINDOW_TITLE};
 
 
 pub struct GlutinWindow {
@@ -21,18 +21,21 @@ pub struct GlutinWindow {
     window: GlWindow,
     mouse_x: i32,
     mouse_y: i32,
+    display_mode: DisplayMode,
+    /// Resolution for windowed mode.
+    resolution: Option<(u32, u32)>,
 }
 
 
 impl Window for GlutinWindow {
     type AudioPlayer = AudioPlayerRodio;
 
-    fn new(rendering_context: RenderingContext) -> Result<Self, ()> {
+    fn new(rendering_context: RenderingContext, geometry: &WindowGeometry) -> Result<Self, ()> {
 
         let events_loop = EventsLoop::new();
         let window_builder = WindowBuilder::new()
             .with_title(WINDOW_TITLE)
-            .with_dimensions(DEFAULT_SCREEN_WIDTH as u32, DEFAULT_SCREEN_HEIGHT as u32)
+            .with_dimensions(geometry.width, geometry.height)
             .with_min_dimensions(DEFAULT_SCREEN_WIDTH as u32, DEFAULT_SCREEN_HEIGHT as u32);
 
         let gl_request = match rendering_context {
@@ -46,24 +49,30 @@ impl Window for GlutinWindow {
         let gl_window = match GlWindow::new(window_builder, context_builder, &events_loop) {
             Ok(window) => window,
             Err(error) => {
-                println!("couldn't create window: {}", error);
+                error!("couldn't create window: {}", error);
                 return Err(());
             }
         };
 
         unsafe {
             if let Err(error) = gl_window.make_current() {
-                println!("couldn't make OpenGL context current: {}", error);
+                error!("couldn't make OpenGL context current: {}", error);
                 return Err(());
             }
         }
 
+        if let Some((x, y)) = geometry.position {
+            gl_window.set_position(x, y);
+        }
+
         let window = Self {
             rendering_context,
             window: gl_window,
             events_loop,
             mouse_x: 0,
             mouse_y: 0,
+            display_mode: DisplayMode::Windowed,
+            resolution: None,
         };
 
         Ok(window)
@@ -79,7 +88,7 @@ impl Window for GlutinWindow {
         quit_flag: &mut bool,
         time_manager: &TimeManager,
     ) {
-        use glutin::{Event, WindowEvent, KeyboardInput, ElementState};
+        use glutin::{Event, WindowEvent, KeyboardInput, ElementState, MouseScrollDelta};
 
         let mouse_x = &mut self.mouse_x;
         let mouse_y = &mut self.mouse_y;
@@ -121,12 +130,29 @@ impl Window for GlutinWindow {
                         WindowEvent::MouseInput { state: ElementState::Released, ..} => {
                             input_manager.update_mouse_button_up(renderer.screen_coordinates_to_world_coordinates(*mouse_x, *mouse_y));
                         },
+                        WindowEvent::MouseInput { state: ElementState::Pressed, ..} => {
+                            input_manager.update_mouse_button_down(renderer.screen_coordinates_to_world_coordinates(*mouse_x, *mouse_y));
+                        },
                         WindowEvent::CursorMoved { position: (x, y), ..} => {
                             *mouse_x = x as i32;
                             *mouse_y = y as i32;
 
                             input_manager.update_mouse_motion(renderer.screen_coordinates_to_world_coordinates(*mouse_x, *mouse_y));
                         },
+                        WindowEvent::ReceivedCharacter(character) => {
+                            input_manager.update_text_input(&character.to_string());
+                        },
+                        WindowEvent::MouseWheel { delta: MouseScrollDelta::LineDelta(_, y), ..} => {
+                            input_manager.update_mouse_wheel(y.round() as i32);
+                        },
+                        WindowEvent::MouseWheel { delta: MouseScrollDelta::PixelDelta(_, y), ..} => {
+                            // Touchpads send small pixel deltas, so scroll one line per event.
+                            if y > 0.0 {
+                                input_manager.update_mouse_wheel(1);
+                            } else if y < 0.0 {
+                                input_manager.update_mouse_wheel(-1);
+                            }
+                        },
                         _ => (),
                     }
                 },
@@ -137,19 +163,58 @@ impl Window for GlutinWindow {
 
     fn swap_buffers(&mut self) -> Result<(), ()> {
         self.window.swap_buffers().map_err(|error| {
-            println!("couldn't swap buffers: {}", error);
+            error!("couldn't swap buffers: {}", error);
         })
     }
 
-    fn set_fullscreen(&mut self, value: bool) {
-        if value {
-            let current_monitor = self.window.get_current_monitor();
-            self.window.set_fullscreen(Some(current_monitor));
-        } else {
-            self.window.set_fullscreen(None);
+    /// Glutin does not support changing display's resolution, so
+    /// exclusive full screen mode is the same as borderless full screen mode.
+    fn set_display_mode(&mut self, display_mode: DisplayMode) {
+        match display_mode {
+            DisplayMode::Windowed => {
+                self.window.set_fullscreen(None);
+
+                if let Some((width, height)) = self.resolution {
+                    self.window.set_inner_size(width, height);
+                }
+            },
+            DisplayMode::BorderlessFullscreen | DisplayMode::ExclusiveFullscreen => {
+                let current_monitor = self.window.get_current_monitor();
+                self.window.set_fullscreen(Some(current_monitor));
+            },
+        }
+
+        self.display_mode = display_mode;
+    }
+
+    fn set_resolution(&mut self, width: u32, height: u32) {
+        let resize_window = self.resolution.is_some();
+
+        self.resolution = Some((width, height));
+
+        if resize_window && self.display_mode == DisplayMode::Windowed {
+            self.window.set_inner_size(width, height);
         }
     }
 
+    fn drawable_size(&self) -> (u32, u32) {
+        self.window.get_inner_size().unwrap_or((DEFAULT_SCREEN_WIDTH as u32, DEFAULT_SCREEN_HEIGHT as u32))
+    }
+
+    fn window_geometry(&self) -> Option<WindowGeometry> {
+        if self.display_mode != DisplayMode::Windowed {
+            return None;
+        }
+
+        self.window.get_inner_size().map(|(width, height)| {
+            WindowGeometry {
+                width,
+                height,
+                position: self.window.get_position(),
+            }
+        })
+    }
+
     fn set_v_sync(&mut self, value: bool) {
         // TODO: glutin window set v-sync setting at runtime
     }
@@ -243,6 +308,9 @@ fn virtual_keycode_to_key(keycode: VirtualKeyCode) -> Option<Key> {
         VirtualKeyCode::Space | VirtualKeyCode::LControl | VirtualKeyCode::RControl => Key::Shoot,
         VirtualKeyCode::Return => Key::Select,
         VirtualKeyCode::Escape  => Key::Back,
+        VirtualKeyCode::F3 => Key::DebugOverlay,
+        VirtualKeyCode::Grave | VirtualKeyCode::F1 => Key::Console,
+        VirtualKeyCode::Back => Key::Backspace,
         _ => return None,
     };
 
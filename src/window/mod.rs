@@ -14,6 +14,7 @@ use gui::GUI;
 use logic::Logic;
 use utils::TimeManager;
 use audio::AudioPlayer;
+use renderer::{DEFAULT_SCREEN_WIDTH, DEFAULT_SCREEN_HEIGHT};
 
 pub const WINDOW_TITLE: &'static str = "Space Boss Battles";
 
@@ -23,10 +24,57 @@ pub enum RenderingContext {
     OpenGLES,
 }
 
+/// How the window is displayed.
+///
+/// Order of the variants must match with the option order of
+/// setting `SettingId::DisplayMode`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DisplayMode {
+    Windowed,
+    /// Full screen window with the same size as the desktop.
+    BorderlessFullscreen,
+    /// Full screen mode which changes display's resolution.
+    ExclusiveFullscreen,
+}
+
+impl DisplayMode {
+    /// Convert setting's option index to `DisplayMode`.
+    pub fn from_index(index: usize) -> DisplayMode {
+        match index {
+            1 => DisplayMode::BorderlessFullscreen,
+            2 => DisplayMode::ExclusiveFullscreen,
+            _ => DisplayMode::Windowed,
+        }
+    }
+}
+
+/// Window size and position in windowed mode.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WindowGeometry {
+    /// Size in pixels.
+    pub width: u32,
+    pub height: u32,
+    /// Position of window's top left corner. If `None`,
+    /// window system will decide window's position.
+    pub position: Option<(i32, i32)>,
+}
+
+impl WindowGeometry {
+    /// Create new `WindowGeometry` with default window size.
+    pub fn new() -> WindowGeometry {
+        WindowGeometry {
+            width: DEFAULT_SCREEN_WIDTH as u32,
+            height: DEFAULT_SCREEN_HEIGHT as u32,
+            position: None,
+        }
+    }
+}
+
 pub trait Window: Sized {
     type AudioPlayer: AudioPlayer;
 
-    fn new(RenderingContext) -> Result<Self, ()>;
+    /// Create new window with size and position from argument `geometry`.
+    fn new(RenderingContext, geometry: &WindowGeometry) -> Result<Self, ()>;
 
     fn handle_events<R: Renderer>(
         &mut self,
@@ -41,7 +89,20 @@ pub trait Window: Sized {
 
     fn swap_buffers(&mut self) -> Result<(), ()>;
 
-    fn set_fullscreen(&mut self, bool);
+    fn set_display_mode(&mut self, DisplayMode);
+
+    /// Set resolution for windowed mode and exclusive full screen mode.
+    ///
+    /// Window will not be resized when resolution is set for the first time,
+    /// so window size from `WindowGeometry` is kept when settings are applied at startup.
+    /// Exclusive full screen mode will use the resolution also when it is set for the first time.
+    fn set_resolution(&mut self, width: u32, height: u32);
+
+    /// Size of window's drawable area in pixels.
+    fn drawable_size(&self) -> (u32, u32);
+
+    /// Current window size and position if window is in windowed mode.
+    fn window_geometry(&self) -> Option<WindowGeometry>;
 
     fn set_v_sync(&mut self, bool);
 
@@ -7,8 +7,10 @@ use sdl2::{EventPump, VideoSubsystem, GameControllerSubsystem, JoystickSubsystem
 use sdl2;
 
 use sdl2::video::{FullscreenType, GLProfile, GLContext};
+use sdl2::pixels::PixelFormatEnum;
 
 use sdl2::keyboard::Keycode;
+use sdl2::mouse::MouseWheelDirection;
 use sdl2::controller::{GameController, Button, Axis};
 
 use sdl2::mixer::{Channel, Chunk, Music};
@@ -16,14 +18,14 @@ use sdl2::mixer;
 
 
 use input::{InputManager, Key, Input};
-use renderer::{Renderer, DEFAULT_SCREEN_HEIGHT, DEFAULT_SCREEN_WIDTH};
+use renderer::Renderer;
 use settings::Settings;
 use gui::GUI;
 use logic::Logic;
 use utils::{TimeManager, TimeMilliseconds};
 use audio::{Audio, Volume, AudioPlayer};
 
-use super::{Window, RenderingContext, WINDOW_TITLE};
+use super::{Window, RenderingContext, DisplayMode, WindowGeometry, WINDOW_TITLE};
 
 #[cfg(not(target_os = "emscripten"))]
 const PAUSE_KEY: Keycode = Keycode::Escape;
@@ -44,14 +46,17 @@ pub struct SDL2Window {
     /// would be otherwise dropped.
     _context: GLContext,
     audio_player: Option<AudioPlayerSDL2>,
+    display_mode: DisplayMode,
+    /// Resolution for windowed and exclusive full screen modes.
+    resolution: Option<(u32, u32)>,
 }
 
 impl Window for SDL2Window {
     type AudioPlayer = AudioPlayerSDL2;
 
-    fn new(rendering_context: RenderingContext) -> Result<Self,()> {
+    fn new(rendering_context: RenderingContext, geometry: &WindowGeometry) -> Result<Self,()> {
         let sdl_context = sdl2::init().expect("sdl2 init failed");
-        println!("SDL2 version: {}", sdl2::version::version());
+        info!("SDL2 version: {}", sdl2::version::version());
 
         let event_pump = sdl_context.event_pump().expect("failed to get handle to sdl2 event_pump");
 
@@ -60,7 +65,13 @@ impl Window for SDL2Window {
 
         let video_subsystem = sdl_context.video().expect("video subsystem init fail");
 
-        let window = video_subsystem.window(WINDOW_TITLE, DEFAULT_SCREEN_WIDTH as u32, DEFAULT_SCREEN_HEIGHT as u32).opengl().build().expect("window creation failed");
+        let mut window_builder = video_subsystem.window(WINDOW_TITLE, geometry.width, geometry.height);
+
+        if let Some((x, y)) = geometry.position {
+            window_builder.position(x, y);
+        }
+
+        let window = window_builder.opengl().resizable().build().expect("window creation failed");
 
         match rendering_context {
             RenderingContext::OpenGL => {
@@ -86,6 +97,8 @@ impl Window for SDL2Window {
             window,
             _context,
             audio_player: AudioPlayerSDL2::new(),
+            display_mode: DisplayMode::Windowed,
+            resolution: None,
         };
 
         Ok(window)
@@ -118,6 +131,10 @@ impl Window for SDL2Window {
                     }
                     Event::MouseMotion { x, y, ..} => input.update_mouse_motion(renderer.screen_coordinates_to_world_coordinates(x, y)),
                     Event::MouseButtonUp { x, y, ..} =>  input.update_mouse_button_up(renderer.screen_coordinates_to_world_coordinates(x, y)),
+                    Event::MouseButtonDown { x, y, ..} =>  input.update_mouse_button_down(renderer.screen_coordinates_to_world_coordinates(x, y)),
+                    Event::TextInput { ref text, ..} => input.update_text_input(text),
+                    Event::MouseWheel { y, direction: MouseWheelDirection::Flipped, ..} => input.update_mouse_wheel(-y),
+                    Event::MouseWheel { y, ..} => input.update_mouse_wheel(y),
                     Event::ControllerDeviceRemoved { which, ..} => self.game_controller_manager.remove_game_controller(which),
                     Event::ControllerAxisMotion { axis, value, ..} => GameControllerManager::handle_axis_motion(axis, value, input, time_manager.current_time()),
                     Event::ControllerButtonDown { button, ..} => {
@@ -149,11 +166,11 @@ impl Window for SDL2Window {
                         {
                             // It seems that full screen setting on emscripten build does not always change the game to full screen mode, so
                             // lets set full screen mode to disabled when event's screen width is less or equal than current screen width.
-                            if window_width_pixels <= self.renderer.screen_width_pixels() {
-                                let value = false;
-                                let setting = settings::BooleanSetting::FullScreen;
-                                settings.update_setting(settings::SettingType::Boolean(setting, value));
-                                gui.get_settings_menu().set_boolean_setting(setting, value);
+                            if window_width_pixels <= renderer.screen_width_pixels() {
+                                use settings::{SettingId, SettingValue};
+
+                                let value = settings.update_setting(SettingId::DisplayMode, SettingValue::Enum(DisplayMode::Windowed as usize));
+                                gui.get_settings_menu().set_setting_value(SettingId::DisplayMode, value);
                             }
                         }
 
@@ -164,6 +181,7 @@ impl Window for SDL2Window {
                     _ => (),
             }
 
+            // Printed directly, so log level doesn't hide events which user requested.
             if settings.print_joystick_events() {
                 match event {
                     Event::JoyAxisMotion { value, axis_idx, .. } => println!("JoyAxisMotion, value: {}, axis_idx: {},", value, axis_idx),
@@ -183,18 +201,62 @@ impl Window for SDL2Window {
         Ok(())
     }
 
-    /// Enable or disable full screen mode.
-    fn set_fullscreen(&mut self, value: bool) {
-        let setting;
+    /// Change between windowed and full screen modes.
+    fn set_display_mode(&mut self, display_mode: DisplayMode) {
+        let setting = match display_mode {
+            DisplayMode::Windowed => FullscreenType::Off,
+            DisplayMode::BorderlessFullscreen => FullscreenType::Desktop,
+            DisplayMode::ExclusiveFullscreen => {
+                self.set_exclusive_fullscreen_resolution();
+                FullscreenType::True
+            },
+        };
 
-        if value {
-            setting = FullscreenType::Desktop;
-        } else {
-            setting = FullscreenType::Off;
+        if let Err(message) = self.window.set_fullscreen(setting) {
+            error!("couldn't change display mode: {}", message);
+            return;
         }
 
-        if let Err(message) = self.window.set_fullscreen(setting) {
-            println!("Error, couldn't change fullscreen setting: {}", message);
+        self.display_mode = display_mode;
+
+        match (display_mode, self.resolution) {
+            (DisplayMode::Windowed, Some((width, height))) => self.set_window_size(width, height),
+            (DisplayMode::ExclusiveFullscreen, Some(resolution)) => self.check_exclusive_fullscreen_resolution(resolution),
+            _ => (),
+        }
+    }
+
+    /// Set resolution for windowed mode and exclusive full screen mode.
+    fn set_resolution(&mut self, width: u32, height: u32) {
+        let first_resolution = self.resolution.is_none();
+
+        self.resolution = Some((width, height));
+
+        match self.display_mode {
+            DisplayMode::Windowed if first_resolution => (),
+            DisplayMode::Windowed => self.set_window_size(width, height),
+            DisplayMode::BorderlessFullscreen => (),
+            // Display mode setting is applied before resolution setting at startup, so
+            // exclusive full screen mode must be set again also for the first resolution.
+            DisplayMode::ExclusiveFullscreen => self.set_display_mode(DisplayMode::ExclusiveFullscreen),
+        }
+    }
+
+    fn drawable_size(&self) -> (u32, u32) {
+        self.window.drawable_size()
+    }
+
+    fn window_geometry(&self) -> Option<WindowGeometry> {
+        if let FullscreenType::Off = self.window.fullscreen_state() {
+            let (width, height) = self.window.size();
+
+            Some(WindowGeometry {
+                width,
+                height,
+                position: Some(self.window.position()),
+            })
+        } else {
+            None
         }
     }
 
@@ -218,7 +280,7 @@ impl Window for SDL2Window {
     fn add_game_controller_mappings(&mut self, game_controller_mappings: &Vec<String>) {
         for mapping in game_controller_mappings {
             if let Err(error) = self.game_controller_manager.game_controller_subsystem.add_mapping(mapping) {
-                println!("error when loading game controller mapping \"{}\", error: {}", mapping, error);
+                error!("error when loading game controller mapping \"{}\", error: {}", mapping, error);
             }
         }
     }
@@ -229,6 +291,38 @@ impl Window for SDL2Window {
 }
 
 
+impl SDL2Window {
+    /// Set window size in windowed mode.
+    fn set_window_size(&mut self, width: u32, height: u32) {
+        if let Err(error) = self.window.set_size(width, height) {
+            error!("couldn't change window size: {}", error);
+        }
+    }
+
+    /// Set display resolution which will be used in exclusive full screen mode.
+    fn set_exclusive_fullscreen_resolution(&mut self) {
+        let (width, height) = match self.resolution {
+            Some(resolution) => resolution,
+            None => return,
+        };
+
+        // SDL will select the closest display mode available.
+        let display_mode = sdl2::video::DisplayMode::new(PixelFormatEnum::Unknown, width as i32, height as i32, 0);
+
+        if let Err(error) = self.window.set_display_mode(display_mode) {
+            error!("couldn't set full screen resolution: {}", error);
+        }
+    }
+
+    /// Log a warning if exclusive full screen mode doesn't use the resolution from argument.
+    fn check_exclusive_fullscreen_resolution(&self, (width, height): (u32, u32)) {
+        match self.window.display_mode() {
+            Ok(mode) if mode.w as u32 == width && mode.h as u32 == height => info!("full screen resolution: {}x{}", width, height),
+            Ok(mode) => warn!("full screen resolution is {}x{} instead of {}x{}", mode.w, mode.h, width, height),
+            Err(error) => error!("couldn't get full screen resolution: {}", error),
+        }
+    }
+}
 
 fn keycode_to_key(keycode: Keycode) -> Option<Key> {
     let key = match keycode {
@@ -239,6 +333,9 @@ fn keycode_to_key(keycode: Keycode) -> Option<Key> {
         Keycode::Space | Keycode::LCtrl | Keycode::RCtrl => Key::Shoot,
         Keycode::Return => Key::Select,
         PAUSE_KEY  => Key::Back,
+        Keycode::F3 => Key::DebugOverlay,
+        Keycode::Backquote | Keycode::F1 => Key::Console,
+        Keycode::Backspace => Key::Backspace,
         _ => return None,
     };
 
@@ -270,14 +367,14 @@ impl GameControllerManager {
     /// If the joystick doesn't have a game controller mapping, method will create default
     /// mapping for the joystick and return the created mapping.
     ///
-    /// If there is an error it will be printed to standard output.
+    /// If there is an error it will be logged.
     pub fn add_game_controller_from_joystick_id(&mut self, id: u32) -> Option<GameControllerMapping> {
         let game_controller_mapping = if !self.game_controller_subsystem.is_game_controller(id) {
             let joystick_name;
             match self.joystick_subsystem.name_for_index(id) {
                 Ok(name) => joystick_name = name,
                 Err(error) => {
-                    println!("error: {}", error);
+                    error!("error: {}", error);
                     return None;
                 }
             }
@@ -286,7 +383,7 @@ impl GameControllerManager {
             match self.joystick_subsystem.device_guid(id) {
                 Ok(guid) => joystick_guid = guid.to_string(),
                 Err(error) => {
-                    println!("error: {}", error);
+                    error!("error: {}", error);
                     return None;
                 }
             }
@@ -298,11 +395,11 @@ impl GameControllerManager {
 
             match self.game_controller_subsystem.add_mapping(&joystick_guid) {
                 Ok(_) => {
-                    println!("default game controller mapping loaded for joystick with id {}", id);
+                    info!("default game controller mapping loaded for joystick with id {}", id);
                     Some(joystick_guid)
                 },
                 Err(error) => {
-                    println!("error: {}", error);
+                    error!("error: {}", error);
                     return None
                 }
             }
@@ -313,9 +410,9 @@ impl GameControllerManager {
         match self.game_controller_subsystem.open(id) {
             Ok(controller) => {
                 self.game_controllers.push(controller);
-                println!("game controller with id {} added", id);
+                info!("game controller with id {} added", id);
             },
-            Err(integer_or_sdl_error) => println!("game controller error: {}", integer_or_sdl_error),
+            Err(integer_or_sdl_error) => error!("game controller error: {}", integer_or_sdl_error),
         }
 
         game_controller_mapping
@@ -336,7 +433,7 @@ impl GameControllerManager {
 
         if let Some(i) = index {
             self.game_controllers.swap_remove(i);
-            println!("game controller with id {} removed", id);
+            info!("game controller with id {} removed", id);
         }
     }
 
@@ -419,13 +516,13 @@ impl Audio for SoundEffectSDL2 {
 
     /// Play sound effect.
     ///
-    /// Prints error message to standard output if there is sound effect
+    /// Logs an error message if there is sound effect
     /// playing error.
     fn play(&mut self) {
         self.channel = match self.channel.play(&self.chunk, 0) {
             Ok(channel) => channel,
             Err(message) => {
-                println!("sound effect playing error: {}", message);
+                error!("sound effect playing error: {}", message);
                 Channel::all()
             },
         };
@@ -503,11 +600,11 @@ impl Audio for MusicSDL2 {
     /// Start playing music if it isn't already playing.
     ///
     /// If starting the music failed, an error message will
-    /// be printed to the standard output.
+    /// be logged.
     fn play(&mut self) {
         if !Music::is_playing() {
             if let Err(message) = self.music.play(-1) {
-                println!("music error: {}", message);
+                error!("music error: {}", message);
             }
         }
     }
@@ -519,11 +616,11 @@ pub struct AudioPlayerSDL2;
 impl AudioPlayerSDL2 {
     pub fn new() -> Option<Self> {
         if let Err(error) = mixer::open_audio(44100, mixer::DEFAULT_FORMAT, mixer::DEFAULT_CHANNELS, 1024) {
-            println!("SDL_mixer init error: {}", error);
+            error!("SDL_mixer init error: {}", error);
 
             None
         } else {
-            println!("SDL_mixer version: {}", mixer::get_linked_version());
+            info!("SDL_mixer version: {}", mixer::get_linked_version());
 
             Some(AudioPlayerSDL2)
         }